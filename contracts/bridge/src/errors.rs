@@ -91,6 +91,35 @@ pub enum Error {
     AssetNotFrozen,
     /// Insufficient emergency signatures.
     InsufficientEmergencySignatures,
+
+    // ── Appended variants ───────────────────────────────────────────────────
+    // SCALE encodes variants by position: new variants go at the end only.
+    /// An operator signature could not be verified against its registered key.
+    InvalidSignature,
+    /// The inbound message has already been finalized on this chain.
+    InboundAlreadyProcessed,
+    /// The corridor to or from this chain has been deactivated.
+    ChainPaused,
+    /// The value transferred with the request is below the chain's bridge fee.
+    InsufficientFee,
+    /// Paying out fees or refunds from the contract balance failed.
+    FeeTransferFailed,
+    /// The sender, or the bridge as a whole, has too many open requests.
+    TooManyOpenRequests,
+    /// The destination chain has reached its request quota for the current window.
+    ChainRateLimited,
+    /// Metadata does not match the hash recorded on the source chain.
+    MetadataCorruption,
+    /// A bridged-in token may only return to its origin chain while
+    /// multi-hop bridging of such tokens is disabled.
+    MultiHopNotAllowed,
+    /// A batch request is empty or exceeds the maximum batch size.
+    BatchTooLarge,
+    /// The request is frozen by the admin or a security officer.
+    RequestFrozen,
+    /// The submitted bridge configuration is internally inconsistent or
+    /// would disable multisig protection.
+    InvalidConfig,
 }
 
 impl Error {
@@ -106,6 +135,10 @@ impl Error {
                 | Error::RateLimitExceeded
                 | Error::BridgePaused
                 | Error::OperationPaused
+                | Error::ChainPaused
+                | Error::FeeTransferFailed
+                | Error::TooManyOpenRequests
+                | Error::ChainRateLimited
         )
     }
 
@@ -123,6 +156,8 @@ impl Error {
                 | Error::ReentrantCall
                 | Error::InvalidStatusTransition
                 | Error::TravelRuleDataAlreadySubmitted
+                | Error::InboundAlreadyProcessed
+                | Error::MetadataCorruption
         )
     }
 
@@ -132,13 +167,19 @@ impl Error {
             Error::RateLimitExceeded
             | Error::BridgePaused
             | Error::OperationPaused
+            | Error::ChainPaused
+            | Error::FeeTransferFailed
+            | Error::TooManyOpenRequests
+            | Error::ChainRateLimited
             | Error::InsufficientSignatures
             | Error::InsufficientEmergencySignatures => Severity::Transient,
             Error::ReentrantCall
             | Error::AlreadySigned
             | Error::DuplicateRequest
             | Error::InvalidStatusTransition
-            | Error::TravelRuleDataAlreadySubmitted => Severity::Fatal,
+            | Error::TravelRuleDataAlreadySubmitted
+            | Error::InboundAlreadyProcessed
+            | Error::MetadataCorruption => Severity::Fatal,
             _ => Severity::User,
         }
     }
@@ -202,6 +243,18 @@ impl ContractError for Error {
             Error::AssetAlreadyFrozen => bridge_codes::BRIDGE_INVALID_REQUEST,
             Error::AssetNotFrozen => bridge_codes::BRIDGE_INVALID_REQUEST,
             Error::InsufficientEmergencySignatures => bridge_codes::BRIDGE_INSUFFICIENT_SIGNATURES,
+            Error::InvalidSignature => bridge_codes::BRIDGE_INVALID_SIGNATURE,
+            Error::InboundAlreadyProcessed => bridge_codes::BRIDGE_INBOUND_ALREADY_PROCESSED,
+            Error::ChainPaused => bridge_codes::BRIDGE_CHAIN_PAUSED,
+            Error::InsufficientFee => bridge_codes::BRIDGE_INSUFFICIENT_FEE,
+            Error::FeeTransferFailed => bridge_codes::BRIDGE_FEE_TRANSFER_FAILED,
+            Error::TooManyOpenRequests => bridge_codes::BRIDGE_TOO_MANY_OPEN_REQUESTS,
+            Error::ChainRateLimited => bridge_codes::BRIDGE_CHAIN_RATE_LIMITED,
+            Error::MetadataCorruption => bridge_codes::BRIDGE_METADATA_CORRUPTION,
+            Error::MultiHopNotAllowed => bridge_codes::BRIDGE_MULTI_HOP_NOT_ALLOWED,
+            Error::BatchTooLarge => bridge_codes::BRIDGE_BATCH_TOO_LARGE,
+            Error::RequestFrozen => bridge_codes::BRIDGE_REQUEST_FROZEN,
+            Error::InvalidConfig => bridge_codes::BRIDGE_INVALID_CONFIG,
         }
     }
 
@@ -261,6 +314,30 @@ impl ContractError for Error {
             Error::InsufficientEmergencySignatures => {
                 "Not enough emergency signatures collected for the operation"
             }
+            Error::InvalidSignature => {
+                "An operator signature could not be verified against its registered key"
+            }
+            Error::InboundAlreadyProcessed => {
+                "The inbound bridge message has already been finalized"
+            }
+            Error::ChainPaused => "Bridging to or from this chain is temporarily paused",
+            Error::InsufficientFee => "The value sent does not cover the bridge fee for this chain",
+            Error::FeeTransferFailed => "Transferring fees or refunds from the bridge failed",
+            Error::TooManyOpenRequests => "Too many bridge requests are already open",
+            Error::ChainRateLimited => {
+                "The destination chain has reached its request limit for this window"
+            }
+            Error::MetadataCorruption => {
+                "The property metadata does not match the hash recorded on the source chain"
+            }
+            Error::MultiHopNotAllowed => {
+                "A bridged token can only be bridged back to its origin chain"
+            }
+            Error::BatchTooLarge => "The bridge batch is empty or holds too many tokens",
+            Error::RequestFrozen => "The bridge request is frozen pending investigation",
+            Error::InvalidConfig => {
+                "The bridge configuration is invalid: check signature bounds, chains and gas limit"
+            }
         }
     }
 
@@ -370,6 +447,25 @@ impl From<scale::Error> for Error {
     }
 }
 
+/// Map bridge errors onto the shared cross-contract error.
+///
+/// Causes with a shared equivalent use it; everything else keeps the
+/// bridge's own error code.
+impl From<Error> for PropChainError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Unauthorized | Error::NotGuardian => PropChainError::Unauthorized,
+            Error::TokenNotFound => PropChainError::NotFound,
+            Error::InvalidMetadata => PropChainError::InvalidMetadata,
+            Error::BridgePaused | Error::OperationPaused | Error::ChainPaused => {
+                PropChainError::Paused
+            }
+            Error::ReentrantCall => PropChainError::ReentrantCall,
+            other => PropChainError::Contract(other.error_code()),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -384,6 +480,9 @@ mod error_tests {
         assert!(Error::BridgePaused.is_retryable());
         assert!(Error::OperationPaused.is_retryable());
         assert!(Error::InsufficientSignatures.is_retryable());
+        assert!(Error::ChainPaused.is_retryable());
+        assert!(Error::TooManyOpenRequests.is_retryable());
+        assert!(Error::ChainRateLimited.is_retryable());
 
         assert!(!Error::Unauthorized.is_retryable());
         assert!(!Error::ReentrantCall.is_retryable());
@@ -411,6 +510,11 @@ mod error_tests {
             Error::BridgePaused, Error::OperationPaused, Error::GasLimitExceeded,
             Error::RateLimitExceeded, Error::ReentrantCall,
             Error::TransactionNotFound, Error::InvalidStatusTransition,
+            Error::InvalidSignature, Error::InboundAlreadyProcessed,
+            Error::ChainPaused, Error::InsufficientFee, Error::FeeTransferFailed,
+            Error::TooManyOpenRequests, Error::ChainRateLimited,
+            Error::MetadataCorruption, Error::MultiHopNotAllowed,
+            Error::BatchTooLarge, Error::RequestFrozen, Error::InvalidConfig,
         ];
         for e in &all {
             assert!(
@@ -461,4 +565,29 @@ mod error_tests {
         let ctx = ErrorContext::new(Error::DuplicateRequest);
         assert_eq!(ctx.into_error(), Error::DuplicateRequest);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn bridge_errors_convert_to_shared_error() {
+        assert_eq!(PropChainError::from(Error::NotGuardian), PropChainError::Unauthorized);
+        assert_eq!(PropChainError::from(Error::ChainPaused), PropChainError::Paused);
+        assert_eq!(
+            PropChainError::from(Error::InvalidMetadata),
+            PropChainError::InvalidMetadata
+        );
+        assert_eq!(
+            PropChainError::from(Error::MetadataCorruption),
+            PropChainError::Contract(bridge_codes::BRIDGE_METADATA_CORRUPTION)
+        );
+    }
+
+    #[test]
+    fn variant_encoding_is_append_only() {
+        use scale::Encode;
+        assert_eq!(Error::Unauthorized.encode(), vec![0]);
+        assert_eq!(Error::InvalidMetadata.encode(), vec![12]);
+        assert_eq!(Error::ReentrantCall.encode(), vec![17]);
+        assert_eq!(Error::InsufficientEmergencySignatures.encode(), vec![25]);
+        assert_eq!(Error::InvalidSignature.encode(), vec![26]);
+        assert_eq!(Error::InvalidConfig.encode(), vec![37]);
+    }
+}
@@ -17,7 +17,7 @@ use propchain_traits::*;
 use scale_info::prelude::vec::Vec;
 
 #[ink::contract]
-mod bridge {
+pub mod bridge {
     use super::*;
     use propchain_traits::{non_reentrant, ReentrancyError, ReentrancyGuard};
 
@@ -28,6 +28,22 @@ mod bridge {
     const PAUSE_AUDIT_LOG_LIMIT: usize = 256;
     const SIGNATURE_BITMAP_BYTES: usize = 32;
     const MAX_VALIDATOR_BITMAP_SLOTS: usize = SIGNATURE_BITMAP_BYTES * 8;
+    /// Maximum number of transactions returned by a single history query.
+    const MAX_HISTORY_PAGE_SIZE: u32 = 100;
+    /// Version tag of the [`OutboundPayload`] encoding.
+    const OUTBOUND_PAYLOAD_VERSION: u8 = 1;
+    /// Version tag of the canonical request encoding behind
+    /// [`PropertyBridge::request_digest`].
+    const REQUEST_ENCODING_VERSION: u8 = 1;
+    /// Maximum number of status transitions kept per request. The oldest
+    /// entry is dropped on insert once the limit is reached.
+    const STATUS_HISTORY_LIMIT: usize = 10;
+    /// Maximum number of tokens a single batch request may carry.
+    const MAX_BRIDGE_BATCH_SIZE: usize = 50;
+
+    /// A request status transition: new status, block number and, for
+    /// failures, the reason.
+    pub type StatusHistoryEntry = (BridgeOperationStatus, u64, Option<String>);
 
     impl From<ReentrancyError> for Error {
         fn from(_: ReentrancyError) -> Self {
@@ -45,6 +61,31 @@ mod bridge {
         Legacy(Vec<AccountId>),
     }
 
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    struct StoredBridgeRequestV3 {
+        request_id: u64,
+        token_id: TokenId,
+        source_chain: ChainId,
+        destination_chain: ChainId,
+        sender: AccountId,
+        recipient: AccountId,
+        required_signatures: u8,
+        signature_storage: SignatureStorage,
+        created_at: u64,
+        expires_at: Option<u64>,
+        status: BridgeOperationStatus,
+        multi_hop_status: MultiHopStatus,
+        route: Vec<ChainId>,
+        current_hop: u32,
+        total_gas_estimate: u64,
+        metadata: PropertyMetadata,
+        min_signatures: u8,
+    }
+
     #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(
         feature = "std",
@@ -115,15 +156,19 @@ mod bridge {
         current_hop: u32,
         total_gas_estimate: u64,
         metadata: PropertyMetadata,
+        /// `min_signatures_required` as configured when the request was
+        /// created; the floor for its liveness-adjusted threshold. Requests
+        /// stored before this was recorded use `required_signatures`.
+        min_signatures: u8,
     }
 
     impl scale::Encode for StoredBridgeRequest {
         fn size_hint(&self) -> usize {
-            StoredBridgeRequestV2::from(self.clone()).size_hint()
+            StoredBridgeRequestV3::from(self.clone()).size_hint()
         }
 
         fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
-            StoredBridgeRequestV2::from(self.clone()).encode_to(dest);
+            StoredBridgeRequestV3::from(self.clone()).encode_to(dest);
         }
     }
 
@@ -134,6 +179,10 @@ mod bridge {
                 bytes.push(byte);
             }
 
+            if let Ok(decoded) = <StoredBridgeRequestV3 as scale::Decode>::decode(&mut &bytes[..]) {
+                return Ok(decoded.into());
+            }
+
             if let Ok(decoded) = <StoredBridgeRequestV2 as scale::Decode>::decode(&mut &bytes[..]) {
                 return Ok(decoded.into());
             }
@@ -143,7 +192,7 @@ mod bridge {
         }
     }
 
-    impl From<StoredBridgeRequest> for StoredBridgeRequestV2 {
+    impl From<StoredBridgeRequest> for StoredBridgeRequestV3 {
         fn from(value: StoredBridgeRequest) -> Self {
             Self {
                 request_id: value.request_id,
@@ -162,6 +211,31 @@ mod bridge {
                 current_hop: value.current_hop,
                 total_gas_estimate: value.total_gas_estimate,
                 metadata: value.metadata,
+                min_signatures: value.min_signatures,
+            }
+        }
+    }
+
+    impl From<StoredBridgeRequestV3> for StoredBridgeRequest {
+        fn from(value: StoredBridgeRequestV3) -> Self {
+            Self {
+                request_id: value.request_id,
+                token_id: value.token_id,
+                source_chain: value.source_chain,
+                destination_chain: value.destination_chain,
+                sender: value.sender,
+                recipient: value.recipient,
+                required_signatures: value.required_signatures,
+                signature_storage: value.signature_storage,
+                created_at: value.created_at,
+                expires_at: value.expires_at,
+                status: value.status,
+                multi_hop_status: value.multi_hop_status,
+                route: value.route,
+                current_hop: value.current_hop,
+                total_gas_estimate: value.total_gas_estimate,
+                metadata: value.metadata,
+                min_signatures: value.min_signatures,
             }
         }
     }
@@ -185,6 +259,7 @@ mod bridge {
                 current_hop: value.current_hop,
                 total_gas_estimate: value.total_gas_estimate,
                 metadata: value.metadata,
+                min_signatures: value.required_signatures,
             }
         }
     }
@@ -208,6 +283,7 @@ mod bridge {
                 current_hop: value.current_hop,
                 total_gas_estimate: value.total_gas_estimate,
                 metadata: value.metadata,
+                min_signatures: value.required_signatures,
             }
         }
     }
@@ -221,6 +297,7 @@ mod bridge {
             sender: AccountId,
             recipient: AccountId,
             required_signatures: u8,
+            min_signatures: u8,
             created_at: u64,
             expires_at: Option<u64>,
             route: Vec<ChainId>,
@@ -244,6 +321,7 @@ mod bridge {
                 current_hop: 0,
                 total_gas_estimate,
                 metadata,
+                min_signatures,
             }
         }
 
@@ -312,21 +390,176 @@ mod bridge {
         affects_inflight: bool,
     }
 
+    /// Running totals of bridge fees held or paid out by the contract.
+    ///
+    /// Every fee ever collected is in exactly one bucket, so
+    /// `collected == escrowed + claimable + claimed + refunded` holds at all
+    /// times.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FeeAccounting {
+        /// Total fees received with bridge requests.
+        pub collected: u128,
+        /// Fees held for requests that have not completed or failed yet.
+        pub escrowed: u128,
+        /// Fees released to operators but not yet withdrawn.
+        pub claimable: u128,
+        /// Fees withdrawn by operators.
+        pub claimed: u128,
+        /// Fees returned to requesters of failed or cancelled requests.
+        pub refunded: u128,
+    }
+
+    /// Responsiveness of one bridge signer, as reported by
+    /// `get_operator_liveness`.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct OperatorLiveness {
+        pub operator: AccountId,
+        /// Block timestamp of the operator's last heartbeat, if any.
+        pub last_seen: Option<u64>,
+        /// Pending requests the operator has not signed yet.
+        pub pending_signatures: u32,
+        /// Whether the operator counts towards signature thresholds under
+        /// the current liveness window.
+        pub live: bool,
+    }
+
+    /// Message relayers forward verbatim to the destination chain's bridge
+    /// contract. Its SCALE encoding is the canonical wire format.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct OutboundPayload {
+        pub version: u8,
+        pub source_chain: ChainId,
+        pub destination_chain: ChainId,
+        pub request_id: u64,
+        pub token_id: TokenId,
+        pub recipient: AccountId,
+        /// Blake2b-256 hash of the SCALE-encoded property metadata.
+        pub metadata_hash: Hash,
+        /// Address of the bridge contract on the destination chain, in that
+        /// chain's native format. Empty if none is configured.
+        pub destination_contract: Vec<u8>,
+    }
+
+    /// Refundable gas deposit held for a bridge request.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct GasDeposit {
+        pub amount: u128,
+        /// Set once the deposit was refunded to the sender or paid to the
+        /// executing operator; a deposit is settled at most once.
+        pub settled: bool,
+    }
+
+    /// Admin-configurable limits on outstanding bridge requests. A value of
+    /// zero disables the corresponding limit.
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct RequestLimits {
+        /// Maximum non-terminal requests a single sender may have.
+        pub max_open_per_sender: u32,
+        /// Maximum non-terminal requests across the whole bridge.
+        pub max_open_total: u32,
+        /// Length in blocks of the per-chain request window.
+        pub chain_window_blocks: u32,
+        /// Maximum requests to one destination chain per window.
+        pub max_requests_per_chain_window: u32,
+    }
+
+    /// Outcome of [`PropertyBridge::simulate_bridge`]: what
+    /// `initiate_bridge_multisig` would accept and `execute_bridge` would
+    /// relay for the same arguments.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SimulationResult {
+        /// Id the request would be assigned.
+        pub request_id: u64,
+        /// Value to send: the destination chain's fee plus the gas deposit.
+        pub fee: u128,
+        /// Gas the request would be estimated at, or zero if the route is
+        /// invalid.
+        pub gas_estimate: u64,
+        /// The caller may bridge the token.
+        pub authorized: bool,
+        /// The token is supported on the destination chain and may be routed
+        /// there.
+        pub compliant: bool,
+        /// The token is already part of an open request.
+        pub duplicate: bool,
+        /// Travel-rule data would have to be submitted before execution.
+        pub travel_rule_data_required: bool,
+        /// First error `initiate_bridge_multisig` would return, if any. Fee
+        /// payment is not checked.
+        pub error: Option<Error>,
+        /// Canonical encoding behind [`PropertyBridge::request_digest`].
+        pub canonical_request: Vec<u8>,
+        /// Digest operators would sign.
+        pub digest: Hash,
+        /// SCALE-encoded [`OutboundPayload`] `execute_bridge` would emit.
+        pub outbound_payload: Vec<u8>,
+    }
+
     /// Bridge contract for cross-chain property token transfers
     #[ink(storage)]
     pub struct PropertyBridge {
         /// Bridge configuration
         config: BridgeConfig,
 
+        /// Chain ID of the network this bridge instance is deployed on.
+        /// Stamped into every request and transaction hash so destination
+        /// contracts can tell deployments apart.
+        chain_id: ChainId,
+
+        /// Set once `set_chain_id` has been used, preventing further changes.
+        chain_id_locked: bool,
+
         /// Multi-signature bridge requests
         bridge_requests: Mapping<u64, StoredBridgeRequest>,
 
-        /// Bridge transaction history
-        bridge_history: Mapping<AccountId, Vec<BridgeTransaction>>,
+        /// Recent status transitions of each request, oldest first
+        request_status_history: Mapping<u64, Vec<StatusHistoryEntry>>,
+
+        /// Bridge transactions keyed by `transaction_id`
+        transactions: Mapping<u64, BridgeTransaction>,
+
+        /// Transaction IDs involving each account, oldest first
+        account_transaction_ids: Mapping<AccountId, Vec<u64>>,
+
+        /// Transaction ID for each transaction hash
+        transaction_id_by_hash: Mapping<Hash, u64>,
 
         /// Chain-specific information
         chain_info: Mapping<ChainId, ChainBridgeInfo>,
 
+        /// Bridge contract address on each remote chain, in its native format
+        destination_contracts: Mapping<ChainId, Vec<u8>>,
+
+        /// Encoded payload of each request's most recently executed hop
+        outbound_payloads: Mapping<u64, Vec<u8>>,
+
+        /// Per-chain token allow-lists, replacing `ChainBridgeInfo::supported_tokens`
+        supported_tokens: Mapping<(ChainId, TokenId), bool>,
+
+        /// Number of allow-listed tokens per chain; zero means every token is allowed
+        supported_token_count: Mapping<ChainId, u32>,
+
         /// Transaction verification records
         verified_transactions: Mapping<Hash, bool>,
 
@@ -367,6 +600,9 @@ mod bridge {
 
         /// Registered ECDSA public keys for optional cryptographic signature verification
         operator_public_keys: Mapping<AccountId, [u8; 33]>,
+        /// Reverse index of `operator_public_keys`, used to identify the
+        /// signer of an offline approval.
+        operator_by_public_key: Mapping<[u8; 33], AccountId>,
 
         /// Pending admin key rotation request
         pending_admin_rotation: Option<propchain_traits::KeyRotationRequest>,
@@ -396,6 +632,13 @@ mod bridge {
         /// can trigger an emergency pause but only the admin may unpause.
         guardians: Vec<AccountId>,
 
+        /// Security officers may freeze and unfreeze individual requests
+        /// alongside the admin.
+        security_officers: Vec<AccountId>,
+
+        /// Frozen requests: request_id -> (reason, block frozen at)
+        frozen_requests: Mapping<u64, (String, u32)>,
+
         /// Bounded chronological audit log of every pause / unpause event.
         /// Capped to `PAUSE_AUDIT_LOG_LIMIT` entries; oldest dropped on
         /// overflow to keep storage usage predictable.
@@ -456,50 +699,121 @@ mod bridge {
         batch_window_start: Mapping<ChainId, u64>,
         /// Batch window duration in seconds.
         batch_window_duration: u64,
+
+        // ── Inbound transfers ───────────────────────────────────────────────
+        /// Property token contract that receives finalized inbound transfers.
+        /// When unset, inbound transfers are only recorded on the bridge.
+        token_contract: Option<AccountId>,
+        /// Replay protection for inbound messages, keyed by
+        /// (source_chain, source_request_id).
+        processed_inbound: Mapping<(ChainId, u64), bool>,
+        /// Origin (source chain, token ID there) of tokens bridged in, keyed
+        /// by local token ID. The first provenance recorded is kept.
+        token_origins: Mapping<TokenId, (ChainId, TokenId)>,
+        /// Request holding each locked token and the owner it was locked from.
+        token_locks: Mapping<TokenId, (u64, AccountId)>,
+        /// Tokens currently locked by the bridge, per original owner.
+        locked_tokens_by_owner: Mapping<AccountId, Vec<TokenId>>,
+        /// When `true`, bridged-in tokens may be sent on to chains other
+        /// than their origin.
+        allow_bridged_multi_hop: bool,
+
+        // ── Bridge fees ─────────────────────────────────────────────────────
+        /// Fee charged per request to each destination chain.
+        chain_fees: Mapping<ChainId, u128>,
+        /// Fee escrowed for each request until it completes or fails.
+        request_fees: Mapping<u64, u128>,
+        /// Released fees each operator can withdraw.
+        operator_fee_balances: Mapping<AccountId, u128>,
+        /// Refunds that could not be pushed to the requester and await withdrawal.
+        refund_balances: Mapping<AccountId, u128>,
+        /// Aggregate fee accounting.
+        fee_accounting: FeeAccounting,
+        /// Gas deposit charged on top of the chain fee for each request.
+        gas_deposit_amount: u128,
+        /// Gas deposit held for each request.
+        gas_deposits: Mapping<u64, GasDeposit>,
+        /// Hash of each request's SCALE-encoded metadata, recorded at
+        /// initiation while `BridgeConfig::metadata_preservation` is on.
+        metadata_hashes: Mapping<u64, Hash>,
+
+        /// Non-terminal request currently holding each token, if any.
+        active_request_by_token: Mapping<TokenId, u64>,
+        /// Every token carried by a batch request, in submission order. The
+        /// request's own `token_id` is the first entry. Single-token
+        /// requests have no entry.
+        batch_tokens: Mapping<u64, Vec<TokenId>>,
+        /// Requests that have not reached a terminal state.
+        open_requests: Vec<u64>,
+        /// Number of entries in `open_requests` created by each sender.
+        open_requests_by_sender: Mapping<AccountId, u32>,
+        /// Limits on open requests and per-chain request rate.
+        request_limits: RequestLimits,
+        /// Start block and request count of each chain's current window.
+        chain_request_window: Mapping<ChainId, (u32, u32)>,
+
+        // ── Signer set epochs ──────────────────────────────────────────────
+        /// Incremented on every change to the validator or operator set.
+        signer_epoch: u32,
+        /// Signer epoch in which each request's current signing round began.
+        request_signer_epoch: Mapping<u64, u32>,
+        /// Epoch at which each account was last removed from the signer set.
+        signer_removed_at: Mapping<AccountId, u32>,
+
+        // ── Operator liveness ──────────────────────────────────────────────
+        /// Block timestamp of each operator's or validator's last heartbeat.
+        operator_last_seen: Mapping<AccountId, u64>,
+        /// Seconds without a heartbeat after which a validator no longer
+        /// counts towards signature thresholds. Zero disables the policy.
+        operator_liveness_window: u64,
     }
 
-    /// Events for bridge operations
+    // Events for bridge operations. `BridgeRequestCreated`,
+    // `BridgeRequestSigned` and `BridgeExecuted` are shared with every
+    // bridging contract and live in `propchain_traits::bridge`; see the
+    // emit helpers below.
+
+    /// Emitted when a signature is dropped from a pending request because
+    /// its signer left the validator or operator set.
     #[ink(event)]
-    pub struct BridgeRequestCreated {
+    pub struct SignatureInvalidated {
         #[ink(topic)]
         pub request_id: u64,
         #[ink(topic)]
-        pub token_id: TokenId,
-        #[ink(topic)]
-        pub source_chain: ChainId,
-        #[ink(topic)]
-        pub destination_chain: ChainId,
-        #[ink(topic)]
-        pub requester: AccountId,
+        pub signer: AccountId,
+        pub signatures_collected: u8,
     }
 
     #[ink(event)]
-    pub struct BridgeRequestSigned {
+    pub struct BridgeFailed {
         #[ink(topic)]
         pub request_id: u64,
         #[ink(topic)]
-        pub signer: AccountId,
-        pub signatures_collected: u8,
-        pub signatures_required: u8,
+        pub token_id: TokenId,
+        pub error: String,
     }
 
+    /// Emitted when a bridge request locks its token on this chain.
     #[ink(event)]
-    pub struct BridgeExecuted {
-        #[ink(topic)]
-        pub request_id: u64,
+    pub struct TokenLockedForBridge {
         #[ink(topic)]
         pub token_id: TokenId,
         #[ink(topic)]
-        pub transaction_hash: Hash,
+        pub request_id: u64,
+        #[ink(topic)]
+        pub original_owner: AccountId,
     }
 
+    /// Emitted when a bridge lock is reverted and the token returns to its
+    /// original owner.
     #[ink(event)]
-    pub struct BridgeFailed {
+    pub struct TokenUnlockedFromBridge {
+        #[ink(topic)]
+        pub token_id: TokenId,
         #[ink(topic)]
         pub request_id: u64,
         #[ink(topic)]
-        pub token_id: TokenId,
-        pub error: String,
+        pub owner: AccountId,
     }
 
     #[ink(event)]
@@ -525,6 +839,30 @@ mod bridge {
         pub rolled_back_at: u32,
     }
 
+    /// Emitted when a requester withdraws their own pending bridge request.
+    #[ink(event)]
+    pub struct BridgeRequestCancelled {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub token_id: TokenId,
+        #[ink(topic)]
+        pub requester: AccountId,
+    }
+
+    /// Emitted when a request passes its `expires_at` block without being
+    /// executed. The token is released back to the requester.
+    #[ink(event)]
+    pub struct BridgeRequestExpired {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub token_id: TokenId,
+        pub requester: AccountId,
+        /// Block number at which the expiry was recorded.
+        pub expired_at: u32,
+    }
+
     /// Emitted whenever the per-chain status of a cross-chain transaction
     /// changes (creation, leg confirmation, failure, etc.). Off-chain
     /// indexers can subscribe to this event to mirror full bridge state.
@@ -574,6 +912,36 @@ mod bridge {
         pub added: bool,
     }
 
+    /// Emitted when a security officer is added or removed.
+    #[ink(event)]
+    pub struct SecurityOfficerSetUpdated {
+        #[ink(topic)]
+        pub officer: AccountId,
+        /// `true` when added, `false` when removed.
+        pub added: bool,
+    }
+
+    /// Emitted when a single request is frozen.
+    #[ink(event)]
+    pub struct BridgeRequestFrozen {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub frozen_by: AccountId,
+        pub reason: String,
+    }
+
+    /// Emitted when a frozen request is released.
+    #[ink(event)]
+    pub struct BridgeRequestUnfrozen {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub unfrozen_by: AccountId,
+        /// Deadline after being pushed back by the time spent frozen
+        pub expires_at: Option<u64>,
+    }
+
     /// Emitted when the auto-pause subsystem flags suspicious activity
     /// (whether or not the threshold is exceeded). Useful for early
     /// dashboards / alerting.
@@ -742,10 +1110,98 @@ mod bridge {
         pub timestamp: u64,
     }
 
+    /// Emitted when a chain corridor is activated or deactivated.
+    #[ink(event)]
+    pub struct ChainStatusChanged {
+        #[ink(topic)]
+        pub chain_id: ChainId,
+        pub is_active: bool,
+        #[ink(topic)]
+        pub changed_by: AccountId,
+        pub timestamp: u64,
+    }
+
+    /// Emitted when the bridge fee for a destination chain changes.
+    #[ink(event)]
+    pub struct ChainFeeUpdated {
+        #[ink(topic)]
+        pub chain_id: ChainId,
+        pub fee: u128,
+    }
+
+    /// Emitted when an escrowed request fee is returned to the requester.
+    #[ink(event)]
+    pub struct BridgeFeeRefunded {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub requester: AccountId,
+        pub amount: u128,
+    }
+
+    /// Emitted when an operator withdraws released bridge fees.
+    #[ink(event)]
+    pub struct OperatorFeesClaimed {
+        #[ink(topic)]
+        pub operator: AccountId,
+        pub amount: u128,
+    }
+
+    /// Carries the encoded [`OutboundPayload`] for relayers when a request
+    /// (or hop) is executed.
+    #[ink(event)]
+    pub struct BridgeMessage {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub destination_chain: ChainId,
+        pub payload: Vec<u8>,
+    }
+
+    /// Emitted when a token is added to or removed from a chain's allow-list.
+    #[ink(event)]
+    pub struct SupportedTokenChanged {
+        #[ink(topic)]
+        pub chain_id: ChainId,
+        #[ink(topic)]
+        pub token_id: TokenId,
+        pub supported: bool,
+    }
+
+    /// Emitted when property metadata is checked against the hash recorded
+    /// on the source chain.
+    #[ink(event)]
+    pub struct MetadataVerified {
+        #[ink(topic)]
+        pub source_chain: ChainId,
+        #[ink(topic)]
+        pub request_id: u64,
+        pub metadata_hash: Hash,
+    }
+
+    /// Emitted when a transfer relayed from another chain is finalized.
+    #[ink(event)]
+    pub struct InboundBridgeFinalized {
+        #[ink(topic)]
+        pub source_chain: ChainId,
+        #[ink(topic)]
+        pub source_request_id: u64,
+        #[ink(topic)]
+        pub recipient: AccountId,
+        pub token_id: TokenId,
+        /// Token ID assigned by the local token contract, if one is configured.
+        pub local_token_id: Option<TokenId>,
+        /// Origin chain and token ID the delivered token traces back to.
+        pub origin: (ChainId, TokenId),
+        pub signatures: u8,
+        pub timestamp: u64,
+    }
+
     impl PropertyBridge {
         /// Creates a new PropertyBridge contract
         #[ink(constructor)]
         pub fn new(
+            chain_id: ChainId,
             supported_chains: Vec<ChainId>,
             min_signatures: u8,
             max_signatures: u8,
@@ -769,9 +1225,18 @@ mod bridge {
             // Initialize chain info for supported chains
             let mut bridge = Self {
                 config,
+                chain_id,
+                chain_id_locked: false,
                 bridge_requests: Mapping::default(),
-                bridge_history: Mapping::default(),
+                request_status_history: Mapping::default(),
+                transactions: Mapping::default(),
+                account_transaction_ids: Mapping::default(),
+                transaction_id_by_hash: Mapping::default(),
                 chain_info: Mapping::default(),
+                destination_contracts: Mapping::default(),
+                outbound_payloads: Mapping::default(),
+                supported_tokens: Mapping::default(),
+                supported_token_count: Mapping::default(),
                 verified_transactions: Mapping::default(),
                 cross_chain_trades: Mapping::default(),
                 cross_chain_tx_status: Mapping::default(),
@@ -785,6 +1250,7 @@ mod bridge {
                 cross_chain_trade_counter: 0,
                 admin: caller,
                 operator_public_keys: Mapping::default(),
+                operator_by_public_key: Mapping::default(),
                 pending_admin_rotation: None,
                 account_daily_requests: Mapping::default(),
                 account_last_reset_day: Mapping::default(),
@@ -793,6 +1259,8 @@ mod bridge {
                 reentrancy_guard: ReentrancyGuard::new(),
                 pause_flags: PauseFlags::none(),
                 guardians: Vec::new(),
+                security_officers: Vec::new(),
+                frozen_requests: Mapping::default(),
                 pause_audit_log: Vec::new(),
                 suspicious_config: SuspiciousActivityConfig::default_config(),
                 account_block_request_count: Mapping::default(),
@@ -815,6 +1283,31 @@ mod bridge {
                 batch_window_size: 10, // Default batch size
                 batch_window_start: Mapping::default(),
                 batch_window_duration: 300, // Default 5 minutes in seconds
+                token_contract: None,
+                processed_inbound: Mapping::default(),
+                token_origins: Mapping::default(),
+                token_locks: Mapping::default(),
+                locked_tokens_by_owner: Mapping::default(),
+                allow_bridged_multi_hop: false,
+                chain_fees: Mapping::default(),
+                request_fees: Mapping::default(),
+                operator_fee_balances: Mapping::default(),
+                refund_balances: Mapping::default(),
+                fee_accounting: FeeAccounting::default(),
+                gas_deposit_amount: 0,
+                gas_deposits: Mapping::default(),
+                metadata_hashes: Mapping::default(),
+                active_request_by_token: Mapping::default(),
+                batch_tokens: Mapping::default(),
+                open_requests: Vec::new(),
+                open_requests_by_sender: Mapping::default(),
+                request_limits: RequestLimits::default(),
+                chain_request_window: Mapping::default(),
+                signer_epoch: 0,
+                request_signer_epoch: Mapping::default(),
+                signer_removed_at: Mapping::default(),
+                operator_last_seen: Mapping::default(),
+                operator_liveness_window: 0,
             };
 
             // Set up default chain information
@@ -828,6 +1321,7 @@ mod bridge {
                     confirmation_blocks: propchain_traits::constants::DEFAULT_CONFIRMATION_BLOCKS,
                     supported_tokens: Vec::new(),
                     chain_daily_limit: 10_000_000_000_000_000_000, // Example large default
+                    gas_limit: None,
                 };
                 bridge.chain_info.insert(chain_id, &chain_info);
             }
@@ -835,8 +1329,15 @@ mod bridge {
             bridge
         }
 
-        /// Initiates a bridge request with multi-signature requirement
-        #[ink(message)]
+        /// Initiates a bridge request with multi-signature requirement.
+        ///
+        /// The value sent must cover the destination chain's fee (see
+        /// [`set_chain_fee`]) plus the gas deposit (see [`set_gas_deposit`]).
+        /// The fee is escrowed until the request completes, when it is
+        /// released to the operators, or is refunded if the request is
+        /// rejected or cancelled. The gas deposit is paid to the executing
+        /// operator, or refunded through `RecoveryAction::RefundGas`.
+        #[ink(message, payable)]
         pub fn initiate_bridge_multisig(
             &mut self,
             token_id: TokenId,
@@ -857,22 +1358,17 @@ mod bridge {
             // kicks in and the offending request is also rejected.
             self.track_request_burst(caller)?;
 
-            // Validate destination chain
-            if !self.config.supported_chains.contains(&destination_chain) {
-                return Err(Error::InvalidChain);
-            }
-
-            // Validate signature requirements
-            if required_signatures < self.config.min_signatures_required
-                || required_signatures > self.config.max_signatures_required
-            {
-                return Err(Error::InsufficientSignatures);
-            }
+            let gas_estimate = self.validate_new_request(
+                caller,
+                token_id,
+                destination_chain,
+                required_signatures,
+                &metadata,
+            )?;
+            self.check_request_limits(caller, destination_chain)?;
 
-            // Check if caller is authorized (token owner or approved operator)
-            if !self.is_authorized_for_token(caller, token_id) {
-                return Err(Error::Unauthorized);
-            }
+            let fee_paid =
+                self.ensure_fee_paid(self.chain_fees.get(destination_chain).unwrap_or(0))?;
 
             // Enforce rate limiting
             // For NFT bridge, we count requests but value is 0 here since NFT value isn't strictly defined by amount.
@@ -894,14 +1390,21 @@ mod bridge {
                 caller,
                 recipient,
                 required_signatures,
+                self.config.min_signatures_required,
                 current_block,
                 expires_at,
                 Vec::new(),
-                0,
+                gas_estimate,
                 metadata,
             );
 
             self.bridge_requests.insert(request_id, &request);
+            self.active_request_by_token.insert(token_id, &request_id);
+            self.open_request(request_id, caller);
+            self.record_status(request_id, BridgeOperationStatus::Pending, None);
+            self.escrow_request_fee(request_id, fee_paid);
+            self.hold_gas_deposit(request_id);
+            self.record_metadata_hash(request_id, &request.metadata);
 
             // Initialize cross-chain transaction status: source leg starts in
             // `Submitted`, destination leg has `NotStarted` until a relayer
@@ -913,23 +1416,31 @@ mod bridge {
                 destination_chain,
             );
 
-            self.env().emit_event(BridgeRequestCreated {
+            self.emit_request_created(
                 request_id,
                 token_id,
-                source_chain: request.source_chain,
+                request.source_chain,
                 destination_chain,
-                requester: caller,
-            });
+                caller,
+            );
 
             Ok(request_id)
         }
 
-        /// Initiates a multi-hop bridge request that routes through one or more intermediate chains.
-        #[ink(message)]
-        pub fn initiate_multi_hop_bridge(
+        /// Initiates one bridge request covering several tokens sent to the
+        /// same recipient on `destination_chain`.
+        ///
+        /// Every token is validated up front and the request is only created
+        /// if all of them pass, so no token is left half-submitted. The batch
+        /// is then signed, executed and recovered as a whole, paying a single
+        /// chain fee and gas deposit. `metadata` describes the batch as a
+        /// whole (e.g. its combined valuation for travel-rule checks). At
+        /// most [`MAX_BRIDGE_BATCH_SIZE`] tokens may be included.
+        #[ink(message, payable)]
+        pub fn initiate_bridge_batch(
             &mut self,
-            token_id: TokenId,
-            route: Vec<ChainId>,
+            token_ids: Vec<TokenId>,
+            destination_chain: ChainId,
             recipient: AccountId,
             required_signatures: u8,
             timeout_blocks: Option<u64>,
@@ -939,11 +1450,115 @@ mod bridge {
             self.ensure_not_paused(BridgeOperation::NewRequest)?;
             self.track_request_burst(caller)?;
 
-            if route.len() < 2 {
-                return Err(Error::InvalidChain);
+            if token_ids.is_empty() || token_ids.len() > MAX_BRIDGE_BATCH_SIZE {
+                return Err(Error::BatchTooLarge);
             }
 
-            let current_chain = self.get_current_chain_id();
+            self.validate_destination(destination_chain)?;
+            let gas_estimate =
+                self.validate_request_gas(destination_chain, required_signatures, &metadata)?;
+
+            for (index, token_id) in token_ids.iter().enumerate() {
+                if token_ids[..index].contains(token_id) {
+                    return Err(Error::DuplicateRequest);
+                }
+                self.validate_token_route(*token_id, destination_chain)?;
+                if !self.is_authorized_for_token(caller, *token_id) {
+                    return Err(Error::Unauthorized);
+                }
+                if self.active_request_by_token.contains(*token_id) {
+                    return Err(Error::DuplicateRequest);
+                }
+            }
+            self.check_request_limits(caller, destination_chain)?;
+
+            let fee_paid =
+                self.ensure_fee_paid(self.chain_fees.get(destination_chain).unwrap_or(0))?;
+            self.check_and_update_rate_limits(caller, destination_chain, 0, true)?;
+
+            self.request_counter += 1;
+            let request_id = self.request_counter;
+            let current_block = u64::from(self.env().block_number());
+            let expires_at = timeout_blocks.map(|blocks| current_block + blocks);
+
+            let request = StoredBridgeRequest::new(
+                request_id,
+                token_ids[0],
+                self.get_current_chain_id(),
+                destination_chain,
+                caller,
+                recipient,
+                required_signatures,
+                self.config.min_signatures_required,
+                current_block,
+                expires_at,
+                Vec::new(),
+                gas_estimate,
+                metadata,
+            );
+
+            self.bridge_requests.insert(request_id, &request);
+            self.batch_tokens.insert(request_id, &token_ids);
+            for token_id in token_ids.iter() {
+                self.active_request_by_token.insert(*token_id, &request_id);
+            }
+            self.open_request(request_id, caller);
+            self.record_status(request_id, BridgeOperationStatus::Pending, None);
+            self.escrow_request_fee(request_id, fee_paid);
+            self.hold_gas_deposit(request_id);
+            self.record_metadata_hash(request_id, &request.metadata);
+            self.init_cross_chain_status(
+                request_id,
+                request.token_id,
+                request.source_chain,
+                destination_chain,
+            );
+
+            for token_id in token_ids {
+                self.emit_request_created(
+                    request_id,
+                    token_id,
+                    request.source_chain,
+                    destination_chain,
+                    caller,
+                );
+            }
+
+            Ok(request_id)
+        }
+
+        /// Returns every token carried by a request: the full set for a
+        /// batch, otherwise just the request's own token.
+        #[ink(message)]
+        pub fn get_request_tokens(&self, request_id: u64) -> Vec<TokenId> {
+            self.bridge_requests
+                .get(request_id)
+                .map(|request| self.request_token_ids(&request))
+                .unwrap_or_default()
+        }
+
+        /// Initiates a multi-hop bridge request that routes through one or more intermediate chains.
+        /// The value sent must cover the sum of the fees of every chain on the route
+        /// plus the gas deposit.
+        #[ink(message, payable)]
+        pub fn initiate_multi_hop_bridge(
+            &mut self,
+            token_id: TokenId,
+            route: Vec<ChainId>,
+            recipient: AccountId,
+            required_signatures: u8,
+            timeout_blocks: Option<u64>,
+            metadata: PropertyMetadata,
+        ) -> Result<u64, Error> {
+            let caller = self.env().caller();
+            self.ensure_not_paused(BridgeOperation::NewRequest)?;
+            self.track_request_burst(caller)?;
+
+            if route.len() < 2 {
+                return Err(Error::InvalidChain);
+            }
+
+            let current_chain = self.get_current_chain_id();
             if route[0] == current_chain {
                 return Err(Error::InvalidChain);
             }
@@ -953,6 +1568,13 @@ mod bridge {
             {
                 return Err(Error::InvalidChain);
             }
+            for chain in route.iter() {
+                self.ensure_chain_active(*chain)?;
+                if !self.is_token_supported(*chain, token_id) {
+                    return Err(Error::BridgeNotSupported);
+                }
+            }
+            self.ensure_onward_route_allowed(token_id, &route)?;
 
             if required_signatures < self.config.min_signatures_required
                 || required_signatures > self.config.max_signatures_required
@@ -964,6 +1586,16 @@ mod bridge {
                 return Err(Error::Unauthorized);
             }
 
+            if self.active_request_by_token.contains(token_id) {
+                return Err(Error::DuplicateRequest);
+            }
+            self.check_request_limits(caller, route[0])?;
+
+            let route_fee = route.iter().fold(0u128, |total, chain| {
+                total.saturating_add(self.chain_fees.get(*chain).unwrap_or(0))
+            });
+            let fee_paid = self.ensure_fee_paid(route_fee)?;
+
             self.check_and_update_rate_limits(caller, *route.last().unwrap(), 0, true)?;
 
             // Check if asset is frozen (skipped: token_id is u64, freeze uses AccountId; see bridge/src/lib.rs helpers)
@@ -983,6 +1615,7 @@ mod bridge {
                 caller,
                 recipient,
                 required_signatures,
+                self.config.min_signatures_required,
                 current_block,
                 expires_at,
                 route.clone(),
@@ -991,15 +1624,15 @@ mod bridge {
             );
 
             self.bridge_requests.insert(request_id, &request);
+            self.active_request_by_token.insert(token_id, &request_id);
+            self.open_request(request_id, caller);
+            self.record_status(request_id, BridgeOperationStatus::Pending, None);
+            self.escrow_request_fee(request_id, fee_paid);
+            self.hold_gas_deposit(request_id);
+            self.record_metadata_hash(request_id, &request.metadata);
             self.init_cross_chain_status(request_id, token_id, current_chain, route[0]);
 
-            self.env().emit_event(BridgeRequestCreated {
-                request_id,
-                token_id,
-                source_chain: current_chain,
-                destination_chain: route[0],
-                requester: caller,
-            });
+            self.emit_request_created(request_id, token_id, current_chain, route[0], caller);
 
             Ok(request_id)
         }
@@ -1033,61 +1666,153 @@ mod bridge {
         #[ink(message)]
         pub fn sign_bridge_request(&mut self, request_id: u64, approve: bool) -> Result<(), Error> {
             let caller = self.env().caller();
+            self.record_signature(caller, request_id, approve)
+        }
 
-            // Granular pause check: blocks if `signing` or `all_operations`
-            // is set (or the legacy emergency-pause boolean).
-            self.ensure_not_paused(BridgeOperation::Signing)?;
-
-            // Check if caller is a registered validator (issue #203: only validators may sign)
-            if !self.validators.contains(&caller) {
-                return Err(Error::Unauthorized);
-            }
-
-            let mut request = self
+        /// Submits an approval signed offline by a bridge operator.
+        ///
+        /// `signature` is a 65-byte recoverable ECDSA signature over
+        /// [`request_digest`]. The signer is identified by the
+        /// recovered public key, so anyone may relay the signature. The
+        /// operator must also be a validator, and the approval is counted
+        /// exactly like a call to [`sign_bridge_request`].
+        #[ink(message)]
+        pub fn submit_operator_signature(
+            &mut self,
+            request_id: u64,
+            signature: [u8; 65],
+        ) -> Result<(), Error> {
+            let request = self
                 .bridge_requests
                 .get(request_id)
                 .ok_or(Error::InvalidRequest)?;
-            self.normalize_signature_storage(&mut request)?;
-
-            // Check if request has expired
-            if let Some(expires_at) = request.expires_at {
-                if u64::from(self.env().block_number()) > expires_at {
-                    return Err(Error::RequestExpired);
-                }
-            }
-
-            // Check if already signed
-            let bit_position = self.get_validator_bit_position(caller)?;
-            if self.request_has_signature(&request, caller, bit_position) {
-                return Err(Error::AlreadySigned);
-            }
-
-            self.add_request_signature(&mut request, caller, bit_position)?;
+            let payload = self.request_digest_of(&request);
+            let public_key = propchain_traits::crypto::verify_ecdsa_signature(
+                &signature,
+                &<[u8; 32]>::from(payload),
+            )
+            .map_err(|_| Error::InvalidSignature)?;
+            let operator = self
+                .operator_by_public_key
+                .get(public_key)
+                .filter(|operator| self.bridge_operators.contains(operator))
+                .ok_or(Error::InvalidSignature)?;
+
+            self.record_signature(operator, request_id, true)
+        }
 
-            // Update status based on approval and signatures collected
-            if !approve {
-                request.status = BridgeOperationStatus::Failed;
-                request.multi_hop_status = MultiHopStatus::Failed;
-            } else if request.signature_count() >= request.required_signatures {
-                request.status = BridgeOperationStatus::Locked;
-            }
+        /// Returns the hash operators sign to approve `request_id` offline.
+        /// Same as [`request_digest`].
+        #[ink(message)]
+        pub fn get_operator_signing_payload(&self, request_id: u64) -> Option<Hash> {
+            self.request_digest(request_id)
+        }
 
-            self.bridge_requests.insert(request_id, &request);
+        /// Returns the Blake2x256 digest of [`encode_request`], the value
+        /// relayers, destination chains and operators agree on.
+        ///
+        /// The encoding binds the request's routing fields, metadata and
+        /// current hop, so a digest cannot be replayed against another
+        /// request, hop or bridge deployment.
+        #[ink(message)]
+        pub fn request_digest(&self, request_id: u64) -> Option<Hash> {
+            self.bridge_requests
+                .get(request_id)
+                .map(|request| self.request_digest_of(&request))
+        }
 
-            // Suspicious-activity heuristic: surge of failed-signature votes
-            // may indicate validator compromise or coordinated attack.
-            if !approve {
-                self.track_failed_signature(caller);
-            }
+        /// Returns the versioned canonical encoding of `request_id`, so
+        /// external verifiers can recompute [`request_digest`].
+        #[ink(message)]
+        pub fn encode_request(&self, request_id: u64) -> Option<Vec<u8>> {
+            self.bridge_requests
+                .get(request_id)
+                .map(|request| self.canonical_request_encoding(&request))
+        }
 
-            self.env().emit_event(BridgeRequestSigned {
+        /// Dry-runs `initiate_bridge_multisig` for the caller without
+        /// changing state, so relayers can test their pipeline without real
+        /// signatures.
+        ///
+        /// Runs the same preconditions (except fee payment and the burst
+        /// heuristic) and returns the encoding, digest and outbound payload
+        /// the request would get once created and executed.
+        #[ink(message)]
+        pub fn simulate_bridge(
+            &self,
+            token_id: TokenId,
+            destination_chain: ChainId,
+            recipient: AccountId,
+            required_signatures: u8,
+            metadata: PropertyMetadata,
+        ) -> SimulationResult {
+            let caller = self.env().caller();
+            let error = self
+                .ensure_not_paused(BridgeOperation::NewRequest)
+                .and_then(|()| {
+                    self.validate_new_request(
+                        caller,
+                        token_id,
+                        destination_chain,
+                        required_signatures,
+                        &metadata,
+                    )
+                })
+                .and_then(|_| self.request_limit_window(caller, destination_chain))
+                .and_then(|_| self.check_rate_limits(caller, destination_chain, 0, true))
+                .err();
+
+            let request_id = self.request_counter + 1;
+            let gas_estimate = self
+                .validate_destination(destination_chain)
+                .and_then(|()| {
+                    self.validate_request_gas(destination_chain, required_signatures, &metadata)
+                })
+                .unwrap_or(0);
+            let request = StoredBridgeRequest::new(
                 request_id,
-                signer: caller,
-                signatures_collected: request.signature_count(),
-                signatures_required: request.required_signatures,
-            });
+                token_id,
+                self.get_current_chain_id(),
+                destination_chain,
+                caller,
+                recipient,
+                required_signatures,
+                self.config.min_signatures_required,
+                u64::from(self.env().block_number()),
+                None,
+                Vec::new(),
+                gas_estimate,
+                metadata,
+            );
+            let travel_rule_threshold = self
+                .travel_rule_thresholds
+                .get(destination_chain)
+                .unwrap_or(u128::MAX);
 
-            Ok(())
+            SimulationResult {
+                request_id,
+                fee: self
+                    .chain_fees
+                    .get(destination_chain)
+                    .unwrap_or(0)
+                    .saturating_add(self.gas_deposit_amount),
+                gas_estimate,
+                authorized: self.is_authorized_for_token(caller, token_id),
+                compliant: self
+                    .validate_token_route(token_id, destination_chain)
+                    .is_ok(),
+                duplicate: self.active_request_by_token.contains(token_id),
+                travel_rule_data_required: request.metadata.valuation > travel_rule_threshold,
+                error,
+                canonical_request: self.canonical_request_encoding(&request),
+                digest: self.request_digest_of(&request),
+                outbound_payload: scale::Encode::encode(&self.outbound_payload(
+                    &request,
+                    request_id,
+                    request.source_chain,
+                    destination_chain,
+                )),
+            }
         }
 
         /// Register an ECDSA public key for cryptographic signature verification.
@@ -1097,8 +1822,7 @@ mod bridge {
             if !self.bridge_operators.contains(&caller) {
                 return Err(Error::Unauthorized);
             }
-            self.operator_public_keys.insert(caller, &public_key);
-            Ok(())
+            self.set_operator_public_key(caller, public_key)
         }
 
         /// Sign a bridge request with optional ECDSA cryptographic signature verification.
@@ -1152,21 +1876,40 @@ mod bridge {
                     .bridge_requests
                     .get(request_id)
                     .ok_or(Error::InvalidRequest)?;
+                self.ensure_not_frozen(request_id)?;
                 self.normalize_signature_storage(&mut request)?;
 
-                // Check if request is ready for execution
-                if request.status != BridgeOperationStatus::Locked {
+                // Check if request is ready for execution. A pending request
+                // may have become executable after silent validators dropped
+                // out of the liveness window.
+                if !self.lock_if_signed(&mut request) {
                     return Err(Error::InvalidRequest);
                 }
 
+                self.ensure_chain_active(request.destination_chain)?;
+                if let Some(expected) = self.metadata_hashes.get(request_id) {
+                    if propchain_traits::crypto::hash_encoded(&request.metadata) != expected {
+                        return Err(Error::MetadataCorruption);
+                    }
+                }
+
                 // Check if enough signatures are collected
-                if request.signature_count() < request.required_signatures {
+                if request.signature_count() < self.effective_required_signatures(&request) {
                     return Err(Error::InsufficientSignatures);
                 }
 
                 // FATF travel rule compliance check
                 self.ensure_travel_rule_compliance(request_id, &request)?;
 
+                // Limits or chain profiles may have changed since the
+                // request was created, so check the hop about to be relayed.
+                let gas_estimate = self.estimate_request_gas(
+                    request.destination_chain,
+                    &request.metadata,
+                    request.required_signatures,
+                )?;
+                self.ensure_within_gas_limit(request.destination_chain, gas_estimate)?;
+
                 // Check if asset is frozen (skipped: token_id is u64, freeze uses AccountId; see bridge/src/lib.rs helpers)
 
                 // Generate transaction hash
@@ -1185,7 +1928,7 @@ mod bridge {
                     recipient: request.recipient,
                     transaction_hash,
                     timestamp: self.env().block_timestamp(),
-                    gas_used: self.estimate_gas_usage(&request),
+                    gas_used: gas_estimate,
                     status: BridgeOperationStatus::InTransit,
                     metadata: request.metadata.clone(),
                 };
@@ -1196,21 +1939,28 @@ mod bridge {
                 if is_last_hop {
                     request.status = BridgeOperationStatus::Completed;
                     request.multi_hop_status = MultiHopStatus::HopCompleted;
+                    self.record_status(request_id, BridgeOperationStatus::Completed, None);
+                    self.clear_active_request(&request);
+                    self.release_request_fee(request_id, caller);
+                    self.pay_gas_deposit(request_id, caller)?;
                 } else {
                     request.current_hop += 1;
                     request.source_chain = old_destination_chain;
                     request.destination_chain = request.route[request.current_hop as usize];
                     request.status = BridgeOperationStatus::Pending;
                     request.clear_signatures();
+                    self.record_status(request_id, BridgeOperationStatus::Pending, None);
+                    self.request_signer_epoch
+                        .insert(request_id, &self.signer_epoch);
                     request.multi_hop_status = MultiHopStatus::InProgress;
 
-                    self.env().emit_event(BridgeRequestCreated {
+                    self.emit_request_created(
                         request_id,
-                        token_id: request.token_id,
-                        source_chain: request.source_chain,
-                        destination_chain: request.destination_chain,
-                        requester: request.sender,
-                    });
+                        request.token_id,
+                        request.source_chain,
+                        request.destination_chain,
+                        request.sender,
+                    );
                 }
 
                 self.bridge_requests.insert(request_id, &request);
@@ -1235,14 +1985,23 @@ mod bridge {
                 }
 
                 // Add to bridge history
-                let mut history = self.bridge_history.get(request.sender).unwrap_or_default();
-                history.push(transaction.clone());
-                self.bridge_history.insert(request.sender, &history);
+                self.record_transaction(request.sender, &transaction);
 
-                self.env().emit_event(BridgeExecuted {
+                for token_id in self.request_token_ids(&request) {
+                    self.emit_executed(request_id, token_id, transaction_hash);
+                }
+
+                let payload = scale::Encode::encode(&self.outbound_payload(
+                    &request,
                     request_id,
-                    token_id: request.token_id,
-                    transaction_hash,
+                    old_source_chain,
+                    old_destination_chain,
+                ));
+                self.outbound_payloads.insert(request_id, &payload);
+                self.env().emit_event(BridgeMessage {
+                    request_id,
+                    destination_chain: old_destination_chain,
+                    payload,
                 });
 
                 Ok(())
@@ -1281,22 +2040,59 @@ mod bridge {
                 // Execute recovery action
                 match recovery_action {
                     RecoveryAction::UnlockToken => {
-                        // Logic to unlock the token would be implemented here
-                        // This would typically call back to the property token contract
+                        // The property token contract is expected to follow
+                        // `TokenUnlockedFromBridge` and restore ownership.
+                        self.release_token_lock(&request);
                     }
                     RecoveryAction::RefundGas => {
-                        // Logic to refund gas costs would be implemented here
+                        let mut deposit = self
+                            .gas_deposits
+                            .get(request_id)
+                            .filter(|deposit| !deposit.settled && deposit.amount > 0)
+                            .ok_or(Error::InvalidRequest)?;
+                        if self.env().transfer(request.sender, deposit.amount).is_err() {
+                            return Err(Error::FeeTransferFailed);
+                        }
+                        deposit.settled = true;
+                        self.gas_deposits.insert(request_id, &deposit);
                     }
                     RecoveryAction::RetryBridge => {
-                        // Reset request to pending for retry
+                        // A token may have been re-bridged since this
+                        // request failed.
+                        let token_ids = self.request_token_ids(&request);
+                        for token_id in token_ids.iter() {
+                            match self.active_request_by_token.get(*token_id) {
+                                Some(active) if active != request_id => {
+                                    return Err(Error::DuplicateRequest);
+                                }
+                                _ => {}
+                            }
+                        }
+                        for token_id in token_ids.iter() {
+                            self.active_request_by_token.insert(*token_id, &request_id);
+                        }
+                        self.open_request(request_id, request.sender);
+
+                        // Reset request to pending for retry; it locks again
+                        // once re-signed.
+                        self.release_token_lock(&request);
                         request.status = BridgeOperationStatus::Pending;
                         request.multi_hop_status = MultiHopStatus::InProgress;
                         request.clear_signatures();
+                        self.record_status(request_id, BridgeOperationStatus::Pending, None);
                     }
                     RecoveryAction::CancelBridge => {
                         // Mark as cancelled
                         request.status = BridgeOperationStatus::Failed;
                         request.multi_hop_status = MultiHopStatus::Failed;
+                        self.record_status(
+                            request_id,
+                            BridgeOperationStatus::Failed,
+                            Some(String::from("Cancelled during recovery")),
+                        );
+                        self.release_token_lock(&request);
+                        self.clear_active_request(&request);
+                        self.refund_request_fee(request_id, request.sender);
                     }
                 }
 
@@ -1311,6 +2107,418 @@ mod bridge {
             })
         }
 
+        // ── Request limits ────────────────────────────────────────────────
+
+        /// Sets limits on open requests and per-chain request rate (admin only).
+        #[ink(message)]
+        pub fn set_request_limits(&mut self, limits: RequestLimits) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.request_limits = limits;
+            Ok(())
+        }
+
+        /// Returns the configured request limits.
+        #[ink(message)]
+        pub fn get_request_limits(&self) -> RequestLimits {
+            self.request_limits.clone()
+        }
+
+        /// Returns the number of non-terminal requests created by `sender`.
+        #[ink(message)]
+        pub fn get_open_request_count(&self, sender: AccountId) -> u32 {
+            self.open_requests_by_sender.get(sender).unwrap_or(0)
+        }
+
+        /// Returns the number of non-terminal requests across the bridge.
+        #[ink(message)]
+        pub fn get_total_open_requests(&self) -> u32 {
+            self.open_requests.len() as u32
+        }
+
+        // ── Bridge fees ───────────────────────────────────────────────────
+
+        /// Sets the fee charged for bridging to `chain_id` (admin only).
+        #[ink(message)]
+        pub fn set_chain_fee(&mut self, chain_id: ChainId, fee: u128) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if !self.config.supported_chains.contains(&chain_id) {
+                return Err(Error::InvalidChain);
+            }
+            self.chain_fees.insert(chain_id, &fee);
+            self.env().emit_event(ChainFeeUpdated { chain_id, fee });
+            Ok(())
+        }
+
+        /// Returns the fee charged for bridging to `chain_id`.
+        #[ink(message)]
+        pub fn get_chain_fee(&self, chain_id: ChainId) -> u128 {
+            self.chain_fees.get(chain_id).unwrap_or(0)
+        }
+
+        /// Returns the non-terminal bridge request holding `token_id`, if any.
+        #[ink(message)]
+        pub fn get_active_request(&self, token_id: TokenId) -> Option<u64> {
+            self.active_request_by_token.get(token_id)
+        }
+
+        /// Sets the refundable gas deposit charged with every new request
+        /// (admin only). Applies to requests created afterwards.
+        #[ink(message)]
+        pub fn set_gas_deposit(&mut self, amount: u128) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.gas_deposit_amount = amount;
+            Ok(())
+        }
+
+        /// Returns the gas deposit charged with every new request.
+        #[ink(message)]
+        pub fn get_gas_deposit(&self) -> u128 {
+            self.gas_deposit_amount
+        }
+
+        /// Returns the gas deposit the admin could refund for `request_id`
+        /// right now: the unsettled deposit of a failed or expired request,
+        /// otherwise zero.
+        #[ink(message)]
+        pub fn get_refundable_amount(&self, request_id: u64) -> u128 {
+            let Some(request) = self.bridge_requests.get(request_id) else {
+                return 0;
+            };
+            if !matches!(
+                request.status,
+                BridgeOperationStatus::Failed | BridgeOperationStatus::Expired
+            ) {
+                return 0;
+            }
+            self.gas_deposits
+                .get(request_id)
+                .filter(|deposit| !deposit.settled)
+                .map_or(0, |deposit| deposit.amount)
+        }
+
+        /// Returns the fee currently escrowed for `request_id`.
+        #[ink(message)]
+        pub fn get_request_fee(&self, request_id: u64) -> u128 {
+            self.request_fees.get(request_id).unwrap_or(0)
+        }
+
+        /// Returns the aggregate fee accounting.
+        #[ink(message)]
+        pub fn get_fee_accounting(&self) -> FeeAccounting {
+            self.fee_accounting.clone()
+        }
+
+        /// Returns the released fees `operator` can withdraw.
+        #[ink(message)]
+        pub fn get_operator_fee_balance(&self, operator: AccountId) -> u128 {
+            self.operator_fee_balances.get(operator).unwrap_or(0)
+        }
+
+        /// Returns refunds owed to `account` that are awaiting withdrawal.
+        #[ink(message)]
+        pub fn get_refund_balance(&self, account: AccountId) -> u128 {
+            self.refund_balances.get(account).unwrap_or(0)
+        }
+
+        /// Withdraws the caller's share of released bridge fees.
+        #[ink(message)]
+        pub fn claim_operator_fees(&mut self) -> Result<u128, Error> {
+            non_reentrant!(self, {
+                let caller = self.env().caller();
+                let amount = self.operator_fee_balances.get(caller).unwrap_or(0);
+                if amount == 0 {
+                    return Err(Error::InvalidRequest);
+                }
+
+                self.operator_fee_balances.remove(caller);
+                if self.env().transfer(caller, amount).is_err() {
+                    self.operator_fee_balances.insert(caller, &amount);
+                    return Err(Error::FeeTransferFailed);
+                }
+                self.fee_accounting.claimable =
+                    self.fee_accounting.claimable.saturating_sub(amount);
+                self.fee_accounting.claimed = self.fee_accounting.claimed.saturating_add(amount);
+
+                self.env().emit_event(OperatorFeesClaimed {
+                    operator: caller,
+                    amount,
+                });
+                Ok(amount)
+            })
+        }
+
+        /// Withdraws refunds that could not be paid out automatically.
+        #[ink(message)]
+        pub fn claim_refund(&mut self) -> Result<u128, Error> {
+            non_reentrant!(self, {
+                let caller = self.env().caller();
+                let amount = self.refund_balances.get(caller).unwrap_or(0);
+                if amount == 0 {
+                    return Err(Error::InvalidRequest);
+                }
+
+                self.refund_balances.remove(caller);
+                if self.env().transfer(caller, amount).is_err() {
+                    self.refund_balances.insert(caller, &amount);
+                    return Err(Error::FeeTransferFailed);
+                }
+                Ok(amount)
+            })
+        }
+
+        // ── Inbound transfers ─────────────────────────────────────────────
+
+        /// Sets the property token contract that receives inbound transfers
+        /// (admin only).
+        #[ink(message)]
+        pub fn set_token_contract(
+            &mut self,
+            token_contract: Option<AccountId>,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.token_contract = token_contract;
+            Ok(())
+        }
+
+        /// Returns the configured property token contract, if any.
+        #[ink(message)]
+        pub fn get_token_contract(&self) -> Option<AccountId> {
+            self.token_contract
+        }
+
+        /// Returns the tokens the bridge currently holds locked for `owner`.
+        #[ink(message)]
+        pub fn get_locked_tokens(&self, owner: AccountId) -> Vec<TokenId> {
+            self.locked_tokens_by_owner.get(owner).unwrap_or_default()
+        }
+
+        /// Returns the origin chain and token ID of a token that was bridged
+        /// in, or `None` for tokens native to this chain.
+        #[ink(message)]
+        pub fn get_token_origin(&self, token_id: TokenId) -> Option<(ChainId, TokenId)> {
+            self.token_origins.get(token_id)
+        }
+
+        /// Allows or forbids bridging bridged-in tokens on to chains other
+        /// than their origin (admin only).
+        #[ink(message)]
+        pub fn set_allow_bridged_multi_hop(&mut self, allowed: bool) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.allow_bridged_multi_hop = allowed;
+            Ok(())
+        }
+
+        /// Returns whether bridged-in tokens may be sent on to third chains.
+        #[ink(message)]
+        pub fn is_bridged_multi_hop_allowed(&self) -> bool {
+            self.allow_bridged_multi_hop
+        }
+
+        /// Returns the canonical hash operators sign to attest an inbound
+        /// transfer. The local chain ID is part of the payload so a quorum
+        /// gathered for one deployment cannot be replayed on another.
+        /// `metadata_hash` is the hash carried by the source chain's
+        /// [`OutboundPayload`], so operators attest to it along with the
+        /// metadata itself.
+        #[ink(message)]
+        pub fn get_inbound_message_hash(
+            &self,
+            source_chain: ChainId,
+            source_request_id: u64,
+            token_id: TokenId,
+            recipient: AccountId,
+            metadata: PropertyMetadata,
+            metadata_hash: Hash,
+        ) -> Hash {
+            propchain_traits::crypto::hash_encoded(&(
+                b"propchain:bridge:inbound",
+                source_chain,
+                self.chain_id,
+                source_request_id,
+                token_id,
+                recipient,
+                metadata,
+                metadata_hash,
+            ))
+        }
+
+        /// Returns whether the inbound message has already been finalized.
+        #[ink(message)]
+        pub fn is_inbound_processed(&self, source_chain: ChainId, source_request_id: u64) -> bool {
+            self.processed_inbound
+                .get((source_chain, source_request_id))
+                .unwrap_or(false)
+        }
+
+        /// Finalizes a transfer relayed from `source_chain`.
+        ///
+        /// Each entry in `operator_signatures` is a bridge operator together
+        /// with its recoverable ECDSA signature over
+        /// [`get_inbound_message_hash`]. At least `min_signatures_required`
+        /// distinct operators must sign with their registered public keys.
+        /// The `(source_chain, source_request_id)` pair can only be
+        /// finalized once. When a token contract is configured the token is
+        /// handed over via [`BridgedTokenReceiver::receive_bridged_token`].
+        ///
+        /// `metadata_hash` is the hash carried by the source chain's
+        /// [`OutboundPayload`] and is covered by the operator signatures.
+        /// While `BridgeConfig::metadata_preservation` is on, `metadata` must
+        /// hash to it or the transfer is rejected with
+        /// [`Error::MetadataCorruption`].
+        #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
+        pub fn finalize_inbound(
+            &mut self,
+            source_chain: ChainId,
+            source_request_id: u64,
+            token_id: TokenId,
+            recipient: AccountId,
+            metadata: PropertyMetadata,
+            metadata_hash: Hash,
+            operator_signatures: Vec<(AccountId, [u8; 65])>,
+        ) -> Result<Option<TokenId>, Error> {
+            non_reentrant!(self, {
+                self.ensure_not_paused(BridgeOperation::Execution)?;
+
+                if source_chain == self.get_current_chain_id()
+                    || !self.config.supported_chains.contains(&source_chain)
+                {
+                    return Err(Error::InvalidChain);
+                }
+                self.ensure_chain_active(source_chain)?;
+
+                let key = (source_chain, source_request_id);
+                if self.processed_inbound.get(key).unwrap_or(false) {
+                    return Err(Error::InboundAlreadyProcessed);
+                }
+
+                let verify_metadata = self.config.metadata_preservation;
+                if verify_metadata
+                    && propchain_traits::crypto::hash_encoded(&metadata) != metadata_hash
+                {
+                    return Err(Error::MetadataCorruption);
+                }
+
+                let message_hash: [u8; 32] = self
+                    .get_inbound_message_hash(
+                        source_chain,
+                        source_request_id,
+                        token_id,
+                        recipient,
+                        metadata.clone(),
+                        metadata_hash,
+                    )
+                    .into();
+
+                let mut signers: Vec<AccountId> = Vec::new();
+                for (operator, signature) in operator_signatures.iter() {
+                    if signers.contains(operator) {
+                        continue;
+                    }
+                    if !self.bridge_operators.contains(operator) {
+                        return Err(Error::InvalidSignature);
+                    }
+                    let expected_key = self
+                        .operator_public_keys
+                        .get(*operator)
+                        .ok_or(Error::InvalidSignature)?;
+                    let approval = propchain_traits::SignedApproval {
+                        signature: *signature,
+                        message_hash,
+                    };
+                    propchain_traits::crypto::verify_signed_approval(&approval, &expected_key)
+                        .map_err(|_| Error::InvalidSignature)?;
+                    signers.push(*operator);
+                }
+
+                let threshold = self.config.min_signatures_required.max(1);
+                if signers.len() < threshold as usize {
+                    return Err(Error::InsufficientSignatures);
+                }
+
+                // Record before handing over the token so a re-entrant or
+                // repeated relay cannot finalize the same message twice.
+                self.processed_inbound.insert(key, &true);
+
+                let local_token_id = match self.token_contract {
+                    Some(token_contract) => {
+                        use ink::env::call::FromAccountId;
+                        let mut receiver: ink::contract_ref!(BridgedTokenReceiver) =
+                            FromAccountId::from_account_id(token_contract);
+                        let minted = receiver
+                            .receive_bridged_token(
+                                source_chain,
+                                token_id,
+                                recipient,
+                                metadata.clone(),
+                            )
+                            .ok_or(Error::BridgeNotSupported)?;
+                        Some(minted)
+                    }
+                    None => None,
+                };
+
+                // A token returning to a chain it already passed through
+                // keeps the provenance recorded on its first arrival.
+                let local_id = local_token_id.unwrap_or(token_id);
+                let origin = match self.token_origins.get(local_id) {
+                    Some(origin) => origin,
+                    None => {
+                        self.token_origins
+                            .insert(local_id, &(source_chain, token_id));
+                        (source_chain, token_id)
+                    }
+                };
+
+                let timestamp = self.env().block_timestamp();
+                self.transaction_counter += 1;
+                let transaction = BridgeTransaction {
+                    transaction_id: self.transaction_counter,
+                    token_id: local_id,
+                    source_chain,
+                    destination_chain: self.chain_id,
+                    sender: recipient,
+                    recipient,
+                    transaction_hash: Hash::from(message_hash),
+                    timestamp,
+                    gas_used: 0,
+                    status: BridgeOperationStatus::Completed,
+                    metadata,
+                };
+                self.record_transaction(recipient, &transaction);
+
+                if verify_metadata {
+                    self.env().emit_event(MetadataVerified {
+                        source_chain,
+                        request_id: source_request_id,
+                        metadata_hash,
+                    });
+                }
+                self.env().emit_event(InboundBridgeFinalized {
+                    source_chain,
+                    source_request_id,
+                    recipient,
+                    token_id,
+                    local_token_id,
+                    origin,
+                    signatures: signers.len() as u8,
+                    timestamp,
+                });
+
+                Ok(local_token_id)
+            })
+        }
+
         // ── Travel rule (FATF) messages ────────────────────────────────────────
 
         /// Submit travel rule data for a bridge request (must be called before
@@ -1435,40 +2643,227 @@ mod bridge {
                     _ => {}
                 }
 
-                // Step 1: mark as Recovering to prevent concurrent rollbacks
-                request.status = BridgeOperationStatus::Recovering;
-                self.bridge_requests.insert(request_id, &request);
-
-                // Step 2: clear signatures so the request cannot be re-executed
+                // Step 1: mark as Recovering to prevent concurrent rollbacks
+                request.status = BridgeOperationStatus::Recovering;
+                self.bridge_requests.insert(request_id, &request);
+
+                // Step 2: clear signatures so the request cannot be re-executed
+                self.normalize_signature_storage(&mut request)?;
+                request.clear_signatures();
+
+                // Step 3: mark as Failed (terminal rollback state)
+                request.status = BridgeOperationStatus::Failed;
+                self.bridge_requests.insert(request_id, &request);
+                self.record_status(
+                    request_id,
+                    BridgeOperationStatus::Failed,
+                    Some(reason.clone()),
+                );
+                self.release_token_lock(&request);
+                self.clear_active_request(&request);
+                self.refund_request_fee(request_id, request.sender);
+
+                // Step 4 + 5: emit structured rollback event for indexers
+                self.env().emit_event(BridgeRolledBack {
+                    request_id,
+                    token_id: request.token_id,
+                    requester: request.sender,
+                    reason,
+                    rolled_back_at: self.env().block_number(),
+                });
+
+                // Mark both source and destination legs as Failed in the
+                // cross-chain tracker so external observers see a terminal
+                // state instead of stale in-flight statuses.
+                self.fail_cross_chain_status(
+                    request_id,
+                    request.source_chain,
+                    request.destination_chain,
+                );
+
+                Ok(())
+            })
+        }
+
+        /// Cancels a request that has not collected its signature quorum yet.
+        /// Only the original sender may cancel; the escrowed fee is refunded.
+        #[ink(message)]
+        pub fn cancel_bridge_request(&mut self, request_id: u64) -> Result<(), Error> {
+            non_reentrant!(self, {
+                let caller = self.env().caller();
+                let mut request = self
+                    .bridge_requests
+                    .get(request_id)
+                    .ok_or(Error::InvalidRequest)?;
+                if caller != request.sender {
+                    return Err(Error::Unauthorized);
+                }
+                self.ensure_not_frozen(request_id)?;
+                if request.status != BridgeOperationStatus::Pending {
+                    return Err(Error::InvalidRequest);
+                }
+
                 self.normalize_signature_storage(&mut request)?;
                 request.clear_signatures();
-
-                // Step 3: mark as Failed (terminal rollback state)
                 request.status = BridgeOperationStatus::Failed;
+                request.multi_hop_status = MultiHopStatus::Failed;
                 self.bridge_requests.insert(request_id, &request);
-
-                // Step 4 + 5: emit structured rollback event for indexers
-                self.env().emit_event(BridgeRolledBack {
+                self.record_status(
                     request_id,
-                    token_id: request.token_id,
-                    requester: request.sender,
-                    reason,
-                    rolled_back_at: self.env().block_number(),
-                });
-
-                // Mark both source and destination legs as Failed in the
-                // cross-chain tracker so external observers see a terminal
-                // state instead of stale in-flight statuses.
+                    BridgeOperationStatus::Failed,
+                    Some(String::from("Cancelled by requester")),
+                );
+                self.clear_active_request(&request);
+                self.refund_request_fee(request_id, request.sender);
                 self.fail_cross_chain_status(
                     request_id,
                     request.source_chain,
                     request.destination_chain,
                 );
 
+                self.env().emit_event(BridgeRequestCancelled {
+                    request_id,
+                    token_id: request.token_id,
+                    requester: request.sender,
+                });
                 Ok(())
             })
         }
 
+        /// Marks every listed request that is past its `expires_at` block and
+        /// still awaiting execution as `Expired`, refunding its fee. Callable
+        /// by anyone; ids that are unknown, not yet expired, or already in a
+        /// terminal state are skipped. Returns the ids that were expired.
+        #[ink(message)]
+        pub fn expire_requests(&mut self, request_ids: Vec<u64>) -> Result<Vec<u64>, Error> {
+            non_reentrant!(self, {
+                let current_block = u64::from(self.env().block_number());
+                let mut expired = Vec::new();
+
+                for request_id in request_ids {
+                    let Some(mut request) = self.bridge_requests.get(request_id) else {
+                        continue;
+                    };
+                    if !matches!(
+                        request.status,
+                        BridgeOperationStatus::Pending | BridgeOperationStatus::Locked
+                    ) || self.frozen_requests.contains(request_id)
+                    {
+                        continue;
+                    }
+                    match request.expires_at {
+                        Some(expires_at) if current_block > expires_at => {}
+                        _ => continue,
+                    }
+
+                    request.status = BridgeOperationStatus::Expired;
+                    request.multi_hop_status = MultiHopStatus::Failed;
+                    self.bridge_requests.insert(request_id, &request);
+                    self.record_status(
+                        request_id,
+                        BridgeOperationStatus::Expired,
+                        Some(String::from("Expired before execution")),
+                    );
+                    self.release_token_lock(&request);
+                    self.clear_active_request(&request);
+                    self.refund_request_fee(request_id, request.sender);
+                    self.fail_cross_chain_status(
+                        request_id,
+                        request.source_chain,
+                        request.destination_chain,
+                    );
+
+                    self.env().emit_event(BridgeRequestExpired {
+                        request_id,
+                        token_id: request.token_id,
+                        requester: request.sender,
+                        expired_at: self.env().block_number(),
+                    });
+                    expired.push(request_id);
+                }
+
+                Ok(expired)
+            })
+        }
+
+        /// Halts a single pending or locked request without pausing its
+        /// corridor (admin or security officer). While frozen the request
+        /// cannot be signed, executed, cancelled or expired. The reason is
+        /// recorded in the request's status history.
+        #[ink(message)]
+        pub fn freeze_request(&mut self, request_id: u64, reason: String) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.ensure_security_officer(caller)?;
+            if reason.is_empty()
+                || reason.len() > propchain_traits::constants::MAX_REASON_LENGTH as usize
+            {
+                return Err(Error::InvalidRequest);
+            }
+            let request = self
+                .bridge_requests
+                .get(request_id)
+                .ok_or(Error::InvalidRequest)?;
+            self.ensure_not_frozen(request_id)?;
+            if !matches!(
+                request.status,
+                BridgeOperationStatus::Pending | BridgeOperationStatus::Locked
+            ) {
+                return Err(Error::InvalidRequest);
+            }
+
+            self.frozen_requests
+                .insert(request_id, &(reason.clone(), self.env().block_number()));
+            self.record_status(
+                request_id,
+                request.status,
+                Some(ink::prelude::format!("Frozen: {}", reason)),
+            );
+            self.env().emit_event(BridgeRequestFrozen {
+                request_id,
+                frozen_by: caller,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// Releases a frozen request (admin or security officer). Its
+        /// deadline is pushed back by the blocks it spent frozen.
+        #[ink(message)]
+        pub fn unfreeze_request(&mut self, request_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            self.ensure_security_officer(caller)?;
+            let (_, frozen_at) = self
+                .frozen_requests
+                .get(request_id)
+                .ok_or(Error::InvalidRequest)?;
+            let mut request = self
+                .bridge_requests
+                .get(request_id)
+                .ok_or(Error::InvalidRequest)?;
+
+            let frozen_for = u64::from(self.env().block_number().saturating_sub(frozen_at));
+            request.expires_at = request
+                .expires_at
+                .map(|expires_at| expires_at.saturating_add(frozen_for));
+            self.bridge_requests.insert(request_id, &request);
+            self.frozen_requests.remove(request_id);
+            self.record_status(request_id, request.status, Some(String::from("Unfrozen")));
+            self.env().emit_event(BridgeRequestUnfrozen {
+                request_id,
+                unfrozen_by: caller,
+                expires_at: request.expires_at,
+            });
+            Ok(())
+        }
+
+        /// Returns the reason a request is frozen, if it is.
+        #[ink(message)]
+        pub fn get_freeze_reason(&self, request_id: u64) -> Option<String> {
+            self.frozen_requests
+                .get(request_id)
+                .map(|(reason, _)| reason)
+        }
+
         /// Gets gas estimation for a bridge operation
         #[ink(message)]
         pub fn estimate_bridge_gas(
@@ -1476,22 +2871,8 @@ mod bridge {
             _token_id: TokenId,
             destination_chain: ChainId,
         ) -> Result<u64, Error> {
-            let chain_info = self
-                .chain_info
-                .get(destination_chain)
-                .ok_or(Error::InvalidChain)?;
-            if !chain_info.is_active {
-                return Err(Error::InvalidChain);
-            }
-
-            let base_gas = propchain_traits::constants::BRIDGE_BASE_GAS;
-            let multiplier = u64::from(chain_info.gas_multiplier);
-            let confirmation_blocks = u64::from(chain_info.confirmation_blocks);
-            let adjusted_base = base_gas.saturating_mul(multiplier) / 100;
-            let confirmation_overhead = adjusted_base.saturating_mul(confirmation_blocks) / 100;
-            let estimated = adjusted_base.saturating_add(confirmation_overhead);
-
-            Ok(estimated.min(self.config.gas_limit_per_bridge))
+            let estimated = self.chain_profile_gas(destination_chain)?;
+            Ok(estimated.min(self.gas_limit_for(destination_chain)))
         }
 
         /// Monitors bridge status
@@ -1509,10 +2890,19 @@ mod bridge {
                 expires_at: request.expires_at,
                 signatures_collected: request.signature_count(),
                 signatures_required: request.required_signatures,
-                error_message: None,
+                error_message: self.latest_failure(request_id),
             })
         }
 
+        /// Returns the most recent status transitions of a request, oldest
+        /// first (at most [`STATUS_HISTORY_LIMIT`] entries).
+        #[ink(message)]
+        pub fn get_request_status_history(&self, request_id: u64) -> Vec<StatusHistoryEntry> {
+            self.request_status_history
+                .get(request_id)
+                .unwrap_or_default()
+        }
+
         /// Returns the raw 256-bit signature bitmap for a bridge request.
         #[ink(message)]
         pub fn get_signature_bitmap(
@@ -1567,10 +2957,65 @@ mod bridge {
             false
         }
 
-        /// Gets bridge history for an account
+        /// Gets bridge history for an account.
+        ///
+        /// Returns at most the [`MAX_HISTORY_PAGE_SIZE`] most recent
+        /// transactions, oldest first; use [`get_bridge_history_paginated`]
+        /// to walk the full history.
         #[ink(message)]
         pub fn get_bridge_history(&self, account: AccountId) -> Vec<BridgeTransaction> {
-            self.bridge_history.get(account).unwrap_or_default()
+            let ids = self
+                .account_transaction_ids
+                .get(account)
+                .unwrap_or_default();
+            let start = ids.len().saturating_sub(MAX_HISTORY_PAGE_SIZE as usize);
+            ids[start..]
+                .iter()
+                .filter_map(|id| self.transactions.get(id))
+                .collect()
+        }
+
+        /// Returns up to `limit` transactions for `account`, oldest first,
+        /// skipping the first `offset`. `limit` is capped at
+        /// [`MAX_HISTORY_PAGE_SIZE`].
+        #[ink(message)]
+        pub fn get_bridge_history_paginated(
+            &self,
+            account: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<BridgeTransaction> {
+            let ids = self
+                .account_transaction_ids
+                .get(account)
+                .unwrap_or_default();
+            ids.iter()
+                .skip(offset as usize)
+                .take(limit.min(MAX_HISTORY_PAGE_SIZE) as usize)
+                .filter_map(|id| self.transactions.get(id))
+                .collect()
+        }
+
+        /// Returns the number of transactions recorded for `account`.
+        #[ink(message)]
+        pub fn get_bridge_history_count(&self, account: AccountId) -> u32 {
+            self.account_transaction_ids
+                .get(account)
+                .map_or(0, |ids| ids.len() as u32)
+        }
+
+        /// Looks up a bridge transaction by its ID.
+        #[ink(message)]
+        pub fn get_transaction(&self, transaction_id: u64) -> Option<BridgeTransaction> {
+            self.transactions.get(transaction_id)
+        }
+
+        /// Looks up a bridge transaction by its transaction hash.
+        #[ink(message)]
+        pub fn get_transaction_by_hash(&self, transaction_hash: Hash) -> Option<BridgeTransaction> {
+            self.transaction_id_by_hash
+                .get(transaction_hash)
+                .and_then(|id| self.transactions.get(id))
         }
 
         /// Quotes bridge fees for a DEX settlement.
@@ -1721,20 +3166,51 @@ mod bridge {
 
             if !self.bridge_operators.contains(&operator) {
                 self.bridge_operators.push(operator);
+                self.signer_epoch = self.signer_epoch.saturating_add(1);
             }
 
             Ok(())
         }
 
-        /// Removes a bridge operator
+        /// Adds a bridge operator together with the compressed ECDSA public
+        /// key they sign offline approvals with.
+        #[ink(message)]
+        pub fn add_bridge_operator_with_key(
+            &mut self,
+            operator: AccountId,
+            public_key: [u8; 33],
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.set_operator_public_key(operator, public_key)?;
+            self.add_bridge_operator(operator)
+        }
+
+        /// Returns the operator registered for `public_key`, if any.
+        #[ink(message)]
+        pub fn get_operator_by_public_key(&self, public_key: [u8; 33]) -> Option<AccountId> {
+            self.operator_by_public_key.get(public_key)
+        }
+
+        /// Removes a bridge operator. Their signatures are stripped from
+        /// pending requests. Fails with `InsufficientSignatures` if the
+        /// operator count would drop below `min_signatures_required`.
         #[ink(message)]
         pub fn remove_bridge_operator(&mut self, operator: AccountId) -> Result<(), Error> {
             let caller = self.env().caller();
             if caller != self.admin {
                 return Err(Error::Unauthorized);
             }
+            if !self.bridge_operators.contains(&operator) {
+                return Ok(());
+            }
+            if self.bridge_operators.len() <= usize::from(self.config.min_signatures_required) {
+                return Err(Error::InsufficientSignatures);
+            }
 
             self.bridge_operators.retain(|op| op != &operator);
+            self.on_signer_removed(operator);
             Ok(())
         }
 
@@ -1761,16 +3237,26 @@ mod bridge {
             }
             self.assign_validator_bit_position(validator)?;
             self.validators.push(validator);
+            self.signer_epoch = self.signer_epoch.saturating_add(1);
             Ok(())
         }
 
-        /// Removes a validator (admin only).
+        /// Removes a validator (admin only). Their signatures are stripped
+        /// from pending requests. Fails with `InsufficientSignatures` if the
+        /// validator count would drop below `min_signatures_required`.
         #[ink(message)]
         pub fn remove_validator(&mut self, validator: AccountId) -> Result<(), Error> {
             if self.env().caller() != self.admin {
                 return Err(Error::Unauthorized);
             }
+            if !self.validators.contains(&validator) {
+                return Ok(());
+            }
+            if self.validators.len() <= usize::from(self.config.min_signatures_required) {
+                return Err(Error::InsufficientSignatures);
+            }
             self.validators.retain(|v| v != &validator);
+            self.on_signer_removed(validator);
             Ok(())
         }
 
@@ -1780,58 +3266,311 @@ mod bridge {
             self.validators.clone()
         }
 
+        /// Records that the calling operator or validator is online.
+        #[ink(message)]
+        pub fn operator_heartbeat(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if !self.bridge_operators.contains(&caller) && !self.validators.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+            self.operator_last_seen
+                .insert(caller, &self.env().block_timestamp());
+            Ok(())
+        }
+
+        /// Sets how many seconds a validator may go without a heartbeat
+        /// before it stops counting towards signature thresholds (admin
+        /// only). Zero disables the policy.
+        #[ink(message)]
+        pub fn set_operator_liveness_window(&mut self, seconds: u64) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            self.operator_liveness_window = seconds;
+            Ok(())
+        }
+
+        /// Returns the liveness window in seconds; zero when disabled.
+        #[ink(message)]
+        pub fn get_operator_liveness_window(&self) -> u64 {
+            self.operator_liveness_window
+        }
+
+        /// Returns the last heartbeat and outstanding signature count of
+        /// every operator and validator, operators first.
+        #[ink(message)]
+        pub fn get_operator_liveness(&self) -> Vec<OperatorLiveness> {
+            let mut accounts = self.bridge_operators.clone();
+            for validator in &self.validators {
+                if !accounts.contains(validator) {
+                    accounts.push(*validator);
+                }
+            }
+
+            let pending: Vec<StoredBridgeRequest> = self
+                .open_requests
+                .iter()
+                .filter_map(|request_id| self.bridge_requests.get(request_id))
+                .filter(|request| request.status == BridgeOperationStatus::Pending)
+                .collect();
+
+            accounts
+                .into_iter()
+                .map(|operator| {
+                    let pending_signatures = if self.validators.contains(&operator) {
+                        let position = self.readonly_validator_bit_position(&operator);
+                        pending
+                            .iter()
+                            .filter(|request| match position {
+                                Some(position) => {
+                                    !self.request_has_signature(request, operator, position)
+                                }
+                                None => true,
+                            })
+                            .count() as u32
+                    } else {
+                        0
+                    };
+                    OperatorLiveness {
+                        operator,
+                        last_seen: self.operator_last_seen.get(operator),
+                        pending_signatures,
+                        live: self.is_operator_live(&operator),
+                    }
+                })
+                .collect()
+        }
+
+        /// Signatures `request_id` needs to proceed once validators outside
+        /// the liveness window are discounted.
+        #[ink(message)]
+        pub fn get_effective_required_signatures(&self, request_id: u64) -> Option<u8> {
+            self.bridge_requests
+                .get(request_id)
+                .map(|request| self.effective_required_signatures(&request))
+        }
+
         /// Returns whether an account is a registered validator.
         #[ink(message)]
         pub fn is_validator(&self, account: AccountId) -> bool {
             self.validators.contains(&account)
         }
         /// Updates bridge configuration (admin only)
+        ///
+        /// Fails with `InvalidConfig` unless `1 <= min <= max <= operator
+        /// count`, at least one chain is supported and the gas limit is
+        /// non-zero. Pending requests keep the signature requirement they
+        /// were created with.
         #[ink(message)]
         pub fn update_config(&mut self, config: BridgeConfig) -> Result<(), Error> {
             let caller = self.env().caller();
             if caller != self.admin {
                 return Err(Error::Unauthorized);
             }
-
-            self.config = config;
+            self.validate_config(&config)?;
+
+            self.config = config;
+            Ok(())
+        }
+
+        /// Gets current bridge configuration
+        #[ink(message)]
+        pub fn get_config(&self) -> BridgeConfig {
+            self.config.clone()
+        }
+
+        /// Pauses or unpauses the bridge (admin only).
+        ///
+        /// Backwards-compatible entry point: `paused = true` sets the master
+        /// kill-switch via [`apply_pause`] (full audit + event), and
+        /// `paused = false` clears every pause flag via [`apply_unpause`].
+        #[ink(message)]
+        pub fn set_emergency_pause(&mut self, paused: bool) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if paused {
+                self.apply_pause(
+                    caller,
+                    PauseFlags::all(),
+                    PauseReason::ManualAdmin,
+                    Some(String::from("Legacy set_emergency_pause(true)")),
+                );
+            } else {
+                self.apply_unpause(caller, PauseFlags::all());
+            }
+            Ok(())
+        }
+
+        /// Returns the chain ID this bridge instance is deployed on.
+        #[ink(message)]
+        pub fn get_chain_id(&self) -> ChainId {
+            self.chain_id
+        }
+
+        /// Sets the local chain ID (admin only). Intended for instances
+        /// deployed before the chain ID was configurable; may only be
+        /// called once.
+        #[ink(message)]
+        pub fn set_chain_id(&mut self, chain_id: ChainId) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if self.chain_id_locked {
+                return Err(Error::InvalidRequest);
+            }
+            self.chain_id = chain_id;
+            self.chain_id_locked = true;
+            Ok(())
+        }
+
+        /// Activates or deactivates a single chain corridor (admin only).
+        ///
+        /// While a chain is inactive, new requests to it, signatures on and
+        /// execution of requests targeting it, and inbound transfers from it
+        /// fail with `ChainPaused`. The global emergency pause still takes
+        /// precedence over this per-chain switch.
+        #[ink(message)]
+        pub fn set_chain_active(&mut self, chain_id: ChainId, active: bool) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            let mut info = self.chain_info.get(chain_id).ok_or(Error::InvalidChain)?;
+            info.is_active = active;
+            self.chain_info.insert(chain_id, &info);
+
+            self.env().emit_event(ChainStatusChanged {
+                chain_id,
+                is_active: active,
+                changed_by: caller,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Gets chain information
+        #[ink(message)]
+        pub fn get_chain_info(&self, chain_id: ChainId) -> Option<ChainBridgeInfo> {
+            self.chain_info.get(chain_id)
+        }
+
+        /// Sets the bridge contract address on `chain_id` (admin only).
+        ///
+        /// `address_bytes` is in the destination chain's native format, e.g.
+        /// 20 bytes for EVM chains. A 32-byte address is also recorded as the
+        /// chain's `bridge_contract_address`.
+        #[ink(message)]
+        pub fn set_destination_contract(
+            &mut self,
+            chain_id: ChainId,
+            address_bytes: Vec<u8>,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            let mut info = self.chain_info.get(chain_id).ok_or(Error::InvalidChain)?;
+            if address_bytes.is_empty() {
+                return Err(Error::InvalidRequest);
+            }
+            info.bridge_contract_address = <[u8; 32]>::try_from(address_bytes.as_slice())
+                .ok()
+                .map(AccountId::from);
+            self.chain_info.insert(chain_id, &info);
+            self.destination_contracts.insert(chain_id, &address_bytes);
             Ok(())
         }
 
-        /// Gets current bridge configuration
+        /// Returns the bridge contract address configured for `chain_id`.
         #[ink(message)]
-        pub fn get_config(&self) -> BridgeConfig {
-            self.config.clone()
+        pub fn get_destination_contract(&self, chain_id: ChainId) -> Option<Vec<u8>> {
+            self.destination_contracts.get(chain_id)
         }
 
-        /// Pauses or unpauses the bridge (admin only).
+        /// Returns the metadata hash recorded when the request was initiated,
+        /// if metadata preservation was enabled at the time.
+        #[ink(message)]
+        pub fn get_metadata_hash(&self, request_id: u64) -> Option<Hash> {
+            self.metadata_hashes.get(request_id)
+        }
+
+        /// Returns the encoded [`OutboundPayload`] of the request's most
+        /// recently executed hop, exactly as emitted in [`BridgeMessage`].
+        #[ink(message)]
+        pub fn get_outbound_payload(&self, request_id: u64) -> Option<Vec<u8>> {
+            self.outbound_payloads.get(request_id)
+        }
+
+        /// Allow-lists `token_id` for bridging to `chain_id` (admin only).
         ///
-        /// Backwards-compatible entry point: `paused = true` sets the master
-        /// kill-switch via [`apply_pause`] (full audit + event), and
-        /// `paused = false` clears every pause flag via [`apply_unpause`].
+        /// A chain with an empty allow-list accepts every token; once a token
+        /// is added, only listed tokens may be bridged to it.
         #[ink(message)]
-        pub fn set_emergency_pause(&mut self, paused: bool) -> Result<(), Error> {
-            let caller = self.env().caller();
-            if caller != self.admin {
+        pub fn add_supported_token(
+            &mut self,
+            chain_id: ChainId,
+            token_id: TokenId,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
                 return Err(Error::Unauthorized);
             }
+            if !self.config.supported_chains.contains(&chain_id) {
+                return Err(Error::InvalidChain);
+            }
+            if self.supported_tokens.contains((chain_id, token_id)) {
+                return Ok(());
+            }
+            self.supported_tokens.insert((chain_id, token_id), &true);
+            let count = self.supported_token_count.get(chain_id).unwrap_or(0);
+            self.supported_token_count
+                .insert(chain_id, &count.saturating_add(1));
+            self.env().emit_event(SupportedTokenChanged {
+                chain_id,
+                token_id,
+                supported: true,
+            });
+            Ok(())
+        }
 
-            if paused {
-                self.apply_pause(
-                    caller,
-                    PauseFlags::all(),
-                    PauseReason::ManualAdmin,
-                    Some(String::from("Legacy set_emergency_pause(true)")),
-                );
-            } else {
-                self.apply_unpause(caller, PauseFlags::all());
+        /// Removes `token_id` from the allow-list of `chain_id` (admin only).
+        /// Removing the last entry makes the chain accept every token again.
+        #[ink(message)]
+        pub fn remove_supported_token(
+            &mut self,
+            chain_id: ChainId,
+            token_id: TokenId,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if !self.supported_tokens.contains((chain_id, token_id)) {
+                return Ok(());
             }
+            self.supported_tokens.remove((chain_id, token_id));
+            let count = self.supported_token_count.get(chain_id).unwrap_or(0);
+            self.supported_token_count
+                .insert(chain_id, &count.saturating_sub(1));
+            self.env().emit_event(SupportedTokenChanged {
+                chain_id,
+                token_id,
+                supported: false,
+            });
             Ok(())
         }
 
-        /// Gets chain information
+        /// Returns whether `token_id` may be bridged to `chain_id`.
         #[ink(message)]
-        pub fn get_chain_info(&self, chain_id: ChainId) -> Option<ChainBridgeInfo> {
-            self.chain_info.get(chain_id)
+        pub fn is_token_supported(&self, chain_id: ChainId, token_id: TokenId) -> bool {
+            self.supported_token_count.get(chain_id).unwrap_or(0) == 0
+                || self.supported_tokens.contains((chain_id, token_id))
+        }
+
+        /// Returns the number of tokens on the allow-list of `chain_id`.
+        #[ink(message)]
+        pub fn get_supported_token_count(&self, chain_id: ChainId) -> u32 {
+            self.supported_token_count.get(chain_id).unwrap_or(0)
         }
 
         /// Updates chain information (admin only)
@@ -1850,6 +3589,37 @@ mod bridge {
             Ok(())
         }
 
+        /// Overrides `BridgeConfig::gas_limit_per_bridge` for requests to
+        /// `chain_id` (admin only). `None` falls back to the global limit.
+        #[ink(message)]
+        pub fn set_gas_limit(
+            &mut self,
+            chain_id: ChainId,
+            limit: Option<u64>,
+        ) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            let mut info = self.chain_info.get(chain_id).ok_or(Error::InvalidChain)?;
+            info.gas_limit = limit;
+            self.chain_info.insert(chain_id, &info);
+            Ok(())
+        }
+
+        /// Gas a request to `destination_chain` would be estimated at, and
+        /// the limit it is checked against.
+        #[ink(message)]
+        pub fn quote_request_gas(
+            &self,
+            destination_chain: ChainId,
+            required_signatures: u8,
+            metadata: PropertyMetadata,
+        ) -> Result<(u64, u64), Error> {
+            let estimate =
+                self.estimate_request_gas(destination_chain, &metadata, required_signatures)?;
+            Ok((estimate, self.gas_limit_for(destination_chain)))
+        }
+
         /// Request a two-step admin rotation with cooldown.
         #[ink(message)]
         pub fn request_admin_rotation(&mut self, new_admin: AccountId) -> Result<(), Error> {
@@ -1968,6 +3738,52 @@ mod bridge {
             self.guardians.clone()
         }
 
+        /// Register a security officer. Officers may freeze and unfreeze
+        /// individual requests. Admin only.
+        #[ink(message)]
+        pub fn add_security_officer(&mut self, officer: AccountId) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            if !self.security_officers.contains(&officer) {
+                self.security_officers.push(officer);
+                self.env().emit_event(SecurityOfficerSetUpdated {
+                    officer,
+                    added: true,
+                });
+            }
+            Ok(())
+        }
+
+        /// Remove a security officer. Admin only.
+        #[ink(message)]
+        pub fn remove_security_officer(&mut self, officer: AccountId) -> Result<(), Error> {
+            if self.env().caller() != self.admin {
+                return Err(Error::Unauthorized);
+            }
+            let before = self.security_officers.len();
+            self.security_officers.retain(|o| o != &officer);
+            if self.security_officers.len() != before {
+                self.env().emit_event(SecurityOfficerSetUpdated {
+                    officer,
+                    added: false,
+                });
+            }
+            Ok(())
+        }
+
+        /// Returns whether `account` is a registered security officer.
+        #[ink(message)]
+        pub fn is_security_officer(&self, account: AccountId) -> bool {
+            self.security_officers.contains(&account)
+        }
+
+        /// Returns the full security officer set.
+        #[ink(message)]
+        pub fn get_security_officers(&self) -> Vec<AccountId> {
+            self.security_officers.clone()
+        }
+
         /// Trigger an emergency pause. The `flags` argument is OR-merged
         /// onto the existing flags so partial pauses can be escalated
         /// (e.g. "new requests" -> "new requests + signing") without
@@ -2810,10 +4626,96 @@ mod bridge {
             true
         }
 
+        /// Rejects routes that would carry a bridged-in token anywhere but
+        /// straight back to its origin chain, unless multi-hop is allowed.
+        fn ensure_onward_route_allowed(
+            &self,
+            token_id: TokenId,
+            route: &[ChainId],
+        ) -> Result<(), Error> {
+            if self.allow_bridged_multi_hop {
+                return Ok(());
+            }
+            match self.token_origins.get(token_id) {
+                Some((origin_chain, _)) if route != [origin_chain] => {
+                    Err(Error::MultiHopNotAllowed)
+                }
+                _ => Ok(()),
+            }
+        }
+
+        /// Preconditions `initiate_bridge_multisig` and `simulate_bridge`
+        /// share. Returns the request's gas estimate.
+        fn validate_new_request(
+            &self,
+            caller: AccountId,
+            token_id: TokenId,
+            destination_chain: ChainId,
+            required_signatures: u8,
+            metadata: &PropertyMetadata,
+        ) -> Result<u64, Error> {
+            self.validate_destination(destination_chain)?;
+            self.validate_token_route(token_id, destination_chain)?;
+            let gas_estimate =
+                self.validate_request_gas(destination_chain, required_signatures, metadata)?;
+
+            // Check if caller is authorized (token owner or approved operator)
+            if !self.is_authorized_for_token(caller, token_id) {
+                return Err(Error::Unauthorized);
+            }
+
+            // A token can only be in one bridge request at a time.
+            if self.active_request_by_token.contains(token_id) {
+                return Err(Error::DuplicateRequest);
+            }
+            Ok(gas_estimate)
+        }
+
+        /// `destination_chain` is a supported, active chain other than this one.
+        fn validate_destination(&self, destination_chain: ChainId) -> Result<(), Error> {
+            if !self.config.supported_chains.contains(&destination_chain) {
+                return Err(Error::InvalidChain);
+            }
+            if destination_chain == self.get_current_chain_id() {
+                return Err(Error::InvalidChain);
+            }
+            self.ensure_chain_active(destination_chain)
+        }
+
+        /// `token_id` is supported on `destination_chain` and may be routed there.
+        fn validate_token_route(
+            &self,
+            token_id: TokenId,
+            destination_chain: ChainId,
+        ) -> Result<(), Error> {
+            if !self.is_token_supported(destination_chain, token_id) {
+                return Err(Error::BridgeNotSupported);
+            }
+            self.ensure_onward_route_allowed(token_id, &[destination_chain])
+        }
+
+        /// Check the signature requirement and return the request's gas
+        /// estimate if it fits under the chain's gas limit.
+        fn validate_request_gas(
+            &self,
+            destination_chain: ChainId,
+            required_signatures: u8,
+            metadata: &PropertyMetadata,
+        ) -> Result<u64, Error> {
+            if required_signatures < self.config.min_signatures_required
+                || required_signatures > self.config.max_signatures_required
+            {
+                return Err(Error::InsufficientSignatures);
+            }
+
+            let gas_estimate =
+                self.estimate_request_gas(destination_chain, metadata, required_signatures)?;
+            self.ensure_within_gas_limit(destination_chain, gas_estimate)?;
+            Ok(gas_estimate)
+        }
+
         fn get_current_chain_id(&self) -> ChainId {
-            // This should return the current chain ID
-            // For now, we'll use a default value
-            1
+            self.chain_id
         }
 
         fn bitmap_has_signature(&self, bitmap: &[u8; SIGNATURE_BITMAP_BYTES], bit: u8) -> bool {
@@ -2903,20 +4805,226 @@ mod bridge {
             }
         }
 
-        fn normalize_signature_storage(
+        fn normalize_signature_storage(
+            &mut self,
+            request: &mut StoredBridgeRequest,
+        ) -> Result<(), Error> {
+            let SignatureStorage::Legacy(signers) = &request.signature_storage else {
+                return Ok(());
+            };
+
+            let mut bitmap = [0; SIGNATURE_BITMAP_BYTES];
+            for signer in signers {
+                let position = self.get_validator_bit_position(*signer)?;
+                self.set_bitmap_signature(&mut bitmap, position);
+            }
+            request.signature_storage = SignatureStorage::Bitmap(bitmap);
+            Ok(())
+        }
+
+        /// Record `signer`'s vote on a request. Shared by the caller-based and
+        /// offline-signature paths so both apply the same rules.
+        fn record_signature(
+            &mut self,
+            signer: AccountId,
+            request_id: u64,
+            approve: bool,
+        ) -> Result<(), Error> {
+            // Granular pause check: blocks if `signing` or `all_operations`
+            // is set (or the legacy emergency-pause boolean).
+            self.ensure_not_paused(BridgeOperation::Signing)?;
+
+            // Check if signer is a registered validator (issue #203: only validators may sign)
+            if !self.validators.contains(&signer) {
+                return Err(Error::Unauthorized);
+            }
+
+            // A signer removed after this signing round began may not vote
+            // in it, even if they have since been re-added.
+            let round_epoch = self.request_signer_epoch.get(request_id).unwrap_or(0);
+            if self
+                .signer_removed_at
+                .get(signer)
+                .is_some_and(|removed_at| removed_at > round_epoch)
+            {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut request = self
+                .bridge_requests
+                .get(request_id)
+                .ok_or(Error::InvalidRequest)?;
+            self.ensure_not_frozen(request_id)?;
+            self.normalize_signature_storage(&mut request)?;
+            self.ensure_chain_active(request.destination_chain)?;
+
+            // Cancelled, expired and finished requests take no more votes.
+            if matches!(
+                request.status,
+                BridgeOperationStatus::Completed
+                    | BridgeOperationStatus::Failed
+                    | BridgeOperationStatus::Expired
+            ) {
+                return Err(Error::InvalidRequest);
+            }
+
+            // Check if request has expired
+            if let Some(expires_at) = request.expires_at {
+                if u64::from(self.env().block_number()) > expires_at {
+                    return Err(Error::RequestExpired);
+                }
+            }
+
+            // Check if already signed
+            let bit_position = self.get_validator_bit_position(signer)?;
+            if self.request_has_signature(&request, signer, bit_position) {
+                return Err(Error::AlreadySigned);
+            }
+
+            self.add_request_signature(&mut request, signer, bit_position)?;
+
+            // Update status based on approval and signatures collected
+            if !approve {
+                request.status = BridgeOperationStatus::Failed;
+                request.multi_hop_status = MultiHopStatus::Failed;
+                self.record_status(
+                    request_id,
+                    BridgeOperationStatus::Failed,
+                    Some(String::from("Rejected by validator")),
+                );
+            } else {
+                self.lock_if_signed(&mut request);
+            }
+
+            self.bridge_requests.insert(request_id, &request);
+
+            // Suspicious-activity heuristic: surge of failed-signature votes
+            // may indicate validator compromise or coordinated attack.
+            if !approve {
+                self.release_token_lock(&request);
+                self.clear_active_request(&request);
+                self.refund_request_fee(request_id, request.sender);
+                self.track_failed_signature(signer);
+            }
+
+            self.emit_request_signed(
+                request_id,
+                signer,
+                request.signature_count(),
+                self.effective_required_signatures(&request),
+            );
+
+            Ok(())
+        }
+
+        /// Moves a pending request to `Locked` once it holds enough
+        /// signatures. Returns whether the request is now locked.
+        fn lock_if_signed(&mut self, request: &mut StoredBridgeRequest) -> bool {
+            if request.status == BridgeOperationStatus::Pending
+                && request.signature_count() >= self.effective_required_signatures(request)
+            {
+                request.status = BridgeOperationStatus::Locked;
+                self.record_status(request.request_id, BridgeOperationStatus::Locked, None);
+                self.record_token_lock(request);
+            }
+            request.status == BridgeOperationStatus::Locked
+        }
+
+        /// Whether `account` has sent a heartbeat within the liveness
+        /// window. Everyone is live while the window is disabled.
+        fn is_operator_live(&self, account: &AccountId) -> bool {
+            if self.operator_liveness_window == 0 {
+                return true;
+            }
+            // block_timestamp is in milliseconds; the window is in seconds.
+            let window = self.operator_liveness_window.saturating_mul(1_000);
+            self.operator_last_seen
+                .get(account)
+                .is_some_and(|last_seen| {
+                    self.env().block_timestamp().saturating_sub(last_seen) <= window
+                })
+        }
+
+        /// Signatures `request` needs to proceed. Validators that are
+        /// silent and have not signed it are taken out of the signer pool,
+        /// so the threshold shrinks to the signers still able to respond,
+        /// but never below `min_signatures_required` as it stood when the
+        /// request was created.
+        fn effective_required_signatures(&self, request: &StoredBridgeRequest) -> u8 {
+            if self.operator_liveness_window == 0 {
+                return request.required_signatures;
+            }
+            let available = self
+                .validators
+                .iter()
+                .filter(|validator| {
+                    self.is_operator_live(validator)
+                        || self
+                            .readonly_validator_bit_position(validator)
+                            .is_some_and(|position| {
+                                self.request_has_signature(request, **validator, position)
+                            })
+                })
+                .count();
+            let available = u8::try_from(available).unwrap_or(u8::MAX);
+            request
+                .required_signatures
+                .min(available)
+                .max(request.min_signatures.min(request.required_signatures))
+        }
+
+        fn validate_config(&self, config: &BridgeConfig) -> Result<(), Error> {
+            let operators = u8::try_from(self.bridge_operators.len()).unwrap_or(u8::MAX);
+            if config.min_signatures_required == 0
+                || config.min_signatures_required > config.max_signatures_required
+                || config.max_signatures_required > operators
+                || config.supported_chains.is_empty()
+                || config.gas_limit_per_bridge == 0
+            {
+                return Err(Error::InvalidConfig);
+            }
+            Ok(())
+        }
+
+        /// SCALE encoding of the fields a request authorizes, prefixed with
+        /// [`REQUEST_ENCODING_VERSION`] and a domain tag.
+        fn canonical_request_encoding(&self, request: &StoredBridgeRequest) -> Vec<u8> {
+            scale::Encode::encode(&(
+                REQUEST_ENCODING_VERSION,
+                b"propchain:bridge:request",
+                self.chain_id,
+                request.request_id,
+                request.token_id,
+                request.source_chain,
+                request.destination_chain,
+                request.sender,
+                request.recipient,
+                request.required_signatures,
+                request.current_hop,
+                propchain_traits::crypto::hash_encoded(&request.metadata),
+            ))
+        }
+
+        fn request_digest_of(&self, request: &StoredBridgeRequest) -> Hash {
+            propchain_traits::crypto::hash_blake2b256(&self.canonical_request_encoding(request))
+        }
+
+        /// Bind `public_key` to `operator`, replacing any key it held before.
+        fn set_operator_public_key(
             &mut self,
-            request: &mut StoredBridgeRequest,
+            operator: AccountId,
+            public_key: [u8; 33],
         ) -> Result<(), Error> {
-            let SignatureStorage::Legacy(signers) = &request.signature_storage else {
-                return Ok(());
-            };
-
-            let mut bitmap = [0; SIGNATURE_BITMAP_BYTES];
-            for signer in signers {
-                let position = self.get_validator_bit_position(*signer)?;
-                self.set_bitmap_signature(&mut bitmap, position);
+            if let Some(owner) = self.operator_by_public_key.get(public_key) {
+                if owner != operator {
+                    return Err(Error::InvalidRequest);
+                }
             }
-            request.signature_storage = SignatureStorage::Bitmap(bitmap);
+            if let Some(previous) = self.operator_public_keys.get(operator) {
+                self.operator_by_public_key.remove(previous);
+            }
+            self.operator_public_keys.insert(operator, &public_key);
+            self.operator_by_public_key.insert(public_key, &operator);
             Ok(())
         }
 
@@ -2964,16 +5072,61 @@ mod bridge {
                 request.destination_chain,
                 request.sender,
                 request.recipient,
+                self.chain_id,
                 self.env().block_timestamp(),
             );
             propchain_traits::crypto::hash_encoded(&data)
         }
 
-        fn estimate_gas_usage(&self, request: &StoredBridgeRequest) -> u64 {
-            // Estimate gas usage based on request complexity
-            let base_gas = 100000; // Base gas for bridge operation
-            let metadata_gas = request.metadata.legal_description.len() as u64 * 100; // Gas for metadata
-            base_gas + metadata_gas
+        /// Gas needed to relay a request to `destination_chain`: the chain's
+        /// profile cost plus the metadata carried and the signatures
+        /// collected. Unlike [`estimate_bridge_gas`] this is not capped at
+        /// the gas limit.
+        fn estimate_request_gas(
+            &self,
+            destination_chain: ChainId,
+            metadata: &PropertyMetadata,
+            required_signatures: u8,
+        ) -> Result<u64, Error> {
+            let chain_gas = self.chain_profile_gas(destination_chain)?;
+            let metadata_gas = (scale::Encode::encoded_size(metadata) as u64)
+                .saturating_mul(propchain_traits::constants::BRIDGE_METADATA_GAS_PER_BYTE);
+            let signature_gas = u64::from(required_signatures)
+                .saturating_mul(propchain_traits::constants::BRIDGE_SIGNATURE_GAS);
+            Ok(chain_gas
+                .saturating_add(metadata_gas)
+                .saturating_add(signature_gas))
+        }
+
+        /// Uncapped gas cost of a bridge operation under the chain's profile.
+        fn chain_profile_gas(&self, chain_id: ChainId) -> Result<u64, Error> {
+            let chain_info = self.chain_info.get(chain_id).ok_or(Error::InvalidChain)?;
+            if !chain_info.is_active {
+                return Err(Error::InvalidChain);
+            }
+
+            let base_gas = propchain_traits::constants::BRIDGE_BASE_GAS;
+            let multiplier = u64::from(chain_info.gas_multiplier);
+            let confirmation_blocks = u64::from(chain_info.confirmation_blocks);
+            let adjusted_base = base_gas.saturating_mul(multiplier) / 100;
+            let confirmation_overhead = adjusted_base.saturating_mul(confirmation_blocks) / 100;
+            Ok(adjusted_base.saturating_add(confirmation_overhead))
+        }
+
+        /// Gas ceiling for requests to `chain_id`: the chain's override if
+        /// set, otherwise `BridgeConfig::gas_limit_per_bridge`.
+        fn gas_limit_for(&self, chain_id: ChainId) -> u64 {
+            self.chain_info
+                .get(chain_id)
+                .and_then(|info| info.gas_limit)
+                .unwrap_or(self.config.gas_limit_per_bridge)
+        }
+
+        fn ensure_within_gas_limit(&self, chain_id: ChainId, estimate: u64) -> Result<(), Error> {
+            if estimate > self.gas_limit_for(chain_id) {
+                return Err(Error::GasLimitExceeded);
+            }
+            Ok(())
         }
 
         /// Check FATF travel rule compliance for the given bridge request.
@@ -3002,8 +5155,12 @@ mod bridge {
             Ok(())
         }
 
-        fn check_and_update_rate_limits(
-            &mut self,
+        /// Checks the per-account request limit (`is_nft`) and the
+        /// destination chain's daily volume limit (`amount`) without
+        /// recording anything. Shared by the real calls and
+        /// `simulate_bridge`.
+        fn check_rate_limits(
+            &self,
             account: AccountId,
             destination_chain: ChainId,
             amount: u128,
@@ -3015,51 +5172,516 @@ mod bridge {
 
             let current_day = self.env().block_timestamp() / 86_400_000;
 
-            if is_nft {
-                let last_reset = self.account_last_reset_day.get(account).unwrap_or(0);
-                let mut daily_requests = self.account_daily_requests.get(account).unwrap_or(0);
-
-                if last_reset < current_day {
-                    daily_requests = 0;
-                    self.account_last_reset_day.insert(account, &current_day);
-                }
+            if is_nft
+                && self.daily_request_count(account, current_day)
+                    >= self.config.max_requests_per_day
+            {
+                return Err(Error::RateLimitExceeded);
+            }
 
-                if daily_requests >= self.config.max_requests_per_day {
+            if amount > 0 {
+                let chain_info = self
+                    .chain_info
+                    .get(destination_chain)
+                    .ok_or(Error::InvalidChain)?;
+                if self
+                    .daily_chain_volume(destination_chain, current_day)
+                    .saturating_add(amount)
+                    > chain_info.chain_daily_limit
+                {
                     return Err(Error::RateLimitExceeded);
                 }
+            }
+
+            Ok(())
+        }
+
+        /// Runs [`Self::check_rate_limits`] and, if it passes, records the
+        /// request and its volume against today's limits.
+        fn check_and_update_rate_limits(
+            &mut self,
+            account: AccountId,
+            destination_chain: ChainId,
+            amount: u128,
+            is_nft: bool,
+        ) -> Result<(), Error> {
+            self.check_rate_limits(account, destination_chain, amount, is_nft)?;
+            if !self.config.rate_limit_enabled {
+                return Ok(());
+            }
+
+            let current_day = self.env().block_timestamp() / 86_400_000;
 
+            if is_nft {
+                let daily_requests = self.daily_request_count(account, current_day);
+                self.account_last_reset_day.insert(account, &current_day);
                 self.account_daily_requests
                     .insert(account, &(daily_requests + 1));
             }
 
             if amount > 0 {
-                let chain_info = self
-                    .chain_info
-                    .get(destination_chain)
-                    .ok_or(Error::InvalidChain)?;
-                let last_chain_reset = self
-                    .chain_last_reset_day
+                let chain_volume = self.daily_chain_volume(destination_chain, current_day);
+                self.chain_last_reset_day
+                    .insert(destination_chain, &current_day);
+                self.chain_daily_volume
+                    .insert(destination_chain, &(chain_volume + amount));
+            }
+
+            Ok(())
+        }
+
+        /// Volume bridged to `destination_chain` on `current_day`.
+        fn daily_chain_volume(&self, destination_chain: ChainId, current_day: u64) -> u128 {
+            if self
+                .chain_last_reset_day
+                .get(destination_chain)
+                .unwrap_or(0)
+                < current_day
+            {
+                return 0;
+            }
+            self.chain_daily_volume.get(destination_chain).unwrap_or(0)
+        }
+
+        /// Requests `account` has made on `current_day`.
+        fn daily_request_count(&self, account: AccountId, current_day: u64) -> u64 {
+            if self.account_last_reset_day.get(account).unwrap_or(0) < current_day {
+                return 0;
+            }
+            self.account_daily_requests.get(account).unwrap_or(0)
+        }
+
+        /// Record the hash of `metadata` for `request_id` when metadata
+        /// preservation is enabled.
+        fn record_metadata_hash(&mut self, request_id: u64, metadata: &PropertyMetadata) {
+            if self.config.metadata_preservation {
+                self.metadata_hashes.insert(
+                    request_id,
+                    &propchain_traits::crypto::hash_encoded(metadata),
+                );
+            }
+        }
+
+        /// Build the relay payload for one hop of `request`.
+        fn outbound_payload(
+            &self,
+            request: &StoredBridgeRequest,
+            request_id: u64,
+            source_chain: ChainId,
+            destination_chain: ChainId,
+        ) -> OutboundPayload {
+            OutboundPayload {
+                version: OUTBOUND_PAYLOAD_VERSION,
+                source_chain,
+                destination_chain,
+                request_id,
+                token_id: request.token_id,
+                recipient: request.recipient,
+                metadata_hash: self
+                    .metadata_hashes
+                    .get(request_id)
+                    .unwrap_or_else(|| propchain_traits::crypto::hash_encoded(&request.metadata)),
+                destination_contract: self
+                    .destination_contracts
                     .get(destination_chain)
+                    .unwrap_or_default(),
+            }
+        }
+
+        fn emit_request_created(
+            &self,
+            request_id: u64,
+            token_id: TokenId,
+            source_chain: ChainId,
+            destination_chain: ChainId,
+            requester: AccountId,
+        ) {
+            self.env().emit_event(BridgeRequestCreated {
+                request_id,
+                token_id,
+                source_chain,
+                destination_chain,
+                requester,
+                contract_kind: BRIDGE_CONTRACT_KIND_BRIDGE,
+                event_version: BRIDGE_EVENT_VERSION,
+            });
+        }
+
+        fn emit_request_signed(
+            &self,
+            request_id: u64,
+            signer: AccountId,
+            signatures_collected: u8,
+            signatures_required: u8,
+        ) {
+            self.env().emit_event(BridgeRequestSigned {
+                request_id,
+                signer,
+                signatures_collected,
+                signatures_required,
+                contract_kind: BRIDGE_CONTRACT_KIND_BRIDGE,
+                event_version: BRIDGE_EVENT_VERSION,
+            });
+        }
+
+        fn emit_executed(&self, request_id: u64, token_id: TokenId, transaction_hash: Hash) {
+            self.env().emit_event(BridgeExecuted {
+                request_id,
+                token_id,
+                transaction_hash,
+                contract_kind: BRIDGE_CONTRACT_KIND_BRIDGE,
+                event_version: BRIDGE_EVENT_VERSION,
+            });
+        }
+
+        /// Admin or a registered security officer.
+        fn ensure_security_officer(&self, caller: AccountId) -> Result<(), Error> {
+            if caller != self.admin && !self.security_officers.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+            Ok(())
+        }
+
+        /// Rejects requests that are currently frozen.
+        fn ensure_not_frozen(&self, request_id: u64) -> Result<(), Error> {
+            if self.frozen_requests.contains(request_id) {
+                return Err(Error::RequestFrozen);
+            }
+            Ok(())
+        }
+
+        /// Append a status transition to the request's history, dropping the
+        /// oldest entry once [`STATUS_HISTORY_LIMIT`] is reached.
+        fn record_status(
+            &mut self,
+            request_id: u64,
+            status: BridgeOperationStatus,
+            error: Option<String>,
+        ) {
+            let mut history = self
+                .request_status_history
+                .get(request_id)
+                .unwrap_or_default();
+            if history.len() >= STATUS_HISTORY_LIMIT {
+                history.remove(0);
+            }
+            history.push((status, u64::from(self.env().block_number()), error));
+            self.request_status_history.insert(request_id, &history);
+        }
+
+        /// Reason recorded with the request's most recent failure, if any.
+        fn latest_failure(&self, request_id: u64) -> Option<String> {
+            self.request_status_history
+                .get(request_id)?
+                .into_iter()
+                .rev()
+                .find_map(|(_, _, error)| error)
+        }
+
+        /// Store `transaction` and index it under `account` and its hash.
+        fn record_transaction(&mut self, account: AccountId, transaction: &BridgeTransaction) {
+            self.transactions
+                .insert(transaction.transaction_id, transaction);
+            self.transaction_id_by_hash
+                .insert(transaction.transaction_hash, &transaction.transaction_id);
+            let mut ids = self
+                .account_transaction_ids
+                .get(account)
+                .unwrap_or_default();
+            ids.push(transaction.transaction_id);
+            self.account_transaction_ids.insert(account, &ids);
+        }
+
+        /// Records each of `request`'s tokens as locked from its sender and
+        /// emits `TokenLockedForBridge` for it. A token already locked (e.g.
+        /// between hops of a multi-hop route) is left as is.
+        fn record_token_lock(&mut self, request: &StoredBridgeRequest) {
+            for token_id in self.request_token_ids(request) {
+                if self.token_locks.contains(token_id) {
+                    continue;
+                }
+                self.token_locks
+                    .insert(token_id, &(request.request_id, request.sender));
+                let mut locked = self
+                    .locked_tokens_by_owner
+                    .get(request.sender)
+                    .unwrap_or_default();
+                locked.push(token_id);
+                self.locked_tokens_by_owner.insert(request.sender, &locked);
+                self.env().emit_event(TokenLockedForBridge {
+                    token_id,
+                    request_id: request.request_id,
+                    original_owner: request.sender,
+                });
+            }
+        }
+
+        /// Reverts the locks `request` placed on its tokens, if any, handing
+        /// each token back to the owner it was locked from.
+        fn release_token_lock(&mut self, request: &StoredBridgeRequest) {
+            for token_id in self.request_token_ids(request) {
+                let owner = match self.token_locks.get(token_id) {
+                    Some((request_id, owner)) if request_id == request.request_id => owner,
+                    _ => continue,
+                };
+                self.token_locks.remove(token_id);
+                let mut locked = self.locked_tokens_by_owner.get(owner).unwrap_or_default();
+                locked.retain(|id| *id != token_id);
+                if locked.is_empty() {
+                    self.locked_tokens_by_owner.remove(owner);
+                } else {
+                    self.locked_tokens_by_owner.insert(owner, &locked);
+                }
+                self.env().emit_event(TokenUnlockedFromBridge {
+                    token_id,
+                    request_id: request.request_id,
+                    owner,
+                });
+            }
+        }
+
+        /// Tokens carried by `request`; see [`get_request_tokens`].
+        fn request_token_ids(&self, request: &StoredBridgeRequest) -> Vec<TokenId> {
+            self.batch_tokens
+                .get(request.request_id)
+                .unwrap_or_else(|| vec![request.token_id])
+        }
+
+        /// Drop `request` from the open-request indexes once it reaches a
+        /// terminal state. Leaves the token index alone if another request
+        /// owns it.
+        fn clear_active_request(&mut self, request: &StoredBridgeRequest) {
+            let request_id = request.request_id;
+            for token_id in self.request_token_ids(request) {
+                if self.active_request_by_token.get(token_id) == Some(request_id) {
+                    self.active_request_by_token.remove(token_id);
+                }
+            }
+            if let Some(index) = self.open_requests.iter().position(|id| *id == request_id) {
+                self.open_requests.remove(index);
+                let open = self
+                    .open_requests_by_sender
+                    .get(request.sender)
                     .unwrap_or(0);
-                let mut chain_volume = self.chain_daily_volume.get(destination_chain).unwrap_or(0);
+                self.open_requests_by_sender
+                    .insert(request.sender, &open.saturating_sub(1));
+            }
+        }
+
+        /// Track `request_id` as open and start its signing round in the
+        /// current signer epoch.
+        fn open_request(&mut self, request_id: u64, sender: AccountId) {
+            if !self.open_requests.contains(&request_id) {
+                self.open_requests.push(request_id);
+                let open = self.open_requests_by_sender.get(sender).unwrap_or(0);
+                self.open_requests_by_sender
+                    .insert(sender, &open.saturating_add(1));
+            }
+            self.request_signer_epoch
+                .insert(request_id, &self.signer_epoch);
+        }
+
+        /// Enforce [`RequestLimits`] for a new request from `sender` to
+        /// `destination_chain`, counting it against the chain's window.
+        fn check_request_limits(
+            &mut self,
+            sender: AccountId,
+            destination_chain: ChainId,
+        ) -> Result<(), Error> {
+            if let Some((window_start, count)) =
+                self.request_limit_window(sender, destination_chain)?
+            {
+                self.chain_request_window
+                    .insert(destination_chain, &(window_start, count + 1));
+            }
+            Ok(())
+        }
+
+        /// Read-only part of [`check_request_limits`]. Returns the chain
+        /// window the request would be counted in, if that limit is enabled.
+        fn request_limit_window(
+            &self,
+            sender: AccountId,
+            destination_chain: ChainId,
+        ) -> Result<Option<(u32, u32)>, Error> {
+            let limits = &self.request_limits;
+            if limits.max_open_total > 0 && self.open_requests.len() as u32 >= limits.max_open_total
+            {
+                return Err(Error::TooManyOpenRequests);
+            }
+            if limits.max_open_per_sender > 0
+                && self.open_requests_by_sender.get(sender).unwrap_or(0)
+                    >= limits.max_open_per_sender
+            {
+                return Err(Error::TooManyOpenRequests);
+            }
 
-                if last_chain_reset < current_day {
-                    chain_volume = 0;
-                    self.chain_last_reset_day
-                        .insert(destination_chain, &current_day);
+            if limits.max_requests_per_chain_window == 0 || limits.chain_window_blocks == 0 {
+                return Ok(None);
+            }
+            let current_block = self.env().block_number();
+            let (window_start, count) = match self.chain_request_window.get(destination_chain) {
+                Some((start, count))
+                    if current_block < start.saturating_add(limits.chain_window_blocks) =>
+                {
+                    (start, count)
                 }
+                _ => (current_block, 0),
+            };
+            if count >= limits.max_requests_per_chain_window {
+                return Err(Error::ChainRateLimited);
+            }
+            Ok(Some((window_start, count)))
+        }
 
-                if chain_volume.saturating_add(amount) > chain_info.chain_daily_limit {
-                    return Err(Error::RateLimitExceeded);
+        /// Advance the signer epoch and strip `signer`'s signatures from
+        /// every pending request.
+        fn on_signer_removed(&mut self, signer: AccountId) {
+            self.signer_epoch = self.signer_epoch.saturating_add(1);
+            self.signer_removed_at.insert(signer, &self.signer_epoch);
+
+            let bit_position = self.readonly_validator_bit_position(&signer);
+            for request_id in self.open_requests.clone() {
+                let Some(mut request) = self.bridge_requests.get(request_id) else {
+                    continue;
+                };
+                if request.status != BridgeOperationStatus::Pending {
+                    continue;
+                }
+                let removed = match (&mut request.signature_storage, bit_position) {
+                    (SignatureStorage::Bitmap(bitmap), Some(bit)) => {
+                        let signed = self.bitmap_has_signature(bitmap, bit);
+                        bitmap[(bit / 8) as usize] &= !(1u8 << (bit % 8));
+                        signed
+                    }
+                    (SignatureStorage::Legacy(signers), _) => {
+                        let before = signers.len();
+                        signers.retain(|s| *s != signer);
+                        signers.len() != before
+                    }
+                    _ => false,
+                };
+                if !removed {
+                    continue;
                 }
+                self.bridge_requests.insert(request_id, &request);
 
-                self.chain_daily_volume
-                    .insert(destination_chain, &(chain_volume + amount));
+                self.env().emit_event(SignatureInvalidated {
+                    request_id,
+                    signer,
+                    signatures_collected: request.signature_count(),
+                });
+            }
+        }
+
+        // ── Bridge fee helper methods ──────────────────────────────────
+
+        /// Returns the fee part of the value sent with the call, or
+        /// `Err(InsufficientFee)` if it does not cover `required` plus the
+        /// gas deposit.
+        fn ensure_fee_paid(&self, required: u128) -> Result<u128, Error> {
+            let paid = self.env().transferred_value();
+            if paid < required.saturating_add(self.gas_deposit_amount) {
+                return Err(Error::InsufficientFee);
+            }
+            Ok(paid - self.gas_deposit_amount)
+        }
+
+        /// Record the gas deposit paid with a new request.
+        fn hold_gas_deposit(&mut self, request_id: u64) {
+            if self.gas_deposit_amount == 0 {
+                return;
             }
+            self.gas_deposits.insert(
+                request_id,
+                &GasDeposit {
+                    amount: self.gas_deposit_amount,
+                    settled: false,
+                },
+            );
+        }
 
+        /// Reimburse the executing operator with a completed request's gas
+        /// deposit. Fails without settling the deposit if the transfer does
+        /// not go through.
+        fn pay_gas_deposit(&mut self, request_id: u64, executor: AccountId) -> Result<(), Error> {
+            let Some(mut deposit) = self.gas_deposits.get(request_id) else {
+                return Ok(());
+            };
+            if deposit.settled {
+                return Ok(());
+            }
+            if self.env().transfer(executor, deposit.amount).is_err() {
+                return Err(Error::FeeTransferFailed);
+            }
+            deposit.settled = true;
+            self.gas_deposits.insert(request_id, &deposit);
             Ok(())
         }
 
+        /// Escrow the full value paid for a new request.
+        fn escrow_request_fee(&mut self, request_id: u64, amount: u128) {
+            if amount == 0 {
+                return;
+            }
+            self.request_fees.insert(request_id, &amount);
+            self.fee_accounting.collected = self.fee_accounting.collected.saturating_add(amount);
+            self.fee_accounting.escrowed = self.fee_accounting.escrowed.saturating_add(amount);
+        }
+
+        /// Release a completed request's fee to the operators in equal
+        /// shares; any remainder goes to `executor`.
+        fn release_request_fee(&mut self, request_id: u64, executor: AccountId) {
+            let Some(amount) = self.request_fees.take(request_id) else {
+                return;
+            };
+            self.fee_accounting.escrowed = self.fee_accounting.escrowed.saturating_sub(amount);
+            self.fee_accounting.claimable = self.fee_accounting.claimable.saturating_add(amount);
+
+            let operators = self.bridge_operators.clone();
+            let share = amount / operators.len().max(1) as u128;
+            let mut distributed = 0u128;
+            if share > 0 {
+                for operator in operators.iter() {
+                    self.credit_operator_fee(*operator, share);
+                    distributed = distributed.saturating_add(share);
+                }
+            }
+            self.credit_operator_fee(executor, amount.saturating_sub(distributed));
+        }
+
+        fn credit_operator_fee(&mut self, operator: AccountId, amount: u128) {
+            if amount == 0 {
+                return;
+            }
+            let balance = self.operator_fee_balances.get(operator).unwrap_or(0);
+            self.operator_fee_balances
+                .insert(operator, &balance.saturating_add(amount));
+        }
+
+        /// Return a request's escrowed fee to its requester. The refund is
+        /// pushed immediately; if the transfer fails it stays claimable via
+        /// [`claim_refund`]. No-op when nothing is escrowed.
+        fn refund_request_fee(&mut self, request_id: u64, requester: AccountId) {
+            let Some(amount) = self.request_fees.take(request_id) else {
+                return;
+            };
+            self.fee_accounting.escrowed = self.fee_accounting.escrowed.saturating_sub(amount);
+            self.fee_accounting.refunded = self.fee_accounting.refunded.saturating_add(amount);
+
+            if self.env().transfer(requester, amount).is_err() {
+                let pending = self.refund_balances.get(requester).unwrap_or(0);
+                self.refund_balances
+                    .insert(requester, &pending.saturating_add(amount));
+            }
+
+            self.env().emit_event(BridgeFeeRefunded {
+                request_id,
+                requester,
+                amount,
+            });
+        }
+
         // ── Cross-chain status helper methods ──────────────────────────
 
         /// Initialize the cross-chain transaction tracker for a new request.
@@ -3260,6 +5882,15 @@ mod bridge {
             }
         }
 
+        /// Returns `Err(ChainPaused)` if the corridor for `chain_id` has been
+        /// deactivated. Chains without stored info are treated as active.
+        fn ensure_chain_active(&self, chain_id: ChainId) -> Result<(), Error> {
+            match self.chain_info.get(chain_id) {
+                Some(info) if !info.is_active => Err(Error::ChainPaused),
+                _ => Ok(()),
+            }
+        }
+
         /// Returns `Err(OperationPaused)` if the given op is currently paused.
         fn ensure_not_paused(&self, op: BridgeOperation) -> Result<(), Error> {
             if self.is_op_paused(op) {
@@ -8,7 +8,7 @@ mod tests {
 
     fn setup_bridge() -> PropertyBridge {
         let supported_chains = vec![1, 2, 3];
-        PropertyBridge::new(supported_chains, 2, 5, 100, 500000)
+        PropertyBridge::new(1, supported_chains, 2, 5, 100, 500000)
     }
 
     #[ink::test]
@@ -697,6 +697,7 @@ mod tests {
             confirmation_blocks: 24,
             supported_tokens: Vec::new(),
             chain_daily_limit: 10_000_000_000_000_000_000,
+            gas_limit: None,
         };
         bridge
             .update_chain_info(2, tuned_chain)
@@ -888,6 +889,10 @@ mod tests {
 
     // ── TASK 1: Cross-chain transaction status tracking ─────────────────
 
+    fn metadata_hash() -> Hash {
+        propchain_traits::crypto::hash_encoded(&make_metadata())
+    }
+
     fn make_metadata() -> PropertyMetadata {
         PropertyMetadata {
             location: String::from("Test Property"),
@@ -1225,6 +1230,34 @@ mod tests {
         }
     }
 
+    #[ink::test]
+    fn requests_stored_without_min_signatures_keep_their_full_threshold() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let stored = StoredBridgeRequestV2 {
+            request_id: 9,
+            token_id: 11,
+            source_chain: 1,
+            destination_chain: 2,
+            sender: accounts.alice,
+            recipient: accounts.bob,
+            required_signatures: 3,
+            signature_storage: SignatureStorage::Bitmap([0; SIGNATURE_BITMAP_BYTES]),
+            created_at: 1,
+            expires_at: None,
+            status: BridgeOperationStatus::Pending,
+            multi_hop_status: MultiHopStatus::InProgress,
+            route: Vec::new(),
+            current_hop: 0,
+            total_gas_estimate: 0,
+            metadata: make_metadata(),
+        };
+
+        let decoded = StoredBridgeRequest::decode(&mut &stored.encode()[..]).unwrap();
+        assert_eq!(decoded.min_signatures, 3);
+        let roundtrip = StoredBridgeRequest::decode(&mut &decoded.encode()[..]).unwrap();
+        assert_eq!(roundtrip, decoded);
+    }
+
     #[ink::test]
     fn bitmap_encoding_is_smaller_for_twenty_four_signatures() {
         let signers: Vec<AccountId> = (0u8..24)
@@ -1272,6 +1305,7 @@ mod tests {
             current_hop: 0,
             total_gas_estimate: 0,
             metadata: make_metadata(),
+            min_signatures: 2,
         };
 
         let legacy_bytes = legacy.encode().len();
@@ -1371,4 +1405,2518 @@ mod tests {
         let result = bridge.execute_bridge(request_id);
         assert!(result.is_ok(), "bridge execution should succeed after travel rule data is submitted");
     }
+
+    // ── Configurable source chain ID ─────────────────────────────────────
+
+    #[ink::test]
+    fn transaction_hash_changes_with_configured_chain_id() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let bridge_a = PropertyBridge::new(1, vec![1, 2, 3], 2, 5, 100, 500000);
+        let bridge_b = PropertyBridge::new(3, vec![1, 2, 3], 2, 5, 100, 500000);
+
+        let request = StoredBridgeRequest::new(
+            1,
+            1,
+            1,
+            2,
+            accounts.alice,
+            accounts.bob,
+            2,
+            2,
+            0,
+            None,
+            Vec::new(),
+            0,
+            make_metadata(),
+        );
+
+        assert_eq!(bridge_a.get_chain_id(), 1);
+        assert_eq!(bridge_b.get_chain_id(), 3);
+        assert_ne!(
+            bridge_a.generate_transaction_hash(&request),
+            bridge_b.generate_transaction_hash(&request)
+        );
+    }
+
+    #[ink::test]
+    fn initiate_rejects_local_chain_as_destination() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        let result =
+            bridge.initiate_bridge_multisig(1, 1, accounts.bob, 2, None, make_metadata());
+        assert_eq!(result, Err(Error::InvalidChain));
+
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .expect("initiate");
+        let status = bridge.get_cross_chain_tx_status(request_id).unwrap();
+        assert_eq!(status.source_chain, 1);
+    }
+
+    #[ink::test]
+    fn set_chain_id_is_admin_only_and_one_time() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(bridge.set_chain_id(3), Err(Error::Unauthorized));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_chain_id(3).expect("first set succeeds");
+        assert_eq!(bridge.get_chain_id(), 3);
+        assert_eq!(bridge.set_chain_id(2), Err(Error::InvalidRequest));
+        assert_eq!(bridge.get_chain_id(), 3);
+
+        // Chain 1 is now a valid destination, chain 3 is local.
+        bridge
+            .initiate_bridge_multisig(1, 1, accounts.bob, 2, None, make_metadata())
+            .expect("chain 1 is remote after reconfiguration");
+        assert_eq!(
+            bridge.initiate_bridge_multisig(1, 3, accounts.bob, 2, None, make_metadata()),
+            Err(Error::InvalidChain)
+        );
+    }
+
+    // ── Inbound finalization ─────────────────────────────────────────────
+
+    fn operator_keypair(seed: u8) -> (secp256k1::SecretKey, [u8; 33]) {
+        let secp = secp256k1::Secp256k1::new();
+        let secret = secp256k1::SecretKey::from_slice(&[seed; 32]).expect("valid secret key");
+        let public = secp256k1::PublicKey::from_secret_key(&secp, &secret).serialize();
+        (secret, public)
+    }
+
+    fn sign_hash(secret: &secp256k1::SecretKey, hash: Hash) -> [u8; 65] {
+        let secp = secp256k1::Secp256k1::new();
+        let message = secp256k1::Message::from_digest_slice(hash.as_ref()).expect("32-byte digest");
+        let (recovery_id, compact) = secp
+            .sign_ecdsa_recoverable(&message, secret)
+            .serialize_compact();
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        signature
+    }
+
+    /// Bridge with alice, bob and charlie registered as operators with
+    /// ECDSA keys derived from seeds 1, 2 and 3. Threshold is 2.
+    fn setup_inbound_bridge() -> (
+        PropertyBridge,
+        ink::env::test::DefaultAccounts<DefaultEnvironment>,
+        Vec<secp256k1::SecretKey>,
+    ) {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_bridge_operator(accounts.bob).unwrap();
+        bridge.add_bridge_operator(accounts.charlie).unwrap();
+
+        let mut secrets = Vec::new();
+        for (seed, operator) in [(1u8, accounts.alice), (2, accounts.bob), (3, accounts.charlie)] {
+            let (secret, public) = operator_keypair(seed);
+            test::set_caller::<DefaultEnvironment>(operator);
+            bridge.register_operator_public_key(public).unwrap();
+            secrets.push(secret);
+        }
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        (bridge, accounts, secrets)
+    }
+
+    #[ink::test]
+    fn finalize_inbound_accepts_operator_quorum() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        let hash = bridge.get_inbound_message_hash(2, 7, 42, accounts.eve, make_metadata(), metadata_hash());
+
+        let result = bridge.finalize_inbound(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            metadata_hash(),
+            vec![
+                (accounts.alice, sign_hash(&secrets[0], hash)),
+                (accounts.bob, sign_hash(&secrets[1], hash)),
+            ],
+        );
+        assert_eq!(result, Ok(None));
+        assert!(bridge.is_inbound_processed(2, 7));
+
+        let history = bridge.get_bridge_history(accounts.eve);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].source_chain, 2);
+        assert_eq!(history[0].destination_chain, 1);
+        assert_eq!(history[0].token_id, 42);
+    }
+
+    #[ink::test]
+    fn finalize_inbound_rejects_replay() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        let hash = bridge.get_inbound_message_hash(2, 7, 42, accounts.eve, make_metadata(), metadata_hash());
+        let signatures = vec![
+            (accounts.alice, sign_hash(&secrets[0], hash)),
+            (accounts.charlie, sign_hash(&secrets[2], hash)),
+        ];
+
+        bridge
+            .finalize_inbound(2, 7, 42, accounts.eve, make_metadata(), metadata_hash(), signatures.clone())
+            .expect("first finalization");
+        assert_eq!(
+            bridge.finalize_inbound(2, 7, 42, accounts.eve, make_metadata(), metadata_hash(), signatures),
+            Err(Error::InboundAlreadyProcessed)
+        );
+    }
+
+    #[ink::test]
+    fn finalize_inbound_rejects_bad_signature() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        let hash = bridge.get_inbound_message_hash(2, 7, 42, accounts.eve, make_metadata(), metadata_hash());
+
+        // Bob's slot carries Charlie's signature: recovers to the wrong key.
+        let result = bridge.finalize_inbound(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            metadata_hash(),
+            vec![
+                (accounts.alice, sign_hash(&secrets[0], hash)),
+                (accounts.bob, sign_hash(&secrets[2], hash)),
+            ],
+        );
+        assert_eq!(result, Err(Error::InvalidSignature));
+
+        // Signature over a different recipient does not verify either.
+        let other = bridge.get_inbound_message_hash(2, 7, 42, accounts.frank, make_metadata(), metadata_hash());
+        let result = bridge.finalize_inbound(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            metadata_hash(),
+            vec![
+                (accounts.alice, sign_hash(&secrets[0], other)),
+                (accounts.bob, sign_hash(&secrets[1], other)),
+            ],
+        );
+        assert_eq!(result, Err(Error::InvalidSignature));
+
+        // Non-operators cannot contribute signatures.
+        let (frank_secret, _) = operator_keypair(9);
+        let result = bridge.finalize_inbound(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            metadata_hash(),
+            vec![
+                (accounts.alice, sign_hash(&secrets[0], hash)),
+                (accounts.frank, sign_hash(&frank_secret, hash)),
+            ],
+        );
+        assert_eq!(result, Err(Error::InvalidSignature));
+        assert!(!bridge.is_inbound_processed(2, 7));
+    }
+
+    #[ink::test]
+    fn finalize_inbound_requires_threshold_of_distinct_operators() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        let hash = bridge.get_inbound_message_hash(2, 7, 42, accounts.eve, make_metadata(), metadata_hash());
+        let alice_signature = sign_hash(&secrets[0], hash);
+
+        let result = bridge.finalize_inbound(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            metadata_hash(),
+            vec![(accounts.alice, alice_signature), (accounts.alice, alice_signature)],
+        );
+        assert_eq!(result, Err(Error::InsufficientSignatures));
+        assert!(!bridge.is_inbound_processed(2, 7));
+    }
+
+    // ── Per-chain pause ──────────────────────────────────────────────────
+
+    #[ink::test]
+    fn per_chain_pause_with_mixed_states() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let mut bridge = PropertyBridge::new(1, vec![1, 2, 3, 4], 1, 5, 100, 500000);
+        bridge.add_validator(accounts.alice).unwrap();
+
+        let to_chain_2 = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 1, None, make_metadata())
+            .expect("chain 2 active");
+        let to_chain_3 = bridge
+            .initiate_bridge_multisig(2, 3, accounts.bob, 1, None, make_metadata())
+            .expect("chain 3 active");
+
+        bridge.set_chain_active(3, false).expect("admin pauses chain 3");
+        assert!(!bridge.get_chain_info(3).unwrap().is_active);
+
+        // Chain 3 is blocked at every stage; chains 2 and 4 are unaffected.
+        assert_eq!(
+            bridge.initiate_bridge_multisig(3, 3, accounts.bob, 1, None, make_metadata()),
+            Err(Error::ChainPaused)
+        );
+        assert_eq!(
+            bridge.sign_bridge_request(to_chain_3, true),
+            Err(Error::ChainPaused)
+        );
+        bridge
+            .initiate_bridge_multisig(4, 4, accounts.bob, 1, None, make_metadata())
+            .expect("chain 4 still active");
+        bridge.sign_bridge_request(to_chain_2, true).expect("chain 2 signing");
+        bridge.execute_bridge(to_chain_2).expect("chain 2 execution");
+
+        // Re-activating chain 3 lets the in-flight request through, but
+        // pausing it again after signing still blocks execution.
+        bridge.set_chain_active(3, true).unwrap();
+        bridge.sign_bridge_request(to_chain_3, true).expect("chain 3 signing");
+        bridge.set_chain_active(3, false).unwrap();
+        assert_eq!(bridge.execute_bridge(to_chain_3), Err(Error::ChainPaused));
+        bridge.set_chain_active(3, true).unwrap();
+        bridge.execute_bridge(to_chain_3).expect("chain 3 execution");
+    }
+
+    #[ink::test]
+    fn global_pause_overrides_active_chains() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        bridge.set_emergency_pause(true).unwrap();
+        assert_eq!(
+            bridge.initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata()),
+            Err(Error::OperationPaused)
+        );
+        bridge.set_emergency_pause(false).unwrap();
+        bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .expect("unpaused");
+    }
+
+    #[ink::test]
+    fn set_chain_active_requires_admin_and_known_chain() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(bridge.set_chain_active(2, false), Err(Error::Unauthorized));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.set_chain_active(99, false), Err(Error::InvalidChain));
+    }
+
+    // ── Bridge fees ─────────────────────────────────────────────────────────
+
+    fn assert_fee_invariant(bridge: &PropertyBridge) {
+        let fees = bridge.get_fee_accounting();
+        assert_eq!(
+            fees.collected,
+            fees.escrowed + fees.claimable + fees.claimed + fees.refunded
+        );
+    }
+
+    fn setup_fee_bridge() -> (PropertyBridge, ink::env::test::DefaultAccounts<DefaultEnvironment>) {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+        bridge.add_bridge_operator(accounts.bob).unwrap();
+        bridge.set_chain_fee(2, 1_000).unwrap();
+        test::set_account_balance::<DefaultEnvironment>(test::callee::<DefaultEnvironment>(), 1_000_000);
+        (bridge, accounts)
+    }
+
+    #[ink::test]
+    fn initiate_rejects_underpaid_fee() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+
+        test::set_value_transferred::<DefaultEnvironment>(999);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata()),
+            Err(Error::InsufficientFee)
+        );
+
+        // Chains without a fee stay free.
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        assert_eq!(bridge.get_chain_fee(3), 0);
+        bridge
+            .initiate_bridge_multisig(2, 3, accounts.bob, 2, None, make_metadata())
+            .expect("no fee for chain 3");
+        assert_eq!(bridge.get_fee_accounting(), FeeAccounting::default());
+    }
+
+    #[ink::test]
+    fn completed_request_fee_is_split_between_operators() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+
+        test::set_value_transferred::<DefaultEnvironment>(1_001);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        assert_eq!(bridge.get_request_fee(request_id), 1_001);
+        assert_eq!(bridge.get_fee_accounting().escrowed, 1_001);
+        assert_fee_invariant(&bridge);
+
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+
+        // Equal shares; the odd unit goes to the executing operator.
+        assert_eq!(bridge.get_request_fee(request_id), 0);
+        assert_eq!(bridge.get_operator_fee_balance(accounts.alice), 501);
+        assert_eq!(bridge.get_operator_fee_balance(accounts.bob), 500);
+        assert_eq!(bridge.get_fee_accounting().claimable, 1_001);
+        assert_fee_invariant(&bridge);
+
+        assert_eq!(bridge.claim_operator_fees(), Ok(501));
+        assert_eq!(bridge.claim_operator_fees(), Err(Error::InvalidRequest));
+        let fees = bridge.get_fee_accounting();
+        assert_eq!(fees.claimed, 501);
+        assert_eq!(fees.claimable, 500);
+        assert_fee_invariant(&bridge);
+    }
+
+    #[ink::test]
+    fn rejected_and_rolled_back_requests_refund_fee() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_000);
+        let rejected = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        let rolled_back = bridge
+            .initiate_bridge_multisig(2, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        let balance_before = test::get_account_balance::<DefaultEnvironment>(accounts.charlie).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(rejected, false).unwrap();
+        assert_eq!(bridge.get_request_fee(rejected), 0);
+        assert_fee_invariant(&bridge);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge
+            .rollback_bridge_transaction(rolled_back, String::from("operator error"))
+            .unwrap();
+        // A later cancellation does not refund twice.
+        bridge
+            .recover_failed_bridge(rolled_back, RecoveryAction::CancelBridge)
+            .unwrap();
+
+        let fees = bridge.get_fee_accounting();
+        assert_eq!(fees.collected, 2_000);
+        assert_eq!(fees.refunded, 2_000);
+        assert_eq!(fees.escrowed, 0);
+        assert_fee_invariant(&bridge);
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.charlie).unwrap(),
+            balance_before + 2_000
+        );
+        assert_eq!(bridge.get_refund_balance(accounts.charlie), 0);
+    }
+
+    #[ink::test]
+    fn set_chain_fee_requires_admin_and_known_chain() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(bridge.set_chain_fee(2, 10), Err(Error::Unauthorized));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.set_chain_fee(99, 10), Err(Error::InvalidChain));
+        bridge.set_chain_fee(2, 10).unwrap();
+        assert_eq!(bridge.get_chain_fee(2), 10);
+    }
+
+    // ── Cancellation and expiry ─────────────────────────────────────────────
+
+    #[ink::test]
+    fn requester_can_cancel_after_a_signature() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_000);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        // Only the original sender may cancel.
+        assert_eq!(bridge.cancel_bridge_request(request_id), Err(Error::Unauthorized));
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.cancel_bridge_request(request_id).unwrap();
+
+        let monitoring = bridge.monitor_bridge_status(request_id).unwrap();
+        assert_eq!(monitoring.status, BridgeOperationStatus::Failed);
+        assert_eq!(monitoring.signatures_collected, 0);
+        assert_eq!(bridge.get_fee_accounting().refunded, 1_000);
+        assert_fee_invariant(&bridge);
+        assert_eq!(bridge.cancel_bridge_request(request_id), Err(Error::InvalidRequest));
+
+        // Cancelled requests take no further signatures.
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            bridge.sign_bridge_request(request_id, true),
+            Err(Error::InvalidRequest)
+        );
+    }
+
+    #[ink::test]
+    fn expired_locked_request_is_released_and_refunded() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_000);
+        let locked = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, Some(1), make_metadata())
+            .unwrap();
+        let open_ended = bridge
+            .initiate_bridge_multisig(2, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(locked, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(locked, true).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(locked).unwrap().status,
+            BridgeOperationStatus::Locked
+        );
+
+        // Not yet past the deadline: nothing to expire.
+        assert_eq!(bridge.expire_requests(vec![locked]), Ok(Vec::new()));
+
+        test::advance_block::<DefaultEnvironment>();
+        test::advance_block::<DefaultEnvironment>();
+
+        // Anyone may sweep; unknown and non-expiring ids are skipped.
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            bridge.expire_requests(vec![locked, open_ended, 999]),
+            Ok(vec![locked])
+        );
+        assert_eq!(
+            bridge.monitor_bridge_status(locked).unwrap().status,
+            BridgeOperationStatus::Expired
+        );
+        assert_eq!(bridge.get_request_fee(locked), 0);
+        assert_eq!(bridge.get_request_fee(open_ended), 1_000);
+        assert_fee_invariant(&bridge);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.execute_bridge(locked), Err(Error::InvalidRequest));
+        bridge
+            .recover_failed_bridge(locked, RecoveryAction::UnlockToken)
+            .expect("expired request can be unlocked");
+
+        // A second sweep is a no-op.
+        assert_eq!(bridge.expire_requests(vec![locked]), Ok(Vec::new()));
+        assert_eq!(bridge.get_fee_accounting().refunded, 1_000);
+    }
+
+    // ── Request freeze ──────────────────────────────────────────────────────
+
+    #[ink::test]
+    fn frozen_request_blocks_signing_execution_cancel_and_expiry() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_000);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, Some(2), make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+
+        // Only the admin or a security officer may freeze.
+        assert_eq!(
+            bridge.freeze_request(request_id, String::from("stolen token")),
+            Err(Error::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_security_officer(accounts.django).unwrap();
+        assert!(bridge.is_security_officer(accounts.django));
+        assert_eq!(
+            bridge.freeze_request(request_id, String::new()),
+            Err(Error::InvalidRequest)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        bridge
+            .freeze_request(request_id, String::from("stolen token"))
+            .unwrap();
+        assert_eq!(
+            bridge.get_freeze_reason(request_id),
+            Some(String::from("stolen token"))
+        );
+        assert_eq!(
+            bridge.freeze_request(request_id, String::from("again")),
+            Err(Error::RequestFrozen)
+        );
+        let history = bridge.get_request_status_history(request_id);
+        assert_eq!(
+            history.last().unwrap().2,
+            Some(String::from("Frozen: stolen token"))
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            bridge.sign_bridge_request(request_id, true),
+            Err(Error::RequestFrozen)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(bridge.execute_bridge(request_id), Err(Error::RequestFrozen));
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            bridge.cancel_bridge_request(request_id),
+            Err(Error::RequestFrozen)
+        );
+
+        // Frozen requests outlive their deadline.
+        for _ in 0..4 {
+            test::advance_block::<DefaultEnvironment>();
+        }
+        assert_eq!(bridge.expire_requests(vec![request_id]), Ok(Vec::new()));
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Pending
+        );
+        assert_eq!(bridge.get_request_fee(request_id), 1_000);
+    }
+
+    #[ink::test]
+    fn unfrozen_request_can_be_signed_and_executed() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 3, accounts.charlie, 2, Some(2), make_metadata())
+            .unwrap();
+        let expires_at = bridge.monitor_bridge_status(request_id).unwrap().expires_at;
+
+        bridge
+            .freeze_request(request_id, String::from("review"))
+            .unwrap();
+        let events_before = test::recorded_events().count();
+        for _ in 0..5 {
+            test::advance_block::<DefaultEnvironment>();
+        }
+
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(bridge.unfreeze_request(request_id), Err(Error::Unauthorized));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.unfreeze_request(request_id).unwrap();
+        assert_eq!(test::recorded_events().count(), events_before + 1);
+        assert_eq!(bridge.get_freeze_reason(request_id), None);
+        assert_eq!(bridge.unfreeze_request(request_id), Err(Error::InvalidRequest));
+
+        // The deadline moved back by the five blocks spent frozen.
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().expires_at,
+            expires_at.map(|block| block + 5)
+        );
+
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        bridge.execute_bridge(request_id).unwrap();
+
+        // Finished requests cannot be frozen.
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            bridge.freeze_request(request_id, String::from("late")),
+            Err(Error::InvalidRequest)
+        );
+    }
+
+    // ── Duplicate request detection ─────────────────────────────────────────
+
+    #[ink::test]
+    fn second_request_for_active_token_is_rejected() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.get_active_request(1), Some(request_id));
+        assert_eq!(
+            bridge.initiate_bridge_multisig(1, 3, accounts.bob, 2, None, make_metadata()),
+            Err(Error::DuplicateRequest)
+        );
+        assert_eq!(
+            bridge.initiate_multi_hop_bridge(1, vec![2, 3], accounts.bob, 2, None, make_metadata()),
+            Err(Error::DuplicateRequest)
+        );
+        // Other tokens are unaffected.
+        bridge
+            .initiate_bridge_multisig(2, 2, accounts.bob, 2, None, make_metadata())
+            .expect("different token");
+    }
+
+    #[ink::test]
+    fn token_can_be_re_requested_after_cancellation() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        let first = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        bridge.cancel_bridge_request(first).unwrap();
+        assert_eq!(bridge.get_active_request(1), None);
+
+        let second = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .expect("re-request after cancellation");
+        assert_eq!(bridge.get_active_request(1), Some(second));
+
+        // The cancelled request cannot be revived while the token is busy.
+        assert_eq!(
+            bridge.recover_failed_bridge(first, RecoveryAction::RetryBridge),
+            Err(Error::DuplicateRequest)
+        );
+    }
+
+    #[ink::test]
+    fn token_can_be_re_requested_after_completion() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        // Still active while locked awaiting execution.
+        assert_eq!(bridge.get_active_request(1), Some(request_id));
+        bridge.execute_bridge(request_id).unwrap();
+        assert_eq!(bridge.get_active_request(1), None);
+
+        bridge
+            .initiate_bridge_multisig(1, 3, accounts.bob, 2, None, make_metadata())
+            .expect("re-request after completion");
+    }
+
+    // ── Offline operator signatures ─────────────────────────────────────────
+
+    /// Inbound-bridge operators, with alice and bob also validators, and a
+    /// pending request for token 1 created by alice.
+    fn setup_offline_signing() -> (
+        PropertyBridge,
+        ink::env::test::DefaultAccounts<DefaultEnvironment>,
+        Vec<secp256k1::SecretKey>,
+        u64,
+    ) {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        (bridge, accounts, secrets, request_id)
+    }
+
+    #[ink::test]
+    fn offline_operator_signatures_reach_quorum() {
+        let (mut bridge, accounts, secrets, request_id) = setup_offline_signing();
+        let payload = bridge.get_operator_signing_payload(request_id).unwrap();
+
+        // Any account may relay the signatures.
+        bridge
+            .submit_operator_signature(request_id, sign_hash(&secrets[0], payload))
+            .expect("alice's signature");
+        assert_eq!(
+            bridge.submit_operator_signature(request_id, sign_hash(&secrets[0], payload)),
+            Err(Error::AlreadySigned)
+        );
+        bridge
+            .submit_operator_signature(request_id, sign_hash(&secrets[1], payload))
+            .expect("bob's signature");
+
+        let monitoring = bridge.monitor_bridge_status(request_id).unwrap();
+        assert_eq!(monitoring.signatures_collected, 2);
+        assert_eq!(monitoring.status, BridgeOperationStatus::Locked);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).expect("executes on offline quorum");
+    }
+
+    #[ink::test]
+    fn offline_and_caller_signatures_share_duplicate_rules() {
+        let (mut bridge, accounts, secrets, request_id) = setup_offline_signing();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+
+        let payload = bridge.get_operator_signing_payload(request_id).unwrap();
+        assert_eq!(
+            bridge.submit_operator_signature(request_id, sign_hash(&secrets[1], payload)),
+            Err(Error::AlreadySigned)
+        );
+        // Charlie holds an operator key but is not a validator.
+        assert_eq!(
+            bridge.submit_operator_signature(request_id, sign_hash(&secrets[2], payload)),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(bridge.monitor_bridge_status(request_id).unwrap().signatures_collected, 1);
+    }
+
+    #[ink::test]
+    fn tampered_or_unknown_operator_signatures_are_rejected() {
+        let (mut bridge, accounts, secrets, request_id) = setup_offline_signing();
+        let payload = bridge.get_operator_signing_payload(request_id).unwrap();
+
+        // Signature over a payload with a different recipient.
+        let tampered = propchain_traits::crypto::hash_encoded(&(
+            REQUEST_ENCODING_VERSION,
+            b"propchain:bridge:request",
+            bridge.get_chain_id(),
+            request_id,
+            1u64,
+            1u64,
+            2u64,
+            accounts.alice,
+            accounts.django,
+            2u8,
+            0u32,
+            propchain_traits::crypto::hash_encoded(&make_metadata()),
+        ));
+        assert_ne!(tampered, payload);
+        assert_eq!(
+            bridge.submit_operator_signature(request_id, sign_hash(&secrets[0], tampered)),
+            Err(Error::InvalidSignature)
+        );
+
+        // A valid signature from a key no operator registered.
+        let (stranger, _) = operator_keypair(9);
+        assert_eq!(
+            bridge.submit_operator_signature(request_id, sign_hash(&stranger, payload)),
+            Err(Error::InvalidSignature)
+        );
+
+        // A signature for another request does not carry over.
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let other = bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        let other_payload = bridge.get_operator_signing_payload(other).unwrap();
+        assert_eq!(
+            bridge.submit_operator_signature(request_id, sign_hash(&secrets[0], other_payload)),
+            Err(Error::InvalidSignature)
+        );
+        assert_eq!(bridge.monitor_bridge_status(request_id).unwrap().signatures_collected, 0);
+    }
+
+    #[ink::test]
+    fn request_digest_is_canonical_and_deterministic() {
+        let (bridge, _, _, request_id) = setup_offline_signing();
+        let encoding = bridge.encode_request(request_id).unwrap();
+        let digest = bridge.request_digest(request_id).unwrap();
+
+        assert_eq!(encoding[0], REQUEST_ENCODING_VERSION);
+        assert_eq!(bridge.encode_request(request_id), Some(encoding.clone()));
+        assert_eq!(digest, propchain_traits::crypto::hash_blake2b256(&encoding));
+        assert_eq!(bridge.get_operator_signing_payload(request_id), Some(digest));
+        assert_eq!(bridge.request_digest(request_id + 100), None);
+        assert_eq!(bridge.encode_request(request_id + 100), None);
+    }
+
+    #[ink::test]
+    fn distinct_requests_do_not_share_a_digest() {
+        let (mut bridge, accounts, _, first) = setup_offline_signing();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        // Same route and metadata; only the token and recipient differ.
+        let second = bridge
+            .initiate_bridge_multisig(2, 2, accounts.frank, 2, None, make_metadata())
+            .unwrap();
+        test::advance_block::<DefaultEnvironment>();
+        let third = bridge
+            .initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+
+        let digests = [first, second, third].map(|id| bridge.request_digest(id).unwrap());
+        assert_ne!(digests[0], digests[1]);
+        assert_ne!(digests[0], digests[2]);
+        assert_ne!(digests[1], digests[2]);
+    }
+
+    #[ink::test]
+    fn operator_public_keys_are_unique() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let (_, public) = operator_keypair(4);
+
+        bridge
+            .add_bridge_operator_with_key(accounts.bob, public)
+            .unwrap();
+        assert_eq!(bridge.get_operator_by_public_key(public), Some(accounts.bob));
+        assert_eq!(
+            bridge.add_bridge_operator_with_key(accounts.charlie, public),
+            Err(Error::InvalidRequest)
+        );
+
+        // Rotating bob's key frees the old one.
+        let (_, rotated) = operator_keypair(5);
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.register_operator_public_key(rotated).unwrap();
+        assert_eq!(bridge.get_operator_by_public_key(public), None);
+        assert_eq!(bridge.get_operator_by_public_key(rotated), Some(accounts.bob));
+    }
+
+    // ── Signer set changes ──────────────────────────────────────────────────
+
+    #[ink::test]
+    fn removing_signer_mid_collection_strips_their_signature() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        for validator in [accounts.alice, accounts.bob, accounts.charlie] {
+            bridge.add_validator(validator).unwrap();
+        }
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 3, None, make_metadata())
+            .unwrap();
+
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(bridge.monitor_bridge_status(request_id).unwrap().signatures_collected, 2);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.remove_validator(accounts.bob).unwrap();
+        assert_eq!(bridge.monitor_bridge_status(request_id).unwrap().signatures_collected, 1);
+        assert!(!bridge.get_signer_list(request_id).unwrap().contains(&accounts.bob));
+
+        // Once re-added, bob still cannot sign the same round again.
+        bridge.add_validator(accounts.bob).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            bridge.sign_bridge_request(request_id, true),
+            Err(Error::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Pending
+        );
+
+        // Requests created after the re-add accept bob's signature.
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let later = bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 3, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(later, true).expect("fresh round");
+    }
+
+    #[ink::test]
+    fn signer_sets_cannot_drop_below_threshold() {
+        let mut bridge = setup_bridge(); // min_signatures_required = 2
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+        assert_eq!(
+            bridge.remove_validator(accounts.bob),
+            Err(Error::InsufficientSignatures)
+        );
+
+        bridge.add_bridge_operator(accounts.bob).unwrap();
+        assert_eq!(
+            bridge.remove_bridge_operator(accounts.bob),
+            Err(Error::InsufficientSignatures)
+        );
+        bridge.add_bridge_operator(accounts.charlie).unwrap();
+        bridge.remove_bridge_operator(accounts.bob).unwrap();
+        assert_eq!(
+            bridge.get_bridge_operators(),
+            vec![accounts.alice, accounts.charlie]
+        );
+        // Removing a non-member is a no-op.
+        bridge.remove_bridge_operator(accounts.bob).unwrap();
+    }
+
+    // ── History pagination and lookup ───────────────────────────────────────
+
+    #[ink::test]
+    fn history_indexes_stay_consistent_across_executions() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+
+        let senders = [accounts.charlie, accounts.django, accounts.eve];
+        let mut expected = Vec::new();
+        for round in 0..4u64 {
+            for (index, sender) in senders.iter().enumerate() {
+                test::advance_block::<DefaultEnvironment>();
+                test::set_caller::<DefaultEnvironment>(*sender);
+                let token_id = round * 10 + index as u64;
+                let request_id = bridge
+                    .initiate_bridge_multisig(token_id, 2, accounts.frank, 2, None, make_metadata())
+                    .unwrap();
+                for validator in [accounts.alice, accounts.bob] {
+                    test::set_caller::<DefaultEnvironment>(validator);
+                    bridge.sign_bridge_request(request_id, true).unwrap();
+                }
+                // Alice deployed the bridge and is its operator.
+                test::set_caller::<DefaultEnvironment>(accounts.alice);
+                bridge.execute_bridge(request_id).unwrap();
+                expected.push((*sender, token_id));
+            }
+        }
+
+        // Every transaction is reachable by ID and by hash, and the two agree.
+        for transaction_id in 1..=12u64 {
+            let transaction = bridge.get_transaction(transaction_id).expect("stored");
+            assert_eq!(transaction.transaction_id, transaction_id);
+            assert_eq!(
+                bridge.get_transaction_by_hash(transaction.transaction_hash),
+                Some(transaction.clone())
+            );
+            let (sender, token_id) = expected[(transaction_id - 1) as usize];
+            assert_eq!(transaction.sender, sender);
+            assert_eq!(transaction.token_id, token_id);
+        }
+        assert_eq!(bridge.get_transaction(13), None);
+        assert_eq!(bridge.get_transaction_by_hash(Hash::from([0u8; 32])), None);
+
+        // Per-account pages cover the account's history exactly once, in order.
+        for sender in senders {
+            assert_eq!(bridge.get_bridge_history_count(sender), 4);
+            let mut paged = bridge.get_bridge_history_paginated(sender, 0, 3);
+            assert_eq!(paged.len(), 3);
+            paged.extend(bridge.get_bridge_history_paginated(sender, 3, 3));
+            assert_eq!(paged, bridge.get_bridge_history(sender));
+            assert!(paged.windows(2).all(|w| w[0].transaction_id < w[1].transaction_id));
+            assert!(paged.iter().all(|tx| tx.sender == sender));
+            assert!(bridge.get_bridge_history_paginated(sender, 4, 3).is_empty());
+        }
+    }
+
+    #[ink::test]
+    fn history_queries_are_capped() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        for transaction_id in 1..=(MAX_HISTORY_PAGE_SIZE as u64 + 5) {
+            let transaction = BridgeTransaction {
+                transaction_id,
+                token_id: transaction_id,
+                source_chain: 1,
+                destination_chain: 2,
+                sender: accounts.bob,
+                recipient: accounts.charlie,
+                transaction_hash: Hash::from([transaction_id as u8; 32]),
+                timestamp: 0,
+                gas_used: 0,
+                status: BridgeOperationStatus::InTransit,
+                metadata: make_metadata(),
+            };
+            bridge.record_transaction(accounts.bob, &transaction);
+        }
+
+        let recent = bridge.get_bridge_history(accounts.bob);
+        assert_eq!(recent.len(), MAX_HISTORY_PAGE_SIZE as usize);
+        assert_eq!(recent[0].transaction_id, 6);
+        assert_eq!(
+            bridge.get_bridge_history_paginated(accounts.bob, 0, u32::MAX).len(),
+            MAX_HISTORY_PAGE_SIZE as usize
+        );
+        assert_eq!(bridge.get_bridge_history_count(accounts.bob), MAX_HISTORY_PAGE_SIZE + 5);
+    }
+
+    // ── Request limits ──────────────────────────────────────────────────────
+
+    fn limited_bridge(limits: RequestLimits) -> (PropertyBridge, ink::env::test::DefaultAccounts<DefaultEnvironment>) {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+        bridge.set_request_limits(limits).unwrap();
+        (bridge, accounts)
+    }
+
+    #[ink::test]
+    fn open_requests_per_sender_are_limited_until_one_closes() {
+        let (mut bridge, accounts) = limited_bridge(RequestLimits {
+            max_open_per_sender: 2,
+            ..Default::default()
+        });
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let first = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.get_open_request_count(accounts.charlie), 2);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::TooManyOpenRequests)
+        );
+
+        // Other senders are unaffected.
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        bridge
+            .initiate_bridge_multisig(4, 2, accounts.eve, 2, None, make_metadata())
+            .expect("separate sender quota");
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.cancel_bridge_request(first).unwrap();
+        assert_eq!(bridge.get_open_request_count(accounts.charlie), 1);
+        bridge
+            .initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata())
+            .expect("slot freed by cancellation");
+    }
+
+    #[ink::test]
+    fn global_open_request_ceiling_recovers_after_completion() {
+        let (mut bridge, accounts) = limited_bridge(RequestLimits {
+            max_open_total: 2,
+            ..Default::default()
+        });
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let first = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::TooManyOpenRequests)
+        );
+
+        for validator in [accounts.alice, accounts.bob] {
+            test::set_caller::<DefaultEnvironment>(validator);
+            bridge.sign_bridge_request(first, true).unwrap();
+        }
+        // Locked requests still count as open.
+        assert_eq!(bridge.get_total_open_requests(), 2);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(first).unwrap();
+        assert_eq!(bridge.get_total_open_requests(), 1);
+        assert_eq!(bridge.get_open_request_count(accounts.charlie), 0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        bridge
+            .initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata())
+            .expect("slot freed by completion");
+    }
+
+    #[ink::test]
+    fn per_chain_window_limits_requests() {
+        let (mut bridge, accounts) = limited_bridge(RequestLimits {
+            chain_window_blocks: 10,
+            max_requests_per_chain_window: 2,
+            ..Default::default()
+        });
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::ChainRateLimited)
+        );
+        bridge
+            .initiate_bridge_multisig(3, 3, accounts.eve, 2, None, make_metadata())
+            .expect("chain 3 has its own window");
+
+        for _ in 0..10 {
+            test::advance_block::<DefaultEnvironment>();
+        }
+        bridge
+            .initiate_bridge_multisig(4, 2, accounts.eve, 2, None, make_metadata())
+            .expect("new window");
+    }
+
+    #[ink::test]
+    fn set_request_limits_is_admin_only() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let limits = RequestLimits {
+            max_open_per_sender: 1,
+            ..Default::default()
+        };
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(bridge.set_request_limits(limits.clone()), Err(Error::Unauthorized));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_request_limits(limits.clone()).unwrap();
+        assert_eq!(bridge.get_request_limits(), limits);
+    }
+
+    // ── Status history ──────────────────────────────────────────────────────
+
+    #[ink::test]
+    fn status_history_records_fail_and_retry() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.monitor_bridge_status(request_id).unwrap().error_message, None);
+
+        test::advance_block::<DefaultEnvironment>();
+        bridge.sign_bridge_request(request_id, false).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().error_message,
+            Some(String::from("Rejected by validator"))
+        );
+
+        test::advance_block::<DefaultEnvironment>();
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::RetryBridge)
+            .unwrap();
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::advance_block::<DefaultEnvironment>();
+        bridge.execute_bridge(request_id).unwrap();
+
+        assert_eq!(
+            bridge.get_request_status_history(request_id),
+            vec![
+                (BridgeOperationStatus::Pending, 0, None),
+                (
+                    BridgeOperationStatus::Failed,
+                    1,
+                    Some(String::from("Rejected by validator"))
+                ),
+                (BridgeOperationStatus::Pending, 2, None),
+                (BridgeOperationStatus::Locked, 2, None),
+                (BridgeOperationStatus::Completed, 3, None),
+            ]
+        );
+        // The last failure stays visible to support tooling.
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().error_message,
+            Some(String::from("Rejected by validator"))
+        );
+    }
+
+    #[ink::test]
+    fn status_history_records_rollback_reason_and_is_capped() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        bridge
+            .rollback_bridge_transaction(request_id, String::from("relayer outage"))
+            .unwrap();
+        let history = bridge.get_request_status_history(request_id);
+        assert_eq!(
+            history.last(),
+            Some(&(
+                BridgeOperationStatus::Failed,
+                0,
+                Some(String::from("relayer outage"))
+            ))
+        );
+
+        for _ in 0..STATUS_HISTORY_LIMIT {
+            bridge.record_status(request_id, BridgeOperationStatus::Pending, None);
+        }
+        let history = bridge.get_request_status_history(request_id);
+        assert_eq!(history.len(), STATUS_HISTORY_LIMIT);
+        assert!(history.iter().all(|(status, _, _)| *status == BridgeOperationStatus::Pending));
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().error_message,
+            None
+        );
+        assert!(bridge.get_request_status_history(999).is_empty());
+    }
+
+    // ── Per-chain token allow-lists ─────────────────────────────────────────
+
+    #[ink::test]
+    fn chains_without_allow_list_accept_any_token() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        assert_eq!(bridge.get_supported_token_count(2), 0);
+        assert!(bridge.is_token_supported(2, 12345));
+        bridge
+            .initiate_bridge_multisig(12345, 2, accounts.bob, 2, None, make_metadata())
+            .expect("allow-all chain");
+    }
+
+    #[ink::test]
+    fn restricted_chain_only_accepts_listed_tokens() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        bridge.add_supported_token(2, 1).unwrap();
+        bridge.add_supported_token(2, 1).unwrap();
+        assert_eq!(bridge.get_supported_token_count(2), 1);
+        assert!(bridge.is_token_supported(2, 1));
+        assert!(!bridge.is_token_supported(2, 7));
+        // Chain 3 is still unrestricted.
+        assert!(bridge.is_token_supported(3, 7));
+
+        assert_eq!(
+            bridge.initiate_bridge_multisig(7, 2, accounts.bob, 2, None, make_metadata()),
+            Err(Error::BridgeNotSupported)
+        );
+        assert_eq!(
+            bridge.initiate_multi_hop_bridge(7, vec![3, 2], accounts.bob, 2, None, make_metadata()),
+            Err(Error::BridgeNotSupported)
+        );
+        bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .expect("listed token");
+        bridge
+            .initiate_bridge_multisig(7, 3, accounts.bob, 2, None, make_metadata())
+            .expect("unrestricted chain");
+
+        // Emptying the list restores allow-all.
+        bridge.remove_supported_token(2, 1).unwrap();
+        assert_eq!(bridge.get_supported_token_count(2), 0);
+        assert!(bridge.is_token_supported(2, 8));
+    }
+
+    #[ink::test]
+    fn supported_token_management_is_admin_only() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(bridge.add_supported_token(2, 1), Err(Error::Unauthorized));
+        assert_eq!(bridge.remove_supported_token(2, 1), Err(Error::Unauthorized));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.add_supported_token(99, 1), Err(Error::InvalidChain));
+    }
+
+    // ── Outbound payloads ───────────────────────────────────────────────────
+
+    #[ink::test]
+    fn execute_produces_deterministic_outbound_payload() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+        let evm_address = vec![0xABu8; 20];
+        bridge.set_destination_contract(2, evm_address.clone()).unwrap();
+        assert_eq!(bridge.get_destination_contract(2), Some(evm_address.clone()));
+        // Non-32-byte addresses are not representable as an AccountId.
+        assert_eq!(bridge.get_chain_info(2).unwrap().bridge_contract_address, None);
+
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.get_outbound_payload(request_id), None);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+
+        let encoded = bridge.get_outbound_payload(request_id).expect("payload stored");
+        let decoded = <OutboundPayload as scale::Decode>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(
+            decoded,
+            OutboundPayload {
+                version: OUTBOUND_PAYLOAD_VERSION,
+                source_chain: 1,
+                destination_chain: 2,
+                request_id,
+                token_id: 1,
+                recipient: accounts.eve,
+                metadata_hash: propchain_traits::crypto::hash_encoded(&make_metadata()),
+                destination_contract: evm_address,
+            }
+        );
+
+        // Encoding the same payload twice yields identical bytes.
+        let request = bridge.bridge_requests.get(request_id).unwrap();
+        let first = scale::Encode::encode(&bridge.outbound_payload(&request, request_id, 1, 2));
+        let second = scale::Encode::encode(&bridge.outbound_payload(&request, request_id, 1, 2));
+        assert_eq!(first, second);
+        assert_eq!(first, encoded);
+    }
+
+    #[ink::test]
+    fn set_destination_contract_validates_input() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            bridge.set_destination_contract(2, vec![1u8; 32]),
+            Err(Error::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            bridge.set_destination_contract(99, vec![1u8; 32]),
+            Err(Error::InvalidChain)
+        );
+        assert_eq!(
+            bridge.set_destination_contract(2, Vec::new()),
+            Err(Error::InvalidRequest)
+        );
+        bridge.set_destination_contract(2, vec![1u8; 32]).unwrap();
+        assert_eq!(
+            bridge.get_chain_info(2).unwrap().bridge_contract_address,
+            Some(AccountId::from([1u8; 32]))
+        );
+    }
+
+    // ── Gas deposits ────────────────────────────────────────────────────────
+
+    #[ink::test]
+    fn gas_deposit_is_required_and_refunded_once() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        bridge.set_gas_deposit(300).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        // The chain fee alone no longer suffices.
+        test::set_value_transferred::<DefaultEnvironment>(1_000);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata()),
+            Err(Error::InsufficientFee)
+        );
+        test::set_value_transferred::<DefaultEnvironment>(1_300);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        assert_eq!(bridge.get_request_fee(request_id), 1_000);
+        // Not refundable while the request is still live.
+        assert_eq!(bridge.get_refundable_amount(request_id), 0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, false).unwrap();
+        assert_eq!(bridge.get_refundable_amount(request_id), 300);
+
+        let balance_before = test::get_account_balance::<DefaultEnvironment>(accounts.charlie).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::RefundGas)
+            .unwrap();
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.charlie).unwrap(),
+            balance_before + 300
+        );
+        assert_eq!(bridge.get_refundable_amount(request_id), 0);
+        assert_eq!(
+            bridge.recover_failed_bridge(request_id, RecoveryAction::RefundGas),
+            Err(Error::InvalidRequest)
+        );
+        assert_fee_invariant(&bridge);
+    }
+
+    #[ink::test]
+    fn gas_deposit_is_paid_to_the_executor_once() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        bridge.set_gas_deposit(300).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_300);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        for validator in [accounts.alice, accounts.bob] {
+            test::set_caller::<DefaultEnvironment>(validator);
+            bridge.sign_bridge_request(request_id, true).unwrap();
+        }
+
+        let balance_before = test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+        assert_eq!(
+            test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap(),
+            balance_before + 300
+        );
+        assert!(bridge.gas_deposits.get(request_id).unwrap().settled);
+        assert_eq!(bridge.get_refundable_amount(request_id), 0);
+    }
+
+    #[ink::test]
+    fn gas_deposit_refund_after_expiry() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        bridge.set_gas_deposit(300).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_300);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, Some(1), make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        // Only failed or expired requests can be refunded.
+        assert_eq!(
+            bridge.recover_failed_bridge(request_id, RecoveryAction::RefundGas),
+            Err(Error::InvalidRequest)
+        );
+
+        test::advance_block::<DefaultEnvironment>();
+        test::advance_block::<DefaultEnvironment>();
+        bridge.expire_requests(vec![request_id]).unwrap();
+        assert_eq!(bridge.get_refundable_amount(request_id), 300);
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::RefundGas)
+            .unwrap();
+        assert_eq!(bridge.get_refundable_amount(request_id), 0);
+    }
+
+    #[ink::test]
+    fn requests_without_deposit_cannot_refund_gas() {
+        let (mut bridge, accounts) = setup_fee_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_000);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, make_metadata())
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        bridge.cancel_bridge_request(request_id).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.get_refundable_amount(request_id), 0);
+        assert_eq!(
+            bridge.recover_failed_bridge(request_id, RecoveryAction::RefundGas),
+            Err(Error::InvalidRequest)
+        );
+    }
+
+    // ── Metadata integrity ──────────────────────────────────────────────────
+
+    #[ink::test]
+    fn corrupted_metadata_is_rejected_across_the_corridor() {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let destination_callee = test::callee::<DefaultEnvironment>();
+
+        // Source side: chain 2 executes a request bound for chain 1.
+        test::set_callee::<DefaultEnvironment>(AccountId::from([0x42; 32]));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let mut source = PropertyBridge::new(2, vec![1, 2, 3], 2, 5, 100, 500000);
+        source.add_validator(accounts.alice).unwrap();
+        source.add_validator(accounts.bob).unwrap();
+        let request_id = source
+            .initiate_bridge_multisig(42, 1, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(source.get_metadata_hash(request_id), Some(metadata_hash()));
+        source.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        source.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        source.execute_bridge(request_id).unwrap();
+        let encoded = source.get_outbound_payload(request_id).unwrap();
+        let payload = <OutboundPayload as scale::Decode>::decode(&mut &encoded[..]).unwrap();
+        assert_eq!(payload.metadata_hash, metadata_hash());
+
+        // Destination side: the relayed metadata was altered in transit.
+        test::set_callee::<DefaultEnvironment>(destination_callee);
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        let mut corrupted = make_metadata();
+        corrupted.valuation += 1;
+        let hash = bridge.get_inbound_message_hash(2, request_id, 42, accounts.eve, corrupted.clone(), payload.metadata_hash);
+        let signatures = vec![
+            (accounts.alice, sign_hash(&secrets[0], hash)),
+            (accounts.bob, sign_hash(&secrets[1], hash)),
+        ];
+        assert_eq!(
+            bridge.finalize_inbound(
+                2,
+                request_id,
+                42,
+                accounts.eve,
+                corrupted,
+                payload.metadata_hash,
+                signatures,
+            ),
+            Err(Error::MetadataCorruption)
+        );
+        assert!(!bridge.is_inbound_processed(2, request_id));
+
+        // The genuine metadata is accepted and verified.
+        let events_before = test::recorded_events().count();
+        let hash = bridge.get_inbound_message_hash(2, request_id, 42, accounts.eve, make_metadata(), payload.metadata_hash);
+        let signatures = vec![
+            (accounts.alice, sign_hash(&secrets[0], hash)),
+            (accounts.bob, sign_hash(&secrets[1], hash)),
+        ];
+        assert_eq!(
+            bridge.finalize_inbound(
+                2,
+                request_id,
+                42,
+                accounts.eve,
+                make_metadata(),
+                payload.metadata_hash,
+                signatures,
+            ),
+            Ok(None)
+        );
+        // MetadataVerified followed by InboundBridgeFinalized.
+        assert_eq!(test::recorded_events().count(), events_before + 2);
+    }
+
+    #[ink::test]
+    fn execute_rejects_tampered_request_metadata() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_validator(accounts.alice).unwrap();
+        bridge.add_validator(accounts.bob).unwrap();
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+
+        let mut request = bridge.bridge_requests.get(request_id).unwrap();
+        request.metadata.legal_description = String::from("Tampered");
+        bridge.bridge_requests.insert(request_id, &request);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.execute_bridge(request_id), Err(Error::MetadataCorruption));
+    }
+
+    #[ink::test]
+    fn metadata_checks_are_skipped_when_preservation_is_off() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let mut config = bridge.get_config();
+        config.metadata_preservation = false;
+        config.max_signatures_required = 3;
+        bridge.update_config(config).unwrap();
+
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.get_metadata_hash(request_id), None);
+
+        let hash = bridge.get_inbound_message_hash(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            Hash::from([0u8; 32]),
+        );
+        let result = bridge.finalize_inbound(
+            2,
+            7,
+            42,
+            accounts.eve,
+            make_metadata(),
+            Hash::from([0u8; 32]),
+            vec![
+                (accounts.alice, sign_hash(&secrets[0], hash)),
+                (accounts.bob, sign_hash(&secrets[1], hash)),
+            ],
+        );
+        assert_eq!(result, Ok(None));
+    }
+
+    #[ink::test]
+    fn finalize_inbound_binds_the_signed_metadata_hash() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        let forged = Hash::from([0xab; 32]);
+
+        // Operators signed a hash that does not match the metadata.
+        let hash = bridge.get_inbound_message_hash(2, 7, 42, accounts.eve, make_metadata(), forged);
+        let signatures = vec![
+            (accounts.alice, sign_hash(&secrets[0], hash)),
+            (accounts.bob, sign_hash(&secrets[1], hash)),
+        ];
+        assert_eq!(
+            bridge.finalize_inbound(2, 7, 42, accounts.eve, make_metadata(), forged, signatures),
+            Err(Error::MetadataCorruption)
+        );
+
+        // Without the metadata check, a relayer still cannot swap in a hash
+        // the operators did not sign.
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let mut config = bridge.get_config();
+        config.metadata_preservation = false;
+        config.max_signatures_required = 3;
+        bridge.update_config(config).unwrap();
+        let hash = bridge.get_inbound_message_hash(2, 7, 42, accounts.eve, make_metadata(), metadata_hash());
+        let signatures = vec![
+            (accounts.alice, sign_hash(&secrets[0], hash)),
+            (accounts.bob, sign_hash(&secrets[1], hash)),
+        ];
+        assert_eq!(
+            bridge.finalize_inbound(2, 7, 42, accounts.eve, make_metadata(), forged, signatures.clone()),
+            Err(Error::InvalidSignature)
+        );
+        assert!(!bridge.is_inbound_processed(2, 7));
+        assert_eq!(
+            bridge.finalize_inbound(2, 7, 42, accounts.eve, make_metadata(), metadata_hash(), signatures),
+            Ok(None)
+        );
+    }
+
+    fn finalize_from(
+        bridge: &mut PropertyBridge,
+        accounts: &ink::env::test::DefaultAccounts<DefaultEnvironment>,
+        secrets: &[secp256k1::SecretKey],
+        source_request_id: u64,
+        token_id: TokenId,
+    ) -> Result<Option<TokenId>, Error> {
+        let hash =
+            bridge.get_inbound_message_hash(2, source_request_id, token_id, accounts.eve, make_metadata(), metadata_hash());
+        bridge.finalize_inbound(
+            2,
+            source_request_id,
+            token_id,
+            accounts.eve,
+            make_metadata(),
+            metadata_hash(),
+            vec![
+                (accounts.alice, sign_hash(&secrets[0], hash)),
+                (accounts.bob, sign_hash(&secrets[1], hash)),
+            ],
+        )
+    }
+
+    #[ink::test]
+    fn bridged_tokens_keep_provenance_and_only_return_to_origin() {
+        let (mut bridge, accounts, secrets) = setup_inbound_bridge();
+        assert_eq!(bridge.get_token_origin(42), None);
+
+        finalize_from(&mut bridge, &accounts, &secrets, 7, 42).unwrap();
+        assert_eq!(bridge.get_token_origin(42), Some((2, 42)));
+        let event = test::recorded_events().last().unwrap();
+        let decoded = <(ChainId, u64, AccountId, TokenId, Option<TokenId>, (ChainId, TokenId))>::decode(
+            &mut &event.data[..],
+        )
+        .unwrap();
+        assert_eq!(decoded.5, (2, 42));
+
+        // Onward to a third chain is refused, directly or via a route.
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(42, 3, accounts.eve, 2, None, make_metadata()),
+            Err(Error::MultiHopNotAllowed)
+        );
+        assert_eq!(
+            bridge.initiate_multi_hop_bridge(42, vec![2, 3], accounts.eve, 2, None, make_metadata()),
+            Err(Error::MultiHopNotAllowed)
+        );
+
+        // Back to the origin chain is fine and keeps the provenance.
+        bridge
+            .initiate_bridge_multisig(42, 2, accounts.eve, 2, None, make_metadata())
+            .expect("returning to the origin chain");
+        assert_eq!(bridge.get_token_origin(42), Some((2, 42)));
+
+        // The token comes back again: the first provenance is retained.
+        finalize_from(&mut bridge, &accounts, &secrets, 8, 42).unwrap();
+        assert_eq!(bridge.get_token_origin(42), Some((2, 42)));
+
+        // With multi-hop enabled bridged tokens may travel on.
+        finalize_from(&mut bridge, &accounts, &secrets, 9, 43).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(
+            bridge.set_allow_bridged_multi_hop(true),
+            Err(Error::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_allow_bridged_multi_hop(true).unwrap();
+        assert!(bridge.is_bridged_multi_hop_allowed());
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        bridge
+            .initiate_bridge_multisig(43, 3, accounts.eve, 2, None, make_metadata())
+            .expect("multi-hop allowed");
+    }
+
+    /// Lock (`true`) and unlock (`false`) events recorded after the first
+    /// `skip` events, as (locked, token_id, request_id, owner).
+    fn lock_events(skip: usize) -> Vec<(bool, TokenId, u64, AccountId)> {
+        let lock = <TokenLockedForBridge as ink::env::Event>::SIGNATURE_TOPIC.unwrap();
+        let unlock = <TokenUnlockedFromBridge as ink::env::Event>::SIGNATURE_TOPIC.unwrap();
+        test::recorded_events()
+            .skip(skip)
+            .filter_map(|event| {
+                let topic: [u8; 32] = event.topics.first()?.as_slice().try_into().ok()?;
+                let locked = if topic == lock {
+                    true
+                } else if topic == unlock {
+                    false
+                } else {
+                    return None;
+                };
+                let (token_id, request_id, owner) =
+                    <(TokenId, u64, AccountId)>::decode(&mut &event.data[..]).ok()?;
+                Some((locked, token_id, request_id, owner))
+            })
+            .collect()
+    }
+
+    #[ink::test]
+    fn bridge_lock_is_announced_and_reverted_on_recovery() {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        for validator in [accounts.alice, accounts.bob, accounts.charlie] {
+            bridge.add_validator(validator).unwrap();
+        }
+
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        let request_id = bridge
+            .initiate_bridge_multisig(7, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert!(bridge.get_locked_tokens(accounts.eve).is_empty());
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(bridge.get_locked_tokens(accounts.eve), vec![7]);
+        assert_eq!(lock_events(start), vec![(true, 7, request_id, accounts.eve)]);
+
+        // Retrying a failed request leaves it unlocked; signing again
+        // re-applies the lock.
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.sign_bridge_request(request_id, false).unwrap();
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::RetryBridge)
+            .unwrap();
+        assert!(bridge.get_locked_tokens(accounts.eve).is_empty());
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(lock_events(start), vec![(true, 7, request_id, accounts.eve)]);
+
+        // The token is handed back exactly once, however the failed
+        // request is then recovered.
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.sign_bridge_request(request_id, false).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::UnlockToken)
+            .unwrap();
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::CancelBridge)
+            .unwrap();
+        assert_eq!(lock_events(start), vec![(false, 7, request_id, accounts.eve)]);
+        assert!(bridge.get_locked_tokens(accounts.eve).is_empty());
+    }
+
+    /// Asserts that no lock on `token_id` or held for `owner` is left.
+    fn assert_unlocked(bridge: &PropertyBridge, token_id: TokenId, owner: AccountId) {
+        assert!(!bridge.token_locks.contains(token_id));
+        assert!(!bridge.locked_tokens_by_owner.contains(owner));
+        assert!(bridge.get_locked_tokens(owner).is_empty());
+    }
+
+    /// A bridge with three validators and a request for token 7 from eve
+    /// that both alice and bob have signed, so it is `Locked`.
+    fn locked_request(
+        expires_in: Option<u64>,
+    ) -> (PropertyBridge, ink::env::test::DefaultAccounts<DefaultEnvironment>, u64) {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        for validator in [accounts.alice, accounts.bob, accounts.charlie] {
+            bridge.add_validator(validator).unwrap();
+        }
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        let request_id = bridge
+            .initiate_bridge_multisig(7, 2, accounts.eve, 2, expires_in, make_metadata())
+            .unwrap();
+        for validator in [accounts.alice, accounts.bob] {
+            test::set_caller::<DefaultEnvironment>(validator);
+            bridge.sign_bridge_request(request_id, true).unwrap();
+        }
+        assert_eq!(bridge.get_locked_tokens(accounts.eve), vec![7]);
+        (bridge, accounts, request_id)
+    }
+
+    #[ink::test]
+    fn rejecting_a_locked_request_releases_its_lock() {
+        let (mut bridge, accounts, request_id) = locked_request(None);
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.sign_bridge_request(request_id, false).unwrap();
+
+        assert_unlocked(&bridge, 7, accounts.eve);
+        assert_eq!(lock_events(start), vec![(false, 7, request_id, accounts.eve)]);
+    }
+
+    #[ink::test]
+    fn expiring_a_locked_request_releases_its_lock() {
+        let (mut bridge, accounts, request_id) = locked_request(Some(1));
+        test::advance_block::<DefaultEnvironment>();
+        test::advance_block::<DefaultEnvironment>();
+        let start = test::recorded_events().count();
+        assert_eq!(bridge.expire_requests(vec![request_id]), Ok(vec![request_id]));
+
+        assert_unlocked(&bridge, 7, accounts.eve);
+        assert_eq!(lock_events(start), vec![(false, 7, request_id, accounts.eve)]);
+    }
+
+    #[ink::test]
+    fn rolling_back_a_locked_request_releases_its_lock() {
+        let (mut bridge, accounts, request_id) = locked_request(None);
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge
+            .rollback_bridge_transaction(request_id, String::from("relayer outage"))
+            .unwrap();
+
+        assert_unlocked(&bridge, 7, accounts.eve);
+        assert_eq!(lock_events(start), vec![(false, 7, request_id, accounts.eve)]);
+    }
+
+    // ── Operator liveness ───────────────────────────────────────────────────
+
+    fn liveness_bridge() -> (PropertyBridge, ink::env::test::DefaultAccounts<DefaultEnvironment>, u64) {
+        let mut bridge = setup_bridge(); // min_signatures_required = 2
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_timestamp::<DefaultEnvironment>(1_000_000);
+        for validator in [accounts.alice, accounts.bob, accounts.charlie] {
+            bridge.add_validator(validator).unwrap();
+            test::set_caller::<DefaultEnvironment>(validator);
+            bridge.operator_heartbeat().unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+        }
+        bridge.set_operator_liveness_window(60).unwrap();
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 3, None, make_metadata())
+            .unwrap();
+        (bridge, accounts, request_id)
+    }
+
+    #[ink::test]
+    fn silent_validators_leave_the_signer_pool_after_the_window() {
+        let (mut bridge, accounts, request_id) = liveness_bridge();
+
+        // Exactly at the window edge everyone still counts.
+        test::set_block_timestamp::<DefaultEnvironment>(1_060_000);
+        assert_eq!(bridge.get_effective_required_signatures(request_id), Some(3));
+        for validator in [accounts.alice, accounts.bob] {
+            test::set_caller::<DefaultEnvironment>(validator);
+            bridge.operator_heartbeat().unwrap();
+        }
+
+        // One millisecond later charlie is silent: 3-of-3 becomes 2-of-2.
+        test::set_block_timestamp::<DefaultEnvironment>(1_060_001);
+        assert_eq!(bridge.get_effective_required_signatures(request_id), Some(2));
+        let liveness = bridge.get_operator_liveness();
+        assert_eq!(liveness.len(), 3);
+        let charlie = liveness
+            .iter()
+            .find(|entry| entry.operator == accounts.charlie)
+            .unwrap();
+        assert_eq!(charlie.last_seen, Some(1_000_000));
+        assert_eq!(charlie.pending_signatures, 1);
+        assert!(!charlie.live);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        let alice = bridge.get_operator_liveness()[0].clone();
+        assert_eq!(
+            (alice.operator, alice.pending_signatures, alice.live),
+            (accounts.alice, 0, true)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Locked
+        );
+    }
+
+    #[ink::test]
+    fn pending_requests_execute_once_silent_validators_drop_out() {
+        let (mut bridge, accounts, request_id) = liveness_bridge();
+        bridge.set_operator_liveness_window(0).unwrap();
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+
+        // With the policy off the request waits for charlie.
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_timestamp::<DefaultEnvironment>(2_000_000);
+        assert_eq!(bridge.execute_bridge(request_id), Err(Error::InvalidRequest));
+
+        // Signers that already voted still count even if silent since.
+        bridge.set_operator_liveness_window(60).unwrap();
+        assert_eq!(bridge.get_effective_required_signatures(request_id), Some(2));
+        bridge.execute_bridge(request_id).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Completed
+        );
+    }
+
+    #[ink::test]
+    fn liveness_degradation_stops_at_min_signatures() {
+        let (mut bridge, _, request_id) = liveness_bridge();
+        test::set_block_timestamp::<DefaultEnvironment>(1_060_001);
+        bridge.operator_heartbeat().unwrap();
+
+        // Only alice is live, but the floor is min_signatures_required.
+        assert_eq!(bridge.get_effective_required_signatures(request_id), Some(2));
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Pending
+        );
+        assert_eq!(bridge.execute_bridge(request_id), Err(Error::InvalidRequest));
+    }
+
+    #[ink::test]
+    fn lowering_min_signatures_does_not_weaken_pending_requests() {
+        let (mut bridge, accounts, request_id) = liveness_bridge();
+        bridge.add_bridge_operator(accounts.bob).unwrap();
+        bridge.add_bridge_operator(accounts.charlie).unwrap();
+        let mut config = bridge.get_config();
+        config.min_signatures_required = 1;
+        config.max_signatures_required = 3;
+        bridge.update_config(config).unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(1_060_001);
+        bridge.operator_heartbeat().unwrap();
+
+        // The pending request keeps the floor it was created with.
+        assert_eq!(bridge.get_effective_required_signatures(request_id), Some(2));
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Pending
+        );
+
+        // Requests created afterwards use the new floor.
+        let later = bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 3, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.get_effective_required_signatures(later), Some(1));
+    }
+
+    #[ink::test]
+    fn liveness_controls_are_restricted() {
+        let (mut bridge, accounts, _) = liveness_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(bridge.operator_heartbeat(), Err(Error::Unauthorized));
+        assert_eq!(
+            bridge.set_operator_liveness_window(10),
+            Err(Error::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            bridge.set_operator_liveness_window(10),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(bridge.get_operator_liveness_window(), 60);
+    }
+
+    // ── Batch requests ──────────────────────────────────────────────────────
+
+    fn batch_bridge() -> (PropertyBridge, ink::env::test::DefaultAccounts<DefaultEnvironment>) {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        for validator in [accounts.alice, accounts.bob, accounts.charlie] {
+            bridge.add_validator(validator).unwrap();
+        }
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        (bridge, accounts)
+    }
+
+    #[ink::test]
+    fn invalid_batches_are_rejected_without_side_effects() {
+        let (mut bridge, accounts) = batch_bridge();
+        bridge
+            .initiate_bridge_multisig(3, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(
+            bridge.initiate_bridge_batch(Vec::new(), 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::BatchTooLarge)
+        );
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(
+            bridge.initiate_bridge_batch(
+                (1..=51).collect(),
+                2,
+                accounts.eve,
+                2,
+                None,
+                make_metadata()
+            ),
+            Err(Error::BatchTooLarge)
+        );
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(
+            bridge.initiate_bridge_batch(vec![1, 2, 1], 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::DuplicateRequest)
+        );
+        // Token 3 is already in flight, so the whole batch is refused.
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(
+            bridge.initiate_bridge_batch(vec![1, 2, 3], 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::DuplicateRequest)
+        );
+        assert_eq!(bridge.get_open_request_count(accounts.eve), 1);
+
+        // Nothing from the rejected batches was reserved.
+        test::advance_block::<DefaultEnvironment>();
+        let request_id = bridge
+            .initiate_bridge_batch(vec![1, 2], 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(bridge.get_request_tokens(request_id), vec![1, 2]);
+        assert_eq!(bridge.get_request_tokens(1), vec![3]);
+    }
+
+    #[ink::test]
+    fn batch_executes_as_one_request_with_per_token_events() {
+        let (mut bridge, accounts) = batch_bridge();
+        let request_id = bridge
+            .initiate_bridge_batch(vec![4, 5, 6], 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(bridge.get_locked_tokens(accounts.eve), vec![4, 5, 6]);
+
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+        let executed = <BridgeExecuted as ink::env::Event>::SIGNATURE_TOPIC.unwrap();
+        let tokens: Vec<TokenId> = test::recorded_events()
+            .skip(start)
+            .filter(|event| event.topics.first().map(|t| t.as_slice()) == Some(&executed[..]))
+            .map(|event| {
+                <(u64, TokenId, Hash)>::decode(&mut &event.data[..])
+                    .unwrap()
+                    .1
+            })
+            .collect();
+        assert_eq!(tokens, vec![4, 5, 6]);
+        assert_eq!(
+            bridge.monitor_bridge_status(request_id).unwrap().status,
+            BridgeOperationStatus::Completed
+        );
+
+        // Every token is free for a new request once the batch completes.
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        bridge
+            .initiate_bridge_multisig(5, 2, accounts.eve, 2, None, make_metadata())
+            .expect("token released");
+    }
+
+    #[ink::test]
+    fn lifecycle_events_decode_with_the_shared_decoder() {
+        let (mut bridge, accounts) = batch_bridge();
+        let start = test::recorded_events().count();
+        let request_id = bridge
+            .initiate_bridge_batch(vec![4, 5], 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+
+        let decoded: Vec<BridgeLifecycleEvent> = test::recorded_events()
+            .skip(start)
+            .filter_map(|event| BridgeLifecycleEvent::decode(&event.topics[0], &event.data))
+            .collect();
+        let summary: Vec<(&str, TokenId)> = decoded
+            .iter()
+            .map(|event| match event {
+                BridgeLifecycleEvent::Created(e) => ("created", e.token_id),
+                BridgeLifecycleEvent::Signed(e) => ("signed", e.signatures_collected as TokenId),
+                BridgeLifecycleEvent::Executed(e) => ("executed", e.token_id),
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("created", 4),
+                ("created", 5),
+                ("signed", 1),
+                ("signed", 2),
+                ("executed", 4),
+                ("executed", 5),
+            ]
+        );
+        assert!(decoded
+            .iter()
+            .all(|event| event.contract_kind() == BRIDGE_CONTRACT_KIND_BRIDGE));
+        if let BridgeLifecycleEvent::Executed(executed) = &decoded[4] {
+            assert_eq!(executed.request_id, request_id);
+            assert_eq!(executed.event_version, BRIDGE_EVENT_VERSION);
+        }
+    }
+
+    #[ink::test]
+    fn lifecycle_events_carry_the_pinned_signature_topics() {
+        let (mut bridge, accounts) = batch_bridge();
+        let start = test::recorded_events().count();
+        let request_id = bridge
+            .initiate_bridge_batch(vec![4], 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+
+        // Indexers match these topics; they changed when the events moved
+        // into propchain_traits and gained contract_kind and event_version.
+        let created = "4f62f3d7286e3c053d9a2ff8a0e22d20a55840fa5c1f7cd86811f4da5ea2e1b9";
+        let signed = "7c4cee7782ed24634b9cb0b06143c5f38c13c798e0ac93fc6685273080c0faae";
+        let executed = "589d77d176310d3848dab5a5659c68a843bcfaaac2dccb3ec372635907d3c14b";
+        let topics: Vec<String> = test::recorded_events()
+            .skip(start)
+            .filter(|event| BridgeLifecycleEvent::decode(&event.topics[0], &event.data).is_some())
+            .map(|event| event.topics[0].iter().map(|byte| format!("{byte:02x}")).collect())
+            .collect();
+        assert_eq!(topics, vec![created, signed, signed, executed]);
+    }
+
+    #[ink::test]
+    fn batch_rejection_unlocks_every_token() {
+        let (mut bridge, accounts) = batch_bridge();
+        let request_id = bridge
+            .initiate_bridge_batch(vec![7, 8], 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        assert_eq!(bridge.get_locked_tokens(accounts.eve), vec![7, 8]);
+
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        bridge.sign_bridge_request(request_id, false).unwrap();
+        assert_eq!(
+            lock_events(start),
+            vec![
+                (false, 7, request_id, accounts.eve),
+                (false, 8, request_id, accounts.eve),
+            ]
+        );
+        assert!(bridge.get_locked_tokens(accounts.eve).is_empty());
+
+        // The failed request no longer holds either token.
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(8, 2, accounts.eve, 2, None, make_metadata()),
+            Ok(2)
+        );
+        let start = test::recorded_events().count();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge
+            .recover_failed_bridge(request_id, RecoveryAction::UnlockToken)
+            .unwrap();
+        assert!(lock_events(start).is_empty());
+
+        // Token 8 was re-bridged meanwhile, so the batch cannot be retried.
+        assert_eq!(
+            bridge.recover_failed_bridge(request_id, RecoveryAction::RetryBridge),
+            Err(Error::DuplicateRequest)
+        );
+    }
+
+    // ── Gas limits ──────────────────────────────────────────────────────────
+
+    #[ink::test]
+    fn requests_are_rejected_above_the_gas_limit() {
+        let (mut bridge, accounts) = batch_bridge();
+        let (estimate, limit) = bridge.quote_request_gas(2, 2, make_metadata()).unwrap();
+        assert_eq!(limit, bridge.get_config().gas_limit_per_bridge);
+        assert!(estimate > bridge.estimate_bridge_gas(1, 2).unwrap());
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_gas_limit(2, Some(estimate - 1)).unwrap();
+        assert_eq!(bridge.quote_request_gas(2, 2, make_metadata()), Ok((estimate, estimate - 1)));
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        assert_eq!(
+            bridge.initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::GasLimitExceeded)
+        );
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(
+            bridge.initiate_bridge_batch(vec![1, 2], 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::GasLimitExceeded)
+        );
+        // Other chains keep the global limit.
+        test::advance_block::<DefaultEnvironment>();
+        bridge
+            .initiate_bridge_multisig(1, 3, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+
+        // A request estimated exactly at the limit is accepted.
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_gas_limit(2, Some(estimate)).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        test::advance_block::<DefaultEnvironment>();
+        bridge
+            .initiate_bridge_multisig(2, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+    }
+
+    #[ink::test]
+    fn execution_rechecks_the_gas_limit_and_records_the_estimate() {
+        let (mut bridge, accounts) = batch_bridge();
+        let (estimate, _) = bridge.quote_request_gas(2, 2, make_metadata()).unwrap();
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        bridge.sign_bridge_request(request_id, true).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_gas_limit(2, Some(estimate - 1)).unwrap();
+        assert_eq!(bridge.execute_bridge(request_id), Err(Error::GasLimitExceeded));
+
+        bridge.set_gas_limit(2, None).unwrap();
+        bridge.execute_bridge(request_id).unwrap();
+        let transaction = bridge.get_transaction(1).unwrap();
+        assert_eq!(transaction.gas_used, estimate);
+    }
+
+    #[ink::test]
+    fn set_gas_limit_is_admin_only_and_needs_a_known_chain() {
+        let (mut bridge, accounts) = batch_bridge();
+        assert_eq!(bridge.set_gas_limit(2, Some(1)), Err(Error::Unauthorized));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(bridge.set_gas_limit(9, Some(1)), Err(Error::InvalidChain));
+        bridge.set_gas_limit(2, Some(1)).unwrap();
+        assert_eq!(bridge.get_chain_info(2).unwrap().gas_limit, Some(1));
+        assert_eq!(bridge.estimate_bridge_gas(1, 2), Ok(1));
+    }
+
+    // ── Simulation ──────────────────────────────────────────────────────────
+
+    #[ink::test]
+    fn simulation_matches_the_request_that_is_created_and_executed() {
+        let (mut bridge, accounts) = batch_bridge();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.set_chain_fee(2, 1_000).unwrap();
+        bridge.set_gas_deposit(50).unwrap();
+        bridge
+            .set_destination_contract(2, vec![0xABu8; 20])
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        let simulated = bridge.simulate_bridge(1, 2, accounts.frank, 2, make_metadata());
+        let (estimate, _) = bridge.quote_request_gas(2, 2, make_metadata()).unwrap();
+        assert_eq!(simulated.error, None);
+        assert_eq!(simulated.fee, 1_050);
+        assert_eq!(simulated.gas_estimate, estimate);
+        assert!(simulated.authorized && simulated.compliant);
+        assert!(!simulated.duplicate && !simulated.travel_rule_data_required);
+        // Simulating changes nothing.
+        assert_eq!(bridge.get_active_request(1), None);
+        assert_eq!(bridge.simulate_bridge(1, 2, accounts.frank, 2, make_metadata()), simulated);
+
+        test::set_value_transferred::<DefaultEnvironment>(simulated.fee);
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.frank, 2, None, make_metadata())
+            .unwrap();
+        assert_eq!(request_id, simulated.request_id);
+        assert_eq!(bridge.encode_request(request_id), Some(simulated.canonical_request));
+        assert_eq!(bridge.request_digest(request_id), Some(simulated.digest));
+
+        for validator in [accounts.alice, accounts.bob] {
+            test::set_caller::<DefaultEnvironment>(validator);
+            bridge.sign_bridge_request(request_id, true).unwrap();
+        }
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.execute_bridge(request_id).unwrap();
+        assert_eq!(bridge.get_outbound_payload(request_id), Some(simulated.outbound_payload));
+        assert_eq!(bridge.get_transaction(1).unwrap().gas_used, simulated.gas_estimate);
+    }
+
+    #[ink::test]
+    fn simulation_applies_the_daily_request_limit() {
+        let (mut bridge, accounts) = batch_bridge();
+        bridge.config.max_requests_per_day = 1;
+        assert_eq!(
+            bridge
+                .simulate_bridge(1, 2, accounts.frank, 2, make_metadata())
+                .error,
+            None
+        );
+        bridge
+            .initiate_bridge_multisig(1, 2, accounts.frank, 2, None, make_metadata())
+            .unwrap();
+
+        test::advance_block::<DefaultEnvironment>();
+        let limited = bridge.simulate_bridge(3, 2, accounts.frank, 2, make_metadata());
+        assert_eq!(limited.error, Some(Error::RateLimitExceeded));
+        assert_eq!(
+            bridge.initiate_bridge_multisig(3, 2, accounts.frank, 2, None, make_metadata()),
+            Err(Error::RateLimitExceeded)
+        );
+    }
+
+    #[ink::test]
+    fn simulation_reports_the_error_initiation_would_return() {
+        let (mut bridge, accounts) = limited_bridge(RequestLimits {
+            max_open_per_sender: 1,
+            ..Default::default()
+        });
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        bridge
+            .initiate_bridge_multisig(1, 2, accounts.frank, 2, None, make_metadata())
+            .unwrap();
+
+        let duplicate = bridge.simulate_bridge(1, 2, accounts.frank, 2, make_metadata());
+        assert!(duplicate.duplicate);
+        assert_eq!(duplicate.error, Some(Error::DuplicateRequest));
+        assert_eq!(
+            bridge
+                .simulate_bridge(2, 2, accounts.frank, 2, make_metadata())
+                .error,
+            Some(Error::TooManyOpenRequests)
+        );
+        assert_eq!(
+            bridge
+                .simulate_bridge(2, 2, accounts.frank, 9, make_metadata())
+                .error,
+            Some(Error::InsufficientSignatures)
+        );
+
+        let unsupported = bridge.simulate_bridge(2, 9, accounts.frank, 2, make_metadata());
+        assert_eq!(unsupported.error, Some(Error::InvalidChain));
+        assert_eq!(unsupported.gas_estimate, 0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_supported_token(2, 5).unwrap();
+        let not_listed = bridge.simulate_bridge(2, 2, accounts.frank, 2, make_metadata());
+        assert!(!not_listed.compliant);
+        assert_eq!(not_listed.error, Some(Error::BridgeNotSupported));
+        bridge.add_supported_token(2, 2).unwrap();
+        bridge.set_travel_rule_threshold(2, 1_000).unwrap();
+        assert!(
+            bridge
+                .simulate_bridge(2, 2, accounts.frank, 2, make_metadata())
+                .travel_rule_data_required
+        );
+        bridge.set_emergency_pause(true).unwrap();
+        assert_eq!(
+            bridge
+                .simulate_bridge(2, 2, accounts.frank, 2, make_metadata())
+                .error,
+            Some(Error::OperationPaused)
+        );
+    }
+
+    // ── Config validation ───────────────────────────────────────────────────
+
+    /// Bridge with three operators (alice, bob, charlie) and a config that
+    /// passes validation as-is.
+    fn config_bridge() -> (PropertyBridge, BridgeConfig) {
+        let mut bridge = setup_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        bridge.add_bridge_operator(accounts.bob).unwrap();
+        bridge.add_bridge_operator(accounts.charlie).unwrap();
+        let mut config = bridge.get_config();
+        config.max_signatures_required = 3;
+        (bridge, config)
+    }
+
+    #[ink::test]
+    fn update_config_rejects_each_invalid_field() {
+        let (mut bridge, valid) = config_bridge();
+        let invalid: [fn(&mut BridgeConfig); 5] = [
+            |c| c.min_signatures_required = 0,
+            |c| c.min_signatures_required = 4,
+            |c| c.max_signatures_required = 4,
+            |c| c.supported_chains.clear(),
+            |c| c.gas_limit_per_bridge = 0,
+        ];
+        for mutate in invalid {
+            let mut config = valid.clone();
+            mutate(&mut config);
+            assert_eq!(bridge.update_config(config), Err(Error::InvalidConfig));
+        }
+        assert_eq!(bridge.get_config().max_signatures_required, 5);
+
+        bridge.update_config(valid.clone()).unwrap();
+        assert_eq!(bridge.get_config(), valid);
+    }
+
+    #[ink::test]
+    fn raising_min_signatures_does_not_reprice_pending_requests() {
+        let (mut bridge, mut config) = config_bridge();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_block_timestamp::<DefaultEnvironment>(1_000_000);
+        for validator in [accounts.alice, accounts.bob, accounts.charlie] {
+            bridge.add_validator(validator).unwrap();
+        }
+        bridge.set_operator_liveness_window(60).unwrap();
+        let request_id = bridge
+            .initiate_bridge_multisig(1, 2, accounts.eve, 2, None, make_metadata())
+            .unwrap();
+
+        config.min_signatures_required = 3;
+        bridge.update_config(config).unwrap();
+        // Every validator has gone silent, so only the floor applies.
+        test::set_block_timestamp::<DefaultEnvironment>(2_000_000);
+        assert_eq!(bridge.get_effective_required_signatures(request_id), Some(2));
+        assert_eq!(
+            bridge.initiate_bridge_multisig(2, 2, accounts.eve, 2, None, make_metadata()),
+            Err(Error::InsufficientSignatures)
+        );
+    }
 }
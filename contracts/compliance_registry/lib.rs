@@ -10,7 +10,7 @@ use propchain_traits::ComplianceChecker;
 use propchain_traits::*;
 
 #[ink::contract]
-mod compliance_registry {
+pub mod compliance_registry {
     use super::*;
     use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
@@ -1089,6 +1089,23 @@ mod compliance_registry {
             }
         }
 
+        /// Returns a reference to the verification record backing
+        /// `account`'s compliance: the hash of the account, its KYC hash and
+        /// the verification time. `None` if the account is not compliant.
+        #[ink(message)]
+        pub fn attest_compliance(&self, account: AccountId) -> Option<Hash> {
+            if !self.is_compliant(account) {
+                return None;
+            }
+            let data = self.compliance_data.get(account)?;
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(account, data.kyc_hash, data.verification_timestamp),
+                &mut output,
+            );
+            Some(Hash::from(output))
+        }
+
         /// Require compliance (use this in property transfer functions)
         #[ink(message)]
         pub fn require_compliance(&self, account: AccountId) -> Result<()> {
@@ -2204,6 +2221,11 @@ mod compliance_registry {
         fn is_compliant(&self, account: AccountId) -> bool {
             ComplianceRegistry::is_compliant(self, account)
         }
+
+        #[ink(message)]
+        fn attest_compliance(&self, account: AccountId) -> Option<Hash> {
+            ComplianceRegistry::attest_compliance(self, account)
+        }
     }
 
     #[cfg(test)]
@@ -2261,6 +2283,11 @@ mod compliance_registry {
 
             // Require compliance should pass
             assert!(contract.require_compliance(user).is_ok());
+
+            // The attestation reference is stable for the same record
+            let reference = contract.attest_compliance(user);
+            assert!(reference.is_some());
+            assert_eq!(contract.attest_compliance(user), reference);
         }
 
         #[ink::test]
@@ -2270,6 +2297,7 @@ mod compliance_registry {
 
             assert!(!contract.is_compliant(user));
             assert_eq!(contract.require_compliance(user), Err(Error::NotVerified));
+            assert_eq!(contract.attest_compliance(user), None);
         }
 
         #[ink::test]
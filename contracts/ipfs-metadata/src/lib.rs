@@ -4,6 +4,7 @@
 use ink::prelude::string::String;
 use ink::prelude::vec::Vec;
 use ink::storage::Mapping;
+use propchain_traits::PropertyOwnership;
 
 #[ink::contract]
 #[allow(clippy::too_many_arguments)]
@@ -17,6 +18,9 @@ mod ipfs_metadata {
     /// IPFS Content Identifier (CID) - stored as String for flexibility
     pub type IpfsCid = String;
 
+    /// Document encryption key wrapped for a single recipient
+    pub type KeyEnvelope = Vec<u8>;
+
     /// Error types for IPFS metadata validation
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -53,6 +57,32 @@ mod ipfs_metadata {
         DocumentAlreadyExists,
         /// Document already notarized with this hash
         AlreadyNotarized,
+        /// Batch exceeds the maximum number of entries
+        BatchTooLarge,
+        /// Property registry contract has not been configured
+        RegistryNotConfigured,
+        /// Cross-contract call to the property registry failed
+        RegistryCallFailed,
+        /// Access expiry is not in the future
+        InvalidExpiry,
+        /// Document or reporter has reached its flag cap
+        FlagLimitExceeded,
+        /// Reporter flagged another document too recently
+        FlagCooldownActive,
+        /// Reporter has already flagged this document
+        AlreadyFlagged,
+        /// Document has no open flags to resolve
+        NoOpenFlags,
+        /// Flag threshold must be at least one
+        InvalidThreshold,
+        /// Encrypted documents must be retrieved through `log_and_get_key_envelope`
+        AccessLogRequired,
+        /// No key envelope was stored for the caller on this document
+        KeyEnvelopeNotFound,
+        /// Property is archived and its documents are read-only
+        PropertyArchived,
+        /// Property is not archived
+        PropertyNotArchived,
     }
 
     /// Enhanced property metadata with IPFS integration
@@ -78,12 +108,39 @@ mod ipfs_metadata {
         pub legal_docs_ipfs_cid: Option<IpfsCid>,
         /// Timestamp of metadata creation
         pub created_at: u64,
-        /// Hash of all metadata content for verification
+        /// Blake2b-256 of the canonical encoding of every other field (see
+        /// [`PropertyMetadata::compute_content_hash`]). Computed on
+        /// registration; a caller may leave it zeroed.
         pub content_hash: Hash,
         /// Whether sensitive data is encrypted
         pub is_encrypted: bool,
     }
 
+    /// Version of the canonical encoding behind `content_hash`. Bump it, and
+    /// keep the old layout decodable, whenever a field is added.
+    pub const METADATA_HASH_VERSION: u8 = 1;
+
+    impl PropertyMetadata {
+        /// Hash of the canonical encoding: a domain tag and
+        /// [`METADATA_HASH_VERSION`] followed by the SCALE encoding of every
+        /// field except `content_hash`, in declaration order.
+        pub fn compute_content_hash(&self) -> Hash {
+            propchain_traits::crypto::hash_encoded(&(
+                b"propchain:ipfs:metadata",
+                METADATA_HASH_VERSION,
+                &self.location,
+                self.size,
+                &self.legal_description,
+                self.valuation,
+                &self.documents_ipfs_cid,
+                &self.images_ipfs_cid,
+                &self.legal_docs_ipfs_cid,
+                self.created_at,
+                self.is_encrypted,
+            ))
+        }
+    }
+
     /// Document information stored on IPFS
     #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(
@@ -115,6 +172,8 @@ mod ipfs_metadata {
         pub uploaded_at: u64,
         /// Last verification timestamp
         pub last_verified_at: u64,
+        /// Whether the document belongs to an archived property
+        pub is_archived: bool,
     }
 
     /// On-chain notarization record proving a document existed at a point in time
@@ -163,6 +222,109 @@ mod ipfs_metadata {
         Other,
     }
 
+    impl DocumentType {
+        /// Document types that count towards a property's completeness score
+        pub const SCORED: [DocumentType; 10] = [
+            DocumentType::Deed,
+            DocumentType::Title,
+            DocumentType::Inspection,
+            DocumentType::Appraisal,
+            DocumentType::Survey,
+            DocumentType::TaxRecords,
+            DocumentType::Insurance,
+            DocumentType::Images,
+            DocumentType::FloorPlans,
+            DocumentType::Legal,
+        ];
+    }
+
+    /// Maximum number of grants accepted by `grant_access_batch`
+    pub const MAX_ACCESS_GRANT_BATCH: u32 = 50;
+    /// Maximum number of properties covered by one `grant_portfolio_access`
+    pub const MAX_PORTFOLIO_GRANTS: u32 = 100;
+
+    /// Open flags at which a document is unpinned pending admin review
+    pub const DEFAULT_FLAG_THRESHOLD: u32 = 3;
+    /// Maximum open flags kept per document
+    pub const MAX_FLAGS_PER_DOCUMENT: u32 = 10;
+    /// Maximum open flags a single reporter may have across documents
+    pub const MAX_OPEN_FLAGS_PER_REPORTER: u32 = 20;
+    /// Minimum time between two flags from the same reporter (1 hour)
+    pub const FLAG_COOLDOWN_MS: u64 = 3_600_000;
+    /// Maximum length of a flag reason
+    pub const MAX_FLAG_REASON_LENGTH: u32 = 256;
+
+    /// Access log entries kept per document; the oldest are overwritten
+    pub const MAX_ACCESS_LOG_ENTRIES: u32 = 100;
+    /// Maximum entries returned by one `get_access_log` call
+    pub const MAX_ACCESS_LOG_PAGE: u32 = 50;
+    /// Maximum size of a wrapped document key
+    pub const MAX_KEY_ENVELOPE_LENGTH: u32 = 1_024;
+
+    /// Maximum entries returned by one `get_archived_properties` call
+    pub const MAX_ARCHIVED_PROPERTIES_PAGE: u32 = 100;
+
+    /// Points awarded for each scored document type present
+    pub const COMPLETENESS_POINTS_PER_TYPE: u32 = 4;
+    /// Points shared across the admin-configured required document types
+    pub const COMPLETENESS_REQUIRED_POINTS: u32 = 45;
+    /// Points awarded for each metadata CID that is set
+    pub const COMPLETENESS_POINTS_PER_CID: u32 = 5;
+
+    /// Report raised against a document by a user with read access
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct DocumentFlag {
+        pub reporter: AccountId,
+        pub reason: String,
+        pub flagged_at: u64,
+    }
+
+    /// Record of an account retrieving a document
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct AccessLogEntry {
+        pub account: AccountId,
+        pub accessed_at: u64,
+    }
+
+    /// Metadata CID fields considered by completeness scoring
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum MetadataCidField {
+        Documents,
+        Images,
+        LegalDocs,
+    }
+
+    /// Item missing from a property's document set
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum MissingItem {
+        /// No document of a required type has been registered
+        RequiredDocument(DocumentType),
+        /// A required document exists but none of its versions is notarized
+        Attestation(DocumentType),
+        /// Metadata is unregistered or the CID field is unset
+        MetadataCid(MetadataCidField),
+    }
+
+    /// Completeness breakdown for a property (score out of 100)
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CompletenessReport {
+        pub property_id: u64,
+        pub score: u32,
+        pub present_types: Vec<DocumentType>,
+        pub missing: Vec<MissingItem>,
+    }
+
     /// Metadata validation rules
     #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(
@@ -281,6 +443,58 @@ mod ipfs_metadata {
         timestamp: u64,
     }
 
+    /// Event emitted when a user flags a document as malicious
+    #[ink(event)]
+    pub struct DocumentFlagged {
+        #[ink(topic)]
+        document_id: u64,
+        #[ink(topic)]
+        reporter: AccountId,
+        reason: String,
+        open_flags: u32,
+        timestamp: u64,
+    }
+
+    /// Event emitted when an account logs its retrieval of a document
+    #[ink(event)]
+    pub struct DocumentAccessed {
+        #[ink(topic)]
+        document_id: u64,
+        #[ink(topic)]
+        account: AccountId,
+        timestamp: u64,
+    }
+
+    /// Event emitted when the admin resolves a document's open flags
+    #[ink(event)]
+    pub struct DocumentFlagResolved {
+        #[ink(topic)]
+        document_id: u64,
+        upheld: bool,
+        flags_cleared: u32,
+        timestamp: u64,
+    }
+
+    /// Event emitted when a property's documents are archived
+    #[ink(event)]
+    pub struct PropertyArchived {
+        #[ink(topic)]
+        property_id: u64,
+        archived_by: AccountId,
+        documents_unpinned: u32,
+        released_size: u64,
+        timestamp: u64,
+    }
+
+    /// Event emitted when an archived property becomes writable again
+    #[ink(event)]
+    pub struct PropertyUnarchived {
+        #[ink(topic)]
+        property_id: u64,
+        unarchived_by: AccountId,
+        timestamp: u64,
+    }
+
     // ============================================================================
     // CONTRACT STORAGE
     // ============================================================================
@@ -309,6 +523,39 @@ mod ipfs_metadata {
         notarizations: Mapping<(u64, Hash), NotarizationRecord>,
         /// Mapping from property_id to list of notarized document hashes
         property_notarization_hashes: Mapping<u64, Vec<Hash>>,
+        /// Document types every property is expected to carry, notarized
+        required_document_types: Vec<DocumentType>,
+        /// Property registry used to resolve owner portfolios
+        registry_contract: Option<AccountId>,
+        /// Mapping from (property_id, account) to access expiry timestamp
+        access_expirations: Mapping<(u64, AccountId), u64>,
+        /// Mapping from document ID to open user flags
+        document_flags: Mapping<u64, Vec<DocumentFlag>>,
+        /// Open flags at which a document is unpinned pending review
+        flag_threshold: u32,
+        /// Mapping from reporter to their number of open flags
+        reporter_open_flags: Mapping<AccountId, u32>,
+        /// Mapping from reporter to the timestamp of their latest flag
+        reporter_last_flag: Mapping<AccountId, u64>,
+        /// Documents unpinned by the flag threshold, re-pinned if cleared
+        flag_unpinned: Mapping<u64, ()>,
+        /// Mapping from (document ID, slot) to access log entry
+        access_logs: Mapping<(u64, u32), AccessLogEntry>,
+        /// Mapping from document ID to the number of accesses ever logged
+        access_log_counts: Mapping<u64, u64>,
+        /// Whether encrypted documents' key envelopes require a logged access
+        require_access_log: bool,
+        /// Mapping from (document ID, account) to the account's wrapped key
+        key_envelopes: Mapping<(u64, AccountId), KeyEnvelope>,
+        /// Mapping from archived property ID to its archival timestamp
+        archived_properties: Mapping<u64, u64>,
+        /// Mapping from slot to archived property ID; slots are dense, and
+        /// unarchiving moves the last entry into the freed slot
+        archived_property_index: Mapping<u32, u64>,
+        /// Mapping from archived property ID to its slot in the index
+        archived_property_slots: Mapping<u64, u32>,
+        /// Number of archived properties
+        archived_property_count: u32,
     }
 
     /// Access level for property documents
@@ -356,6 +603,22 @@ mod ipfs_metadata {
                 access_permissions: Mapping::default(),
                 notarizations: Mapping::default(),
                 property_notarization_hashes: Mapping::default(),
+                required_document_types: Vec::new(),
+                registry_contract: None,
+                access_expirations: Mapping::default(),
+                document_flags: Mapping::default(),
+                flag_threshold: DEFAULT_FLAG_THRESHOLD,
+                reporter_open_flags: Mapping::default(),
+                reporter_last_flag: Mapping::default(),
+                flag_unpinned: Mapping::default(),
+                access_logs: Mapping::default(),
+                access_log_counts: Mapping::default(),
+                require_access_log: false,
+                key_envelopes: Mapping::default(),
+                archived_properties: Mapping::default(),
+                archived_property_index: Mapping::default(),
+                archived_property_slots: Mapping::default(),
+                archived_property_count: 0,
             }
         }
 
@@ -376,6 +639,22 @@ mod ipfs_metadata {
                 access_permissions: Mapping::default(),
                 notarizations: Mapping::default(),
                 property_notarization_hashes: Mapping::default(),
+                required_document_types: Vec::new(),
+                registry_contract: None,
+                access_expirations: Mapping::default(),
+                document_flags: Mapping::default(),
+                flag_threshold: DEFAULT_FLAG_THRESHOLD,
+                reporter_open_flags: Mapping::default(),
+                reporter_last_flag: Mapping::default(),
+                flag_unpinned: Mapping::default(),
+                access_logs: Mapping::default(),
+                access_log_counts: Mapping::default(),
+                require_access_log: false,
+                key_envelopes: Mapping::default(),
+                archived_properties: Mapping::default(),
+                archived_property_index: Mapping::default(),
+                archived_property_slots: Mapping::default(),
+                archived_property_count: 0,
             }
         }
 
@@ -384,17 +663,32 @@ mod ipfs_metadata {
         // ============================================================================
 
         /// Validates and registers property metadata
+        ///
+        /// `content_hash` is computed here and stored; a non-zero hash
+        /// supplied by the caller must match it, otherwise the call fails
+        /// with `ContentHashMismatch`.
         #[ink(message)]
         pub fn validate_and_register_metadata(
             &mut self,
             property_id: u64,
-            metadata: PropertyMetadata,
+            mut metadata: PropertyMetadata,
         ) -> Result<(), Error> {
             let caller = self.env().caller();
 
+            if self.archived_properties.contains(property_id) {
+                return Err(Error::PropertyArchived);
+            }
+
             // Validate metadata structure
             self.validate_metadata(metadata.clone())?;
 
+            let content_hash = metadata.compute_content_hash();
+            if metadata.content_hash != Hash::from([0; 32]) && metadata.content_hash != content_hash
+            {
+                return Err(Error::ContentHashMismatch);
+            }
+            metadata.content_hash = content_hash;
+
             // Store metadata
             self.property_metadata.insert(property_id, &metadata);
 
@@ -465,29 +759,11 @@ mod ipfs_metadata {
         /// Validates IPFS CID format
         #[ink(message)]
         pub fn validate_ipfs_cid(&self, cid: String) -> Result<(), Error> {
-            // Basic CID validation
-            // CIDv0: starts with "Qm" and is 46 characters
-            // CIDv1: starts with "b" and uses base32
-            if cid.is_empty() {
-                return Err(Error::InvalidIpfsCid);
-            }
-
-            if cid.starts_with("Qm") {
-                // CIDv0: must be exactly 46 characters
-                if cid.len() == 46 {
-                    Ok(())
-                } else {
-                    Err(Error::InvalidIpfsCid)
-                }
-            } else if cid.starts_with('b') {
-                // CIDv1: minimum length check
-                if cid.len() >= 10 {
-                    Ok(())
-                } else {
-                    Err(Error::InvalidIpfsCid)
-                }
+            // CIDv0 ("Qm" + 46 chars) or CIDv1 ("b" + base32); shared with the
+            // property registry's badge metadata checks.
+            if propchain_traits::validation::is_valid_ipfs_cid(&cid) {
+                Ok(())
             } else {
-                // Neither CIDv0 nor CIDv1 format
                 Err(Error::InvalidIpfsCid)
             }
         }
@@ -562,6 +838,7 @@ mod ipfs_metadata {
                 uploader: caller,
                 uploaded_at: timestamp,
                 last_verified_at: timestamp,
+                is_archived: false,
             };
 
             // Store document
@@ -592,7 +869,7 @@ mod ipfs_metadata {
         pub fn pin_document(&mut self, document_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
 
-            let mut document = self
+            let document = self
                 .documents
                 .get(document_id)
                 .ok_or(Error::DocumentNotFound)?;
@@ -605,6 +882,17 @@ mod ipfs_metadata {
                 return Ok(());
             }
 
+            self.apply_pin(document)
+        }
+
+        /// Pins a document, enforcing the property's pinned size limit
+        fn apply_pin(&mut self, mut document: IpfsDocument) -> Result<(), Error> {
+            let document_id = document.document_id;
+
+            if document.is_archived {
+                return Err(Error::PropertyArchived);
+            }
+
             // Check pin size limits
             let current_pinned_size = self
                 .property_pinned_size
@@ -642,7 +930,7 @@ mod ipfs_metadata {
         pub fn unpin_document(&mut self, document_id: u64) -> Result<(), Error> {
             let caller = self.env().caller();
 
-            let mut document = self
+            let document = self
                 .documents
                 .get(document_id)
                 .ok_or(Error::DocumentNotFound)?;
@@ -655,6 +943,15 @@ mod ipfs_metadata {
                 return Ok(());
             }
 
+            self.apply_unpin(document);
+
+            Ok(())
+        }
+
+        /// Unpins a document and releases its share of the pinned size
+        fn apply_unpin(&mut self, mut document: IpfsDocument) {
+            let document_id = document.document_id;
+
             // Update document pin status
             document.is_pinned = false;
             self.documents.insert(document_id, &document);
@@ -678,8 +975,6 @@ mod ipfs_metadata {
                 ipfs_cid: document.ipfs_cid,
                 timestamp: self.env().block_timestamp(),
             });
-
-            Ok(())
         }
 
         /// Verifies content hash of a document
@@ -721,181 +1016,835 @@ mod ipfs_metadata {
             Ok(is_valid)
         }
 
-        // ============================================================================
-        // ACCESS CONTROL
-        // ============================================================================
-
-        /// Grants access to property documents
+        /// Flags a document as malicious. Open to any account with read
+        /// access; reaching the flag threshold unpins the document until the
+        /// admin resolves the flags.
         #[ink(message)]
-        pub fn grant_access(
-            &mut self,
-            property_id: u64,
-            account: AccountId,
-            access_level: AccessLevel,
-        ) -> Result<(), Error> {
+        pub fn flag_document(&mut self, document_id: u64, reason: String) -> Result<(), Error> {
             let caller = self.env().caller();
+            let timestamp = self.env().block_timestamp();
 
-            // Only admin or property owner can grant access
-            if caller != self.admin {
-                self.check_admin_access(property_id, caller)?;
-            }
-
-            self.access_permissions
-                .insert((property_id, account), &access_level);
-
-            Ok(())
-        }
+            let document = self
+                .documents
+                .get(document_id)
+                .ok_or(Error::DocumentNotFound)?;
 
-        /// Revokes access to property documents
-        #[ink(message)]
-        pub fn revoke_access(&mut self, property_id: u64, account: AccountId) -> Result<(), Error> {
-            let caller = self.env().caller();
+            // Check access permissions
+            self.check_read_access(document.property_id, caller)?;
 
-            // Only admin or property owner can revoke access
-            if caller != self.admin {
-                self.check_admin_access(property_id, caller)?;
+            if reason.is_empty() {
+                return Err(Error::RequiredFieldMissing);
             }
-
-            self.access_permissions.remove((property_id, account));
-
-            Ok(())
-        }
-
-        /// Checks if account has read access
-        fn check_read_access(&self, property_id: u64, account: AccountId) -> Result<(), Error> {
-            if account == self.admin {
-                return Ok(());
+            if reason.len() as u32 > MAX_FLAG_REASON_LENGTH {
+                return Err(Error::SizeLimitExceeded);
             }
 
-            let access_level = self
-                .access_permissions
-                .get((property_id, account))
-                .unwrap_or(AccessLevel::None);
-
-            match access_level {
-                AccessLevel::None => Err(Error::Unauthorized),
-                _ => Ok(()),
+            if let Some(last) = self.reporter_last_flag.get(caller) {
+                if timestamp < last.saturating_add(FLAG_COOLDOWN_MS) {
+                    return Err(Error::FlagCooldownActive);
+                }
             }
-        }
 
-        /// Checks if account has write access
-        fn check_write_access(&self, property_id: u64, account: AccountId) -> Result<(), Error> {
-            if account == self.admin {
-                return Ok(());
+            let mut flags = self.document_flags.get(document_id).unwrap_or_default();
+            if flags.iter().any(|flag| flag.reporter == caller) {
+                return Err(Error::AlreadyFlagged);
+            }
+            let reporter_open = self.reporter_open_flags.get(caller).unwrap_or(0);
+            if flags.len() as u32 >= MAX_FLAGS_PER_DOCUMENT
+                || reporter_open >= MAX_OPEN_FLAGS_PER_REPORTER
+            {
+                return Err(Error::FlagLimitExceeded);
             }
 
-            let access_level = self
-                .access_permissions
-                .get((property_id, account))
-                .unwrap_or(AccessLevel::None);
+            flags.push(DocumentFlag {
+                reporter: caller,
+                reason: reason.clone(),
+                flagged_at: timestamp,
+            });
+            self.document_flags.insert(document_id, &flags);
+            self.reporter_open_flags
+                .insert(caller, &(reporter_open + 1));
+            self.reporter_last_flag.insert(caller, &timestamp);
 
-            match access_level {
-                AccessLevel::Write | AccessLevel::Admin => Ok(()),
-                _ => Err(Error::Unauthorized),
+            let open_flags = flags.len() as u32;
+            self.env().emit_event(DocumentFlagged {
+                document_id,
+                reporter: caller,
+                reason,
+                open_flags,
+                timestamp,
+            });
+
+            if open_flags >= self.flag_threshold && document.is_pinned {
+                self.flag_unpinned.insert(document_id, &());
+                self.apply_unpin(document);
             }
+
+            Ok(())
         }
 
-        /// Checks if account has admin access
-        fn check_admin_access(&self, property_id: u64, account: AccountId) -> Result<(), Error> {
-            let access_level = self
-                .access_permissions
-                .get((property_id, account))
-                .unwrap_or(AccessLevel::None);
+        /// Gets the open flags raised against a document
+        #[ink(message)]
+        pub fn get_document_flags(&self, document_id: u64) -> Vec<DocumentFlag> {
+            self.document_flags.get(document_id).unwrap_or_default()
+        }
 
-            match access_level {
-                AccessLevel::Admin => Ok(()),
-                _ => Err(Error::Unauthorized),
+        /// Removes a document's open flags, releasing the reporters' quota
+        fn clear_flags(&mut self, document_id: u64) -> Vec<DocumentFlag> {
+            self.flag_unpinned.remove(document_id);
+            let flags = self.document_flags.take(document_id).unwrap_or_default();
+            for flag in flags.iter() {
+                let open = self.reporter_open_flags.get(flag.reporter).unwrap_or(0);
+                self.reporter_open_flags
+                    .insert(flag.reporter, &open.saturating_sub(1));
             }
+            flags
         }
 
         // ============================================================================
-        // QUERY FUNCTIONS
+        // ACCESS AUDIT LOG
         // ============================================================================
 
-        /// Gets property metadata
+        /// Records that the caller retrieved a document. Callers with read
+        /// access log before (or as proof of) fetching it from IPFS.
         #[ink(message)]
-        pub fn get_metadata(&self, property_id: u64) -> Option<PropertyMetadata> {
-            self.property_metadata.get(property_id)
+        pub fn log_access(&mut self, document_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let document = self
+                .documents
+                .get(document_id)
+                .ok_or(Error::DocumentNotFound)?;
+            self.check_read_access(document.property_id, caller)?;
+
+            self.record_access(document_id, caller);
+            Ok(())
         }
 
-        /// Gets document information
+        /// Gets up to `limit` retained access log entries of a document,
+        /// oldest first, skipping the first `offset` (property admins only)
         #[ink(message)]
-        pub fn get_document(&self, document_id: u64) -> Option<IpfsDocument> {
-            self.documents.get(document_id)
+        pub fn get_access_log(
+            &self,
+            document_id: u64,
+            offset: u32,
+            limit: u32,
+        ) -> Result<Vec<AccessLogEntry>, Error> {
+            let caller = self.env().caller();
+            let document = self
+                .documents
+                .get(document_id)
+                .ok_or(Error::DocumentNotFound)?;
+            if caller != self.admin {
+                self.check_admin_access(document.property_id, caller)?;
+            }
+
+            let total = self.access_log_counts.get(document_id).unwrap_or(0);
+            let capacity = u64::from(MAX_ACCESS_LOG_ENTRIES);
+            let oldest = total.saturating_sub(capacity);
+            let start = oldest.saturating_add(u64::from(offset));
+            let end = start
+                .saturating_add(u64::from(limit.min(MAX_ACCESS_LOG_PAGE)))
+                .min(total);
+
+            Ok((start..end)
+                .filter_map(|index| {
+                    self.access_logs
+                        .get((document_id, (index % capacity) as u32))
+                })
+                .collect())
         }
 
-        /// Gets all documents for a property
+        /// Gets the number of accesses ever logged for a document
         #[ink(message)]
-        pub fn get_property_documents(&self, property_id: u64) -> Vec<u64> {
-            self.property_documents.get(property_id).unwrap_or_default()
+        pub fn get_access_log_count(&self, document_id: u64) -> u64 {
+            self.access_log_counts.get(document_id).unwrap_or(0)
         }
 
-        /// Notarizes a property document, recording proof of existence on-chain
+        /// Requires a logged access before key envelopes of encrypted
+        /// documents are released (admin only)
         #[ink(message)]
-        pub fn notarize_document(
-            &mut self,
-            property_id: u64,
-            document_hash: Hash,
-            document_type: DocumentType,
-        ) -> Result<(), Error> {
+        pub fn set_require_access_log(&mut self, required: bool) -> Result<(), Error> {
             let caller = self.env().caller();
 
-            if self.notarizations.contains((property_id, document_hash)) {
-                return Err(Error::AlreadyNotarized);
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
             }
 
-            let timestamp = self.env().block_timestamp();
-            let block_number = self.env().block_number();
-
-            let record = NotarizationRecord {
-                property_id,
-                document_hash,
-                document_type,
-                notarized_by: caller,
-                timestamp,
-                block_number,
-            };
-
-            self.notarizations
-                .insert((property_id, document_hash), &record);
-
-            let mut hashes = self
-                .property_notarization_hashes
-                .get(property_id)
-                .unwrap_or_default();
-            hashes.push(document_hash);
-            self.property_notarization_hashes
-                .insert(property_id, &hashes);
-
-            self.env().emit_event(DocumentNotarized {
-                property_id,
-                document_hash,
-                notarized_by: caller,
-                timestamp,
-            });
+            self.require_access_log = required;
 
             Ok(())
         }
 
-        /// Verifies whether a document hash has been notarized for a property
+        /// Gets whether encrypted documents require a logged access
         #[ink(message)]
-        pub fn verify_document_notarization(
-            &self,
-            property_id: u64,
-            document_hash: Hash,
-        ) -> Option<NotarizationRecord> {
-            self.notarizations.get((property_id, document_hash))
+        pub fn is_access_log_required(&self) -> bool {
+            self.require_access_log
         }
 
-        /// Gets all notarized documents for a property
+        /// Stores the document key wrapped for `account` (write access only)
         #[ink(message)]
-        pub fn get_property_notarizations(&self, property_id: u64) -> Vec<NotarizationRecord> {
-            self.property_notarization_hashes
-                .get(property_id)
-                .unwrap_or_default()
-                .iter()
-                .filter_map(|hash| self.notarizations.get((property_id, *hash)))
-                .collect()
+        pub fn set_key_envelope(
+            &mut self,
+            document_id: u64,
+            account: AccountId,
+            envelope: KeyEnvelope,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            let document = self
+                .documents
+                .get(document_id)
+                .ok_or(Error::DocumentNotFound)?;
+            self.check_write_access(document.property_id, caller)?;
+
+            if !document.is_encrypted {
+                return Err(Error::EncryptionRequired);
+            }
+            if envelope.is_empty() {
+                return Err(Error::RequiredFieldMissing);
+            }
+            if envelope.len() as u32 > MAX_KEY_ENVELOPE_LENGTH {
+                return Err(Error::SizeLimitExceeded);
+            }
+
+            self.key_envelopes.insert((document_id, account), &envelope);
+
+            Ok(())
+        }
+
+        /// Gets the caller's key envelope for a document. Fails with
+        /// `AccessLogRequired` for encrypted documents while logging is
+        /// required; use `log_and_get_key_envelope` instead.
+        #[ink(message)]
+        pub fn get_key_envelope(&self, document_id: u64) -> Result<KeyEnvelope, Error> {
+            if self.require_access_log {
+                return Err(Error::AccessLogRequired);
+            }
+            self.key_envelope_for(document_id, self.env().caller())
+        }
+
+        /// Logs the caller's access and returns their key envelope in the
+        /// same transaction
+        #[ink(message)]
+        pub fn log_and_get_key_envelope(&mut self, document_id: u64) -> Result<KeyEnvelope, Error> {
+            let caller = self.env().caller();
+            let envelope = self.key_envelope_for(document_id, caller)?;
+            self.record_access(document_id, caller);
+            Ok(envelope)
+        }
+
+        /// Loads `account`'s key envelope after checking its read access
+        fn key_envelope_for(
+            &self,
+            document_id: u64,
+            account: AccountId,
+        ) -> Result<KeyEnvelope, Error> {
+            let document = self
+                .documents
+                .get(document_id)
+                .ok_or(Error::DocumentNotFound)?;
+            self.check_read_access(document.property_id, account)?;
+            self.key_envelopes
+                .get((document_id, account))
+                .ok_or(Error::KeyEnvelopeNotFound)
+        }
+
+        /// Appends to the document's access ring buffer, overwriting the
+        /// oldest entry once `MAX_ACCESS_LOG_ENTRIES` are held
+        fn record_access(&mut self, document_id: u64, account: AccountId) {
+            let timestamp = self.env().block_timestamp();
+            let total = self.access_log_counts.get(document_id).unwrap_or(0);
+            let slot = (total % u64::from(MAX_ACCESS_LOG_ENTRIES)) as u32;
+            self.access_logs.insert(
+                (document_id, slot),
+                &AccessLogEntry {
+                    account,
+                    accessed_at: timestamp,
+                },
+            );
+            self.access_log_counts
+                .insert(document_id, &total.saturating_add(1));
+
+            self.env().emit_event(DocumentAccessed {
+                document_id,
+                account,
+                timestamp,
+            });
+        }
+
+        // ============================================================================
+        // ACCESS CONTROL
+        // ============================================================================
+
+        /// Grants access to property documents
+        #[ink(message)]
+        pub fn grant_access(
+            &mut self,
+            property_id: u64,
+            account: AccountId,
+            access_level: AccessLevel,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            // Only admin or property owner can grant access
+            if caller != self.admin {
+                self.check_admin_access(property_id, caller)?;
+            }
+            self.check_grant_allowed(property_id, &access_level)?;
+
+            self.access_permissions
+                .insert((property_id, account), &access_level);
+            self.access_expirations.remove((property_id, account));
+
+            Ok(())
+        }
+
+        /// Grants access on several properties at once. The caller's admin
+        /// rights are checked on every listed property before any grant is
+        /// applied, so the batch either succeeds entirely or changes nothing.
+        #[ink(message)]
+        pub fn grant_access_batch(
+            &mut self,
+            grants: Vec<(u64, AccountId, AccessLevel)>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if grants.len() as u32 > MAX_ACCESS_GRANT_BATCH {
+                return Err(Error::BatchTooLarge);
+            }
+
+            for (property_id, _, access_level) in grants.iter() {
+                if caller != self.admin {
+                    self.check_admin_access(*property_id, caller)?;
+                }
+                self.check_grant_allowed(*property_id, access_level)?;
+            }
+
+            for (property_id, account, access_level) in grants {
+                self.access_permissions
+                    .insert((property_id, account), &access_level);
+                self.access_expirations.remove((property_id, account));
+            }
+
+            Ok(())
+        }
+
+        /// Grants a reviewer access to every property `owner` holds in the
+        /// configured registry, optionally expiring at `expires_at`. Callable
+        /// by the owner or the contract admin; returns the number of grants.
+        #[ink(message)]
+        pub fn grant_portfolio_access(
+            &mut self,
+            owner: AccountId,
+            reviewer: AccountId,
+            access_level: AccessLevel,
+            expires_at: Option<u64>,
+        ) -> Result<u32, Error> {
+            let caller = self.env().caller();
+
+            if caller != owner && caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            if let Some(expiry) = expires_at {
+                if expiry <= self.env().block_timestamp() {
+                    return Err(Error::InvalidExpiry);
+                }
+            }
+
+            let registry_addr = self.registry_contract.ok_or(Error::RegistryNotConfigured)?;
+
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            let registry: ink::contract_ref!(PropertyOwnership) =
+                FromAccountId::from_account_id(registry_addr);
+            // Check the size before loading the list itself.
+            let count = match registry.call().owner_property_count(owner).try_invoke() {
+                Ok(Ok(count)) => count,
+                _ => return Err(Error::RegistryCallFailed),
+            };
+            if count > MAX_PORTFOLIO_GRANTS {
+                return Err(Error::BatchTooLarge);
+            }
+            let property_ids = match registry.call().get_owner_properties(owner).try_invoke() {
+                Ok(Ok(property_ids)) => property_ids,
+                _ => return Err(Error::RegistryCallFailed),
+            };
+            for property_id in property_ids.iter() {
+                self.check_grant_allowed(*property_id, &access_level)?;
+            }
+
+            for property_id in property_ids.iter() {
+                self.access_permissions
+                    .insert((*property_id, reviewer), &access_level);
+                if let Some(expiry) = expires_at {
+                    self.access_expirations
+                        .insert((*property_id, reviewer), &expiry);
+                } else {
+                    self.access_expirations.remove((*property_id, reviewer));
+                }
+            }
+
+            Ok(property_ids.len() as u32)
+        }
+
+        /// Revokes access to property documents
+        #[ink(message)]
+        pub fn revoke_access(&mut self, property_id: u64, account: AccountId) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            // Only admin or property owner can revoke access
+            if caller != self.admin {
+                self.check_admin_access(property_id, caller)?;
+            }
+
+            self.access_permissions.remove((property_id, account));
+            self.access_expirations.remove((property_id, account));
+
+            Ok(())
+        }
+
+        /// Gets an account's effective access level, treating expired grants as none
+        #[ink(message)]
+        pub fn get_access_level(&self, property_id: u64, account: AccountId) -> AccessLevel {
+            self.effective_access(property_id, account)
+        }
+
+        /// Gets the expiry timestamp of an account's access, if it expires
+        #[ink(message)]
+        pub fn get_access_expiry(&self, property_id: u64, account: AccountId) -> Option<u64> {
+            self.access_expirations.get((property_id, account))
+        }
+
+        /// Resolves the stored access level, honouring any expiry
+        fn effective_access(&self, property_id: u64, account: AccountId) -> AccessLevel {
+            if let Some(expiry) = self.access_expirations.get((property_id, account)) {
+                if self.env().block_timestamp() >= expiry {
+                    return AccessLevel::None;
+                }
+            }
+
+            self.access_permissions
+                .get((property_id, account))
+                .unwrap_or(AccessLevel::None)
+        }
+
+        /// Checks if account has read access
+        fn check_read_access(&self, property_id: u64, account: AccountId) -> Result<(), Error> {
+            if account == self.admin {
+                return Ok(());
+            }
+
+            let access_level = self.effective_access(property_id, account);
+
+            match access_level {
+                AccessLevel::None => Err(Error::Unauthorized),
+                _ => Ok(()),
+            }
+        }
+
+        /// Checks if account has write access. Archived properties accept
+        /// no writes, not even from the admin.
+        fn check_write_access(&self, property_id: u64, account: AccountId) -> Result<(), Error> {
+            if self.archived_properties.contains(property_id) {
+                return Err(Error::PropertyArchived);
+            }
+
+            if account == self.admin {
+                return Ok(());
+            }
+
+            let access_level = self.effective_access(property_id, account);
+
+            match access_level {
+                AccessLevel::Write | AccessLevel::Admin => Ok(()),
+                _ => Err(Error::Unauthorized),
+            }
+        }
+
+        /// Checks if account has admin access
+        fn check_admin_access(&self, property_id: u64, account: AccountId) -> Result<(), Error> {
+            let access_level = self.effective_access(property_id, account);
+
+            match access_level {
+                AccessLevel::Admin => Ok(()),
+                _ => Err(Error::Unauthorized),
+            }
+        }
+
+        /// Rejects grants beyond `Read` on archived properties
+        fn check_grant_allowed(
+            &self,
+            property_id: u64,
+            access_level: &AccessLevel,
+        ) -> Result<(), Error> {
+            if self.archived_properties.contains(property_id)
+                && !matches!(access_level, AccessLevel::None | AccessLevel::Read)
+            {
+                return Err(Error::PropertyArchived);
+            }
+            Ok(())
+        }
+
+        // ============================================================================
+        // ARCHIVAL
+        // ============================================================================
+
+        /// Archives a sold or closed-out property (admin or property admin).
+        /// Its documents become read-only and are unpinned, releasing the
+        /// property's pin quota; returns the number of documents unpinned.
+        #[ink(message)]
+        pub fn archive_property(&mut self, property_id: u64) -> Result<u32, Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                self.check_admin_access(property_id, caller)?;
+            }
+
+            self.apply_archive(property_id, caller)
+        }
+
+        /// Archives a property the configured registry no longer knows, so
+        /// deregistrations carry over without a property admin. Callable by
+        /// anyone; returns whether the property was archived.
+        #[ink(message)]
+        pub fn sync_archive_from_registry(&mut self, property_id: u64) -> Result<bool, Error> {
+            let registry_addr = self.registry_contract.ok_or(Error::RegistryNotConfigured)?;
+            if self.archived_properties.contains(property_id) {
+                return Ok(false);
+            }
+
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            let registry: ink::contract_ref!(PropertyOwnership) =
+                FromAccountId::from_account_id(registry_addr);
+            let exists = match registry.call().property_exists(property_id).try_invoke() {
+                Ok(Ok(exists)) => exists,
+                _ => return Err(Error::RegistryCallFailed),
+            };
+            if exists {
+                return Ok(false);
+            }
+
+            self.apply_archive(property_id, self.env().caller())?;
+            Ok(true)
+        }
+
+        /// Makes an archived property writable again (admin or property
+        /// admin). Documents stay unpinned until pinned again.
+        #[ink(message)]
+        pub fn unarchive_property(&mut self, property_id: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                self.check_admin_access(property_id, caller)?;
+            }
+
+            if self.archived_properties.take(property_id).is_none() {
+                return Err(Error::PropertyNotArchived);
+            }
+            self.remove_archived_slot(property_id);
+
+            for document_id in self.property_documents.get(property_id).unwrap_or_default() {
+                if let Some(mut document) = self.documents.get(document_id) {
+                    document.is_archived = false;
+                    self.documents.insert(document_id, &document);
+                }
+            }
+
+            self.env().emit_event(PropertyUnarchived {
+                property_id,
+                unarchived_by: caller,
+                timestamp: self.env().block_timestamp(),
+            });
+
+            Ok(())
+        }
+
+        /// Gets whether a property is archived
+        #[ink(message)]
+        pub fn is_property_archived(&self, property_id: u64) -> bool {
+            self.archived_properties.contains(property_id)
+        }
+
+        /// Gets the timestamp at which a property was archived
+        #[ink(message)]
+        pub fn get_archived_at(&self, property_id: u64) -> Option<u64> {
+            self.archived_properties.get(property_id)
+        }
+
+        /// Gets up to `limit` archived property IDs, skipping the first
+        /// `offset`. Entries are in archival order until a property is
+        /// unarchived, which moves the most recent entry into its place.
+        #[ink(message)]
+        pub fn get_archived_properties(&self, offset: u32, limit: u32) -> Vec<u64> {
+            let end = offset
+                .saturating_add(limit.min(MAX_ARCHIVED_PROPERTIES_PAGE))
+                .min(self.archived_property_count);
+            (offset..end)
+                .filter_map(|slot| self.archived_property_index.get(slot))
+                .collect()
+        }
+
+        /// Gets the number of archived properties
+        #[ink(message)]
+        pub fn get_archived_property_count(&self) -> u32 {
+            self.archived_property_count
+        }
+
+        /// Drops `property_id` from the archive index, filling its slot with
+        /// the last entry
+        fn remove_archived_slot(&mut self, property_id: u64) {
+            let Some(slot) = self.archived_property_slots.take(property_id) else {
+                return;
+            };
+            let last = self.archived_property_count.saturating_sub(1);
+            if slot != last {
+                if let Some(moved) = self.archived_property_index.get(last) {
+                    self.archived_property_index.insert(slot, &moved);
+                    self.archived_property_slots.insert(moved, &slot);
+                }
+            }
+            self.archived_property_index.remove(last);
+            self.archived_property_count = last;
+        }
+
+        /// Unpins and marks every document of the property archived
+        fn apply_archive(&mut self, property_id: u64, caller: AccountId) -> Result<u32, Error> {
+            if self.archived_properties.contains(property_id) {
+                return Err(Error::PropertyArchived);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let pinned_before = self.property_pinned_size.get(property_id).unwrap_or(0);
+            let mut documents_unpinned = 0u32;
+            for document_id in self.property_documents.get(property_id).unwrap_or_default() {
+                let Some(mut document) = self.documents.get(document_id) else {
+                    continue;
+                };
+                if document.is_pinned {
+                    self.apply_unpin(document.clone());
+                    document.is_pinned = false;
+                    documents_unpinned += 1;
+                }
+                // Flag review must not re-pin an archived document
+                self.flag_unpinned.remove(document_id);
+                document.is_archived = true;
+                self.documents.insert(document_id, &document);
+            }
+
+            self.archived_properties.insert(property_id, &timestamp);
+            let slot = self.archived_property_count;
+            self.archived_property_index.insert(slot, &property_id);
+            self.archived_property_slots.insert(property_id, &slot);
+            self.archived_property_count = slot.saturating_add(1);
+
+            self.env().emit_event(PropertyArchived {
+                property_id,
+                archived_by: caller,
+                documents_unpinned,
+                released_size: pinned_before
+                    .saturating_sub(self.property_pinned_size.get(property_id).unwrap_or(0)),
+                timestamp,
+            });
+
+            Ok(documents_unpinned)
+        }
+
+        // ============================================================================
+        // QUERY FUNCTIONS
+        // ============================================================================
+
+        /// Gets the completeness score (0-100) of a property's documents and metadata
+        #[ink(message)]
+        pub fn get_completeness_score(&self, property_id: u64) -> u32 {
+            self.get_completeness_report(property_id).score
+        }
+
+        /// Gets the completeness score together with the items still missing
+        #[ink(message)]
+        pub fn get_completeness_report(&self, property_id: u64) -> CompletenessReport {
+            let documents: Vec<IpfsDocument> = self
+                .property_documents
+                .get(property_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| self.documents.get(*id))
+                .collect();
+
+            let present_types: Vec<DocumentType> = DocumentType::SCORED
+                .iter()
+                .filter(|t| documents.iter().any(|d| d.document_type == **t))
+                .cloned()
+                .collect();
+            let mut score = present_types.len() as u32 * COMPLETENESS_POINTS_PER_TYPE;
+            let mut missing = Vec::new();
+
+            let required = &self.required_document_types;
+            let mut attested = 0u32;
+            for doc_type in required {
+                let of_type: Vec<&IpfsDocument> = documents
+                    .iter()
+                    .filter(|d| d.document_type == *doc_type)
+                    .collect();
+                if of_type.is_empty() {
+                    missing.push(MissingItem::RequiredDocument(doc_type.clone()));
+                } else if of_type
+                    .iter()
+                    .any(|d| self.notarizations.contains((property_id, d.content_hash)))
+                {
+                    attested += 1;
+                } else {
+                    missing.push(MissingItem::Attestation(doc_type.clone()));
+                }
+            }
+            score += if required.is_empty() {
+                COMPLETENESS_REQUIRED_POINTS
+            } else {
+                COMPLETENESS_REQUIRED_POINTS * attested / required.len() as u32
+            };
+
+            let metadata = self.property_metadata.get(property_id);
+            let cids = [
+                (
+                    MetadataCidField::Documents,
+                    metadata
+                        .as_ref()
+                        .and_then(|m| m.documents_ipfs_cid.as_ref()),
+                ),
+                (
+                    MetadataCidField::Images,
+                    metadata.as_ref().and_then(|m| m.images_ipfs_cid.as_ref()),
+                ),
+                (
+                    MetadataCidField::LegalDocs,
+                    metadata
+                        .as_ref()
+                        .and_then(|m| m.legal_docs_ipfs_cid.as_ref()),
+                ),
+            ];
+            for (field, cid) in cids {
+                if cid.is_some() {
+                    score += COMPLETENESS_POINTS_PER_CID;
+                } else {
+                    missing.push(MissingItem::MetadataCid(field));
+                }
+            }
+
+            CompletenessReport {
+                property_id,
+                score,
+                present_types,
+                missing,
+            }
+        }
+
+        /// Gets the document types required for a complete property
+        #[ink(message)]
+        pub fn get_required_document_types(&self) -> Vec<DocumentType> {
+            self.required_document_types.clone()
+        }
+
+        /// Gets property metadata
+        #[ink(message)]
+        pub fn get_metadata(&self, property_id: u64) -> Option<PropertyMetadata> {
+            self.property_metadata.get(property_id)
+        }
+
+        /// Returns whether `candidate` hashes to the content hash stored for
+        /// `property_id`. `candidate.content_hash` itself is ignored.
+        #[ink(message)]
+        pub fn verify_metadata_integrity(
+            &self,
+            property_id: u64,
+            candidate: PropertyMetadata,
+        ) -> bool {
+            self.property_metadata
+                .get(property_id)
+                .is_some_and(|stored| stored.content_hash == candidate.compute_content_hash())
+        }
+
+        /// Gets document information
+        #[ink(message)]
+        pub fn get_document(&self, document_id: u64) -> Option<IpfsDocument> {
+            self.documents.get(document_id)
+        }
+
+        /// Gets all documents for a property
+        #[ink(message)]
+        pub fn get_property_documents(&self, property_id: u64) -> Vec<u64> {
+            self.property_documents.get(property_id).unwrap_or_default()
+        }
+
+        /// Notarizes a property document, recording proof of existence on-chain
+        #[ink(message)]
+        pub fn notarize_document(
+            &mut self,
+            property_id: u64,
+            document_hash: Hash,
+            document_type: DocumentType,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if self.archived_properties.contains(property_id) {
+                return Err(Error::PropertyArchived);
+            }
+
+            if self.notarizations.contains((property_id, document_hash)) {
+                return Err(Error::AlreadyNotarized);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let block_number = self.env().block_number();
+
+            let record = NotarizationRecord {
+                property_id,
+                document_hash,
+                document_type,
+                notarized_by: caller,
+                timestamp,
+                block_number,
+            };
+
+            self.notarizations
+                .insert((property_id, document_hash), &record);
+
+            let mut hashes = self
+                .property_notarization_hashes
+                .get(property_id)
+                .unwrap_or_default();
+            hashes.push(document_hash);
+            self.property_notarization_hashes
+                .insert(property_id, &hashes);
+
+            self.env().emit_event(DocumentNotarized {
+                property_id,
+                document_hash,
+                notarized_by: caller,
+                timestamp,
+            });
+
+            Ok(())
+        }
+
+        /// Verifies whether a document hash has been notarized for a property
+        #[ink(message)]
+        pub fn verify_document_notarization(
+            &self,
+            property_id: u64,
+            document_hash: Hash,
+        ) -> Option<NotarizationRecord> {
+            self.notarizations.get((property_id, document_hash))
+        }
+
+        /// Gets all notarized documents for a property
+        #[ink(message)]
+        pub fn get_property_notarizations(&self, property_id: u64) -> Vec<NotarizationRecord> {
+            self.property_notarization_hashes
+                .get(property_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|hash| self.notarizations.get((property_id, *hash)))
+                .collect()
         }
 
         /// Gets document by IPFS CID
@@ -935,6 +1884,49 @@ mod ipfs_metadata {
             Ok(())
         }
 
+        /// Sets the document types required for a complete property (admin only)
+        #[ink(message)]
+        pub fn set_required_document_types(
+            &mut self,
+            document_types: Vec<DocumentType>,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut required: Vec<DocumentType> = Vec::new();
+            for doc_type in document_types {
+                if !required.contains(&doc_type) {
+                    required.push(doc_type);
+                }
+            }
+            self.required_document_types = required;
+
+            Ok(())
+        }
+
+        /// Sets the property registry used for portfolio grants (admin only)
+        #[ink(message)]
+        pub fn set_registry_contract(&mut self, registry: Option<AccountId>) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.registry_contract = registry;
+
+            Ok(())
+        }
+
+        /// Gets the configured property registry
+        #[ink(message)]
+        pub fn get_registry_contract(&self) -> Option<AccountId> {
+            self.registry_contract
+        }
+
         /// Adds allowed MIME type (admin only)
         #[ink(message)]
         pub fn add_allowed_mime_type(&mut self, mime_type: String) -> Result<(), Error> {
@@ -973,6 +1965,16 @@ mod ipfs_metadata {
                 .get(document_id)
                 .ok_or(Error::DocumentNotFound)?;
 
+            self.clear_flags(document_id);
+            self.remove_document(document, reason);
+
+            Ok(())
+        }
+
+        /// Removes a malicious document from the registry
+        fn remove_document(&mut self, document: IpfsDocument, reason: String) {
+            let document_id = document.document_id;
+
             // Emit malicious file event
             self.env().emit_event(MaliciousFileDetected {
                 document_id,
@@ -981,22 +1983,84 @@ mod ipfs_metadata {
                 timestamp: self.env().block_timestamp(),
             });
 
-            // Remove document from registry
-            self.documents.remove(document_id);
-            self.cid_to_document.remove(&document.ipfs_cid);
+            // Remove document from registry
+            self.documents.remove(document_id);
+            self.cid_to_document.remove(&document.ipfs_cid);
+
+            // Remove from property documents list
+            let mut doc_ids = self
+                .property_documents
+                .get(document.property_id)
+                .unwrap_or_default();
+            doc_ids.retain(|&id| id != document_id);
+            self.property_documents
+                .insert(document.property_id, &doc_ids);
+        }
+
+        /// Resolves a flagged document (admin only). Upholding removes the
+        /// document; rejecting clears its flags and re-pins it if the flag
+        /// threshold had unpinned it.
+        #[ink(message)]
+        pub fn resolve_flag(&mut self, document_id: u64, uphold: bool) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            let document = self
+                .documents
+                .get(document_id)
+                .ok_or(Error::DocumentNotFound)?;
+
+            let was_flag_unpinned = self.flag_unpinned.contains(document_id);
+            let flags = self.clear_flags(document_id);
+            if flags.is_empty() {
+                return Err(Error::NoOpenFlags);
+            }
+
+            if uphold {
+                let reason = flags[0].reason.clone();
+                self.remove_document(document, reason);
+            } else if was_flag_unpinned && !document.is_pinned {
+                // Leave the document unpinned if the quota filled up meanwhile
+                let _ = self.apply_pin(document);
+            }
+
+            self.env().emit_event(DocumentFlagResolved {
+                document_id,
+                upheld: uphold,
+                flags_cleared: flags.len() as u32,
+                timestamp: self.env().block_timestamp(),
+            });
+
+            Ok(())
+        }
+
+        /// Sets the open-flag count that unpins a document (admin only)
+        #[ink(message)]
+        pub fn set_flag_threshold(&mut self, threshold: u32) -> Result<(), Error> {
+            let caller = self.env().caller();
+
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
 
-            // Remove from property documents list
-            let mut doc_ids = self
-                .property_documents
-                .get(document.property_id)
-                .unwrap_or_default();
-            doc_ids.retain(|&id| id != document_id);
-            self.property_documents
-                .insert(document.property_id, &doc_ids);
+            if threshold == 0 || threshold > MAX_FLAGS_PER_DOCUMENT {
+                return Err(Error::InvalidThreshold);
+            }
+
+            self.flag_threshold = threshold;
 
             Ok(())
         }
 
+        /// Gets the open-flag count that unpins a document
+        #[ink(message)]
+        pub fn get_flag_threshold(&self) -> u32 {
+            self.flag_threshold
+        }
+
         /// Handles IPFS network failure gracefully
         #[ink(message)]
         pub fn handle_ipfs_failure(
@@ -1082,5 +2146,782 @@ mod ipfs_metadata {
             let result = contract.verify_document_notarization(property_id, document_hash);
             assert_eq!(result, None);
         }
+
+        /// `compute_content_hash` of [`sample_metadata`] under
+        /// `METADATA_HASH_VERSION` 1.
+        const CANONICAL_SAMPLE_HASH: [u8; 32] = [
+            241, 51, 239, 191, 145, 78, 26, 134, 165, 107, 17, 171, 41, 118, 171, 229, 242, 37,
+            113, 234, 0, 220, 176, 226, 198, 195, 178, 112, 214, 52, 188, 143,
+        ];
+
+        fn sample_metadata() -> PropertyMetadata {
+            PropertyMetadata {
+                location: String::from("1 Main St"),
+                size: 100,
+                legal_description: String::from("Lot 1"),
+                valuation: 1_000,
+                documents_ipfs_cid: None,
+                images_ipfs_cid: None,
+                legal_docs_ipfs_cid: None,
+                created_at: 0,
+                content_hash: Hash::from([0; 32]),
+                is_encrypted: false,
+            }
+        }
+
+        fn register_doc(
+            contract: &mut IpfsMetadataRegistry,
+            property_id: u64,
+            cid_tag: u8,
+            document_type: DocumentType,
+        ) -> u64 {
+            let cid = ink::prelude::format!("bafy{:0>8}", cid_tag);
+            contract
+                .register_ipfs_document(
+                    property_id,
+                    cid,
+                    document_type,
+                    Hash::from([cid_tag; 32]),
+                    1_000,
+                    String::from("application/pdf"),
+                    false,
+                )
+                .expect("document should register")
+        }
+
+        #[ink::test]
+        fn completeness_score_tracks_document_additions() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let property_id = 7u64;
+
+            // Nothing configured as required: only the required share is awarded
+            assert_eq!(
+                contract.get_completeness_score(property_id),
+                COMPLETENESS_REQUIRED_POINTS
+            );
+
+            register_doc(&mut contract, property_id, 1, DocumentType::Deed);
+            register_doc(&mut contract, property_id, 2, DocumentType::Survey);
+            register_doc(&mut contract, property_id, 3, DocumentType::Images);
+            // A second document of a type already present adds nothing
+            register_doc(&mut contract, property_id, 4, DocumentType::Images);
+
+            let report = contract.get_completeness_report(property_id);
+            assert_eq!(
+                report.present_types,
+                vec![
+                    DocumentType::Deed,
+                    DocumentType::Survey,
+                    DocumentType::Images
+                ]
+            );
+            assert_eq!(
+                report.score,
+                3 * COMPLETENESS_POINTS_PER_TYPE + COMPLETENESS_REQUIRED_POINTS
+            );
+            assert_eq!(
+                report.missing,
+                vec![
+                    MissingItem::MetadataCid(MetadataCidField::Documents),
+                    MissingItem::MetadataCid(MetadataCidField::Images),
+                    MissingItem::MetadataCid(MetadataCidField::LegalDocs),
+                ]
+            );
+        }
+
+        #[ink::test]
+        fn completeness_requires_attested_required_types() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let property_id = 8u64;
+            contract
+                .set_required_document_types(vec![
+                    DocumentType::Deed,
+                    DocumentType::Title,
+                    DocumentType::Deed,
+                ])
+                .unwrap();
+            assert_eq!(
+                contract.get_required_document_types(),
+                vec![DocumentType::Deed, DocumentType::Title]
+            );
+
+            register_doc(&mut contract, property_id, 1, DocumentType::Deed);
+            let report = contract.get_completeness_report(property_id);
+            assert_eq!(report.score, COMPLETENESS_POINTS_PER_TYPE);
+            assert!(report
+                .missing
+                .contains(&MissingItem::Attestation(DocumentType::Deed)));
+            assert!(report
+                .missing
+                .contains(&MissingItem::RequiredDocument(DocumentType::Title)));
+
+            contract
+                .notarize_document(property_id, Hash::from([1; 32]), DocumentType::Deed)
+                .unwrap();
+            let report = contract.get_completeness_report(property_id);
+            assert_eq!(
+                report.score,
+                COMPLETENESS_POINTS_PER_TYPE + COMPLETENESS_REQUIRED_POINTS / 2
+            );
+            assert!(!report
+                .missing
+                .contains(&MissingItem::Attestation(DocumentType::Deed)));
+        }
+
+        #[ink::test]
+        fn completeness_counts_metadata_cids() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let property_id = 9u64;
+            let metadata = PropertyMetadata {
+                documents_ipfs_cid: Some(String::from("bafydocuments")),
+                legal_docs_ipfs_cid: Some(String::from("bafylegaldocs")),
+                ..sample_metadata()
+            };
+            contract
+                .validate_and_register_metadata(property_id, metadata)
+                .unwrap();
+
+            let report = contract.get_completeness_report(property_id);
+            assert_eq!(
+                report.score,
+                COMPLETENESS_REQUIRED_POINTS + 2 * COMPLETENESS_POINTS_PER_CID
+            );
+            assert_eq!(
+                report.missing,
+                vec![MissingItem::MetadataCid(MetadataCidField::Images)]
+            );
+        }
+
+        #[ink::test]
+        fn completeness_follows_revocations_and_supersedes() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let property_id = 10u64;
+            contract
+                .set_required_document_types(vec![DocumentType::Deed])
+                .unwrap();
+
+            let original = register_doc(&mut contract, property_id, 1, DocumentType::Deed);
+            contract
+                .notarize_document(property_id, Hash::from([1; 32]), DocumentType::Deed)
+                .unwrap();
+            let full = COMPLETENESS_POINTS_PER_TYPE + COMPLETENESS_REQUIRED_POINTS;
+            assert_eq!(contract.get_completeness_score(property_id), full);
+
+            // Superseding deed is registered but not yet notarized; the
+            // original still carries the attestation
+            let replacement = register_doc(&mut contract, property_id, 2, DocumentType::Deed);
+            assert_eq!(contract.get_completeness_score(property_id), full);
+
+            // Revoking the original leaves the unattested replacement
+            contract
+                .report_malicious_file(original, String::from("forged"))
+                .unwrap();
+            let report = contract.get_completeness_report(property_id);
+            assert_eq!(report.score, COMPLETENESS_POINTS_PER_TYPE);
+            assert_eq!(
+                report.missing[0],
+                MissingItem::Attestation(DocumentType::Deed)
+            );
+
+            // Revoking the replacement removes the type entirely
+            contract
+                .report_malicious_file(replacement, String::from("forged"))
+                .unwrap();
+            let report = contract.get_completeness_report(property_id);
+            assert_eq!(report.score, 0);
+            assert_eq!(
+                report.missing[0],
+                MissingItem::RequiredDocument(DocumentType::Deed)
+            );
+        }
+
+        #[ink::test]
+        fn set_required_document_types_is_admin_only() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(
+                contract.set_required_document_types(vec![DocumentType::Deed]),
+                Err(Error::Unauthorized)
+            );
+            assert!(contract.get_required_document_types().is_empty());
+        }
+
+        #[ink::test]
+        fn grant_access_batch_is_atomic() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract
+                .validate_and_register_metadata(1, sample_metadata())
+                .unwrap();
+            contract
+                .validate_and_register_metadata(2, sample_metadata())
+                .unwrap();
+
+            // Bob has no admin rights on property 3, so nothing is applied
+            assert_eq!(
+                contract.grant_access_batch(vec![
+                    (1, accounts.charlie, AccessLevel::Read),
+                    (3, accounts.charlie, AccessLevel::Read),
+                ]),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(
+                contract.get_access_level(1, accounts.charlie),
+                AccessLevel::None
+            );
+
+            contract
+                .grant_access_batch(vec![
+                    (1, accounts.charlie, AccessLevel::Read),
+                    (2, accounts.charlie, AccessLevel::Write),
+                ])
+                .unwrap();
+            assert_eq!(
+                contract.get_access_level(1, accounts.charlie),
+                AccessLevel::Read
+            );
+            assert_eq!(
+                contract.get_access_level(2, accounts.charlie),
+                AccessLevel::Write
+            );
+        }
+
+        #[ink::test]
+        fn grant_access_batch_enforces_size_cap() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let grants: Vec<(u64, AccountId, AccessLevel)> = (0..=MAX_ACCESS_GRANT_BATCH as u64)
+                .map(|property_id| (property_id, accounts.charlie, AccessLevel::Read))
+                .collect();
+
+            assert_eq!(
+                contract.grant_access_batch(grants.clone()),
+                Err(Error::BatchTooLarge)
+            );
+            assert_eq!(contract.grant_access_batch(grants[1..].to_vec()), Ok(()));
+            assert_eq!(
+                contract.get_access_level(0, accounts.charlie),
+                AccessLevel::None
+            );
+        }
+
+        #[ink::test]
+        fn expired_access_is_ignored_until_regranted() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract
+                .grant_access(1, accounts.charlie, AccessLevel::Write)
+                .unwrap();
+            contract
+                .access_expirations
+                .insert((1, accounts.charlie), &1_000);
+
+            assert_eq!(
+                contract.get_access_level(1, accounts.charlie),
+                AccessLevel::Write
+            );
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            assert_eq!(
+                contract.get_access_level(1, accounts.charlie),
+                AccessLevel::None
+            );
+            assert_eq!(
+                contract.check_write_access(1, accounts.charlie),
+                Err(Error::Unauthorized)
+            );
+
+            // A direct grant is permanent and clears the expiry
+            contract
+                .grant_access_batch(vec![(1, accounts.charlie, AccessLevel::Read)])
+                .unwrap();
+            assert_eq!(contract.get_access_expiry(1, accounts.charlie), None);
+            assert_eq!(
+                contract.get_access_level(1, accounts.charlie),
+                AccessLevel::Read
+            );
+        }
+
+        #[ink::test]
+        fn grant_portfolio_access_validates_before_calling_registry() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(500);
+
+            assert_eq!(
+                contract.grant_portfolio_access(
+                    accounts.bob,
+                    accounts.charlie,
+                    AccessLevel::Read,
+                    None
+                ),
+                Err(Error::RegistryNotConfigured)
+            );
+            assert_eq!(
+                contract.grant_portfolio_access(
+                    accounts.bob,
+                    accounts.charlie,
+                    AccessLevel::Read,
+                    Some(500)
+                ),
+                Err(Error::InvalidExpiry)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(
+                contract.set_registry_contract(Some(accounts.eve)),
+                Err(Error::Unauthorized)
+            );
+            assert_eq!(
+                contract.grant_portfolio_access(
+                    accounts.bob,
+                    accounts.charlie,
+                    AccessLevel::Read,
+                    None
+                ),
+                Err(Error::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.set_registry_contract(Some(accounts.eve)).unwrap();
+            assert_eq!(contract.get_registry_contract(), Some(accounts.eve));
+        }
+
+        fn setup_flaggable(contract: &mut IpfsMetadataRegistry, property_id: u64) -> u64 {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let document_id = register_doc(contract, property_id, 1, DocumentType::Images);
+            contract.pin_document(document_id).unwrap();
+            for reviewer in [accounts.bob, accounts.charlie, accounts.django] {
+                contract
+                    .grant_access(property_id, reviewer, AccessLevel::Read)
+                    .unwrap();
+            }
+            document_id
+        }
+
+        #[ink::test]
+        fn flag_threshold_unpins_until_flags_are_rejected() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let document_id = setup_flaggable(&mut contract, 1);
+            contract.set_flag_threshold(2).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract
+                .flag_document(document_id, String::from("malware"))
+                .unwrap();
+            assert!(contract.get_document(document_id).unwrap().is_pinned);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract
+                .flag_document(document_id, String::from("phishing"))
+                .unwrap();
+            assert!(!contract.get_document(document_id).unwrap().is_pinned);
+            assert_eq!(contract.get_property_pinned_size(1), 0);
+            assert_eq!(contract.get_document_flags(document_id).len(), 2);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.resolve_flag(document_id, false).unwrap();
+            assert!(contract.get_document(document_id).unwrap().is_pinned);
+            assert_eq!(contract.get_property_pinned_size(1), 1_000);
+            assert!(contract.get_document_flags(document_id).is_empty());
+            assert_eq!(contract.reporter_open_flags.get(accounts.bob), Some(0));
+            assert_eq!(
+                contract.resolve_flag(document_id, false),
+                Err(Error::NoOpenFlags)
+            );
+        }
+
+        #[ink::test]
+        fn upheld_flag_removes_document() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let document_id = setup_flaggable(&mut contract, 1);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract
+                .flag_document(document_id, String::from("malware"))
+                .unwrap();
+            assert_eq!(
+                contract.resolve_flag(document_id, true),
+                Err(Error::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.resolve_flag(document_id, true).unwrap();
+            assert_eq!(contract.get_document(document_id), None);
+            assert!(contract.get_property_documents(1).is_empty());
+            assert_eq!(contract.reporter_open_flags.get(accounts.bob), Some(0));
+        }
+
+        #[ink::test]
+        fn flag_document_enforces_cooldown_and_access() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let first = setup_flaggable(&mut contract, 1);
+            let second = register_doc(&mut contract, 1, 2, DocumentType::Deed);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                contract.flag_document(first, String::from("malware")),
+                Err(Error::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.flag_document(first, String::new()),
+                Err(Error::RequiredFieldMissing)
+            );
+            contract
+                .flag_document(first, String::from("malware"))
+                .unwrap();
+            assert_eq!(
+                contract.flag_document(second, String::from("malware")),
+                Err(Error::FlagCooldownActive)
+            );
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(FLAG_COOLDOWN_MS);
+            assert_eq!(
+                contract.flag_document(first, String::from("again")),
+                Err(Error::AlreadyFlagged)
+            );
+            contract
+                .flag_document(second, String::from("malware"))
+                .unwrap();
+            assert_eq!(contract.reporter_open_flags.get(accounts.bob), Some(2));
+        }
+
+        #[ink::test]
+        fn flag_document_caps_reports() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let document_id = register_doc(&mut contract, 1, 1, DocumentType::Deed);
+            for i in 0..=MAX_FLAGS_PER_DOCUMENT as u8 {
+                contract
+                    .grant_access(1, AccountId::from([0x80 + i; 32]), AccessLevel::Read)
+                    .unwrap();
+            }
+
+            for i in 0..MAX_FLAGS_PER_DOCUMENT as u8 {
+                ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from(
+                    [0x80 + i; 32],
+                ));
+                contract
+                    .flag_document(document_id, String::from("malware"))
+                    .unwrap();
+            }
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from(
+                [0x80 + MAX_FLAGS_PER_DOCUMENT as u8; 32],
+            ));
+            assert_eq!(
+                contract.flag_document(document_id, String::from("malware")),
+                Err(Error::FlagLimitExceeded)
+            );
+
+            // A reporter's open flags are capped across documents too
+            let reporter = AccountId::from([0x80; 32]);
+            contract
+                .reporter_open_flags
+                .insert(reporter, &MAX_OPEN_FLAGS_PER_REPORTER);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(
+                ink::env::test::default_accounts::<ink::env::DefaultEnvironment>().alice,
+            );
+            let other = register_doc(&mut contract, 1, 2, DocumentType::Title);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(reporter);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(FLAG_COOLDOWN_MS);
+            assert_eq!(
+                contract.flag_document(other, String::from("malware")),
+                Err(Error::FlagLimitExceeded)
+            );
+        }
+
+        fn register_encrypted_doc(contract: &mut IpfsMetadataRegistry, property_id: u64) -> u64 {
+            contract
+                .register_ipfs_document(
+                    property_id,
+                    String::from("bafyencrypted"),
+                    DocumentType::Deed,
+                    Hash::from([0xee; 32]),
+                    1_000,
+                    String::from("application/pdf"),
+                    true,
+                )
+                .expect("document should register")
+        }
+
+        #[ink::test]
+        fn log_and_get_key_envelope_records_the_access() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let document_id = register_encrypted_doc(&mut contract, 1);
+            let plain = register_doc(&mut contract, 1, 2, DocumentType::Title);
+            contract
+                .grant_access(1, accounts.bob, AccessLevel::Read)
+                .unwrap();
+            assert_eq!(
+                contract.set_key_envelope(plain, accounts.bob, vec![1; 32]),
+                Err(Error::EncryptionRequired)
+            );
+            contract
+                .set_key_envelope(document_id, accounts.bob, vec![7; 32])
+                .unwrap();
+            contract.set_require_access_log(true).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.get_key_envelope(document_id),
+                Err(Error::AccessLogRequired)
+            );
+            assert_eq!(
+                contract.set_require_access_log(false),
+                Err(Error::Unauthorized)
+            );
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(42);
+            assert_eq!(
+                contract.log_and_get_key_envelope(document_id),
+                Ok(vec![7; 32])
+            );
+            assert_eq!(
+                contract.get_access_log(document_id, 0, 10),
+                Err(Error::Unauthorized)
+            );
+
+            // Accounts without read access or an envelope get nothing logged
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.eve);
+            assert_eq!(
+                contract.log_and_get_key_envelope(document_id),
+                Err(Error::Unauthorized)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                contract.log_and_get_key_envelope(document_id),
+                Err(Error::KeyEnvelopeNotFound)
+            );
+
+            assert_eq!(
+                contract.get_access_log(document_id, 0, 10),
+                Ok(vec![AccessLogEntry {
+                    account: accounts.bob,
+                    accessed_at: 42,
+                }])
+            );
+            assert_eq!(contract.get_access_log_count(plain), 0);
+        }
+
+        #[ink::test]
+        fn access_log_rolls_over_at_cap() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let document_id = register_doc(&mut contract, 1, 1, DocumentType::Deed);
+            contract
+                .grant_access(1, accounts.bob, AccessLevel::Read)
+                .unwrap();
+            contract
+                .grant_access(1, accounts.charlie, AccessLevel::Admin)
+                .unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            for timestamp in 0..u64::from(MAX_ACCESS_LOG_ENTRIES) + 5 {
+                ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+                contract.log_access(document_id).unwrap();
+            }
+            assert_eq!(
+                contract.get_access_log_count(document_id),
+                u64::from(MAX_ACCESS_LOG_ENTRIES) + 5
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            let first_page = contract.get_access_log(document_id, 0, 100).unwrap();
+            assert_eq!(first_page.len() as u32, MAX_ACCESS_LOG_PAGE);
+            assert_eq!(first_page[0].accessed_at, 5);
+            let last_page = contract
+                .get_access_log(document_id, MAX_ACCESS_LOG_ENTRIES - 2, 10)
+                .unwrap();
+            let timestamps: Vec<u64> = last_page.iter().map(|entry| entry.accessed_at).collect();
+            let newest = u64::from(MAX_ACCESS_LOG_ENTRIES) + 4;
+            assert_eq!(timestamps, vec![newest - 1, newest]);
+        }
+
+        #[ink::test]
+        fn archive_releases_quota_and_rejects_writes() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let deed = register_doc(&mut contract, 1, 1, DocumentType::Deed);
+            let title = register_doc(&mut contract, 1, 2, DocumentType::Title);
+            register_doc(&mut contract, 1, 3, DocumentType::Survey);
+            contract.pin_document(deed).unwrap();
+            contract.pin_document(title).unwrap();
+            contract
+                .grant_access(1, accounts.bob, AccessLevel::Admin)
+                .unwrap();
+            assert_eq!(contract.get_property_pinned_size(1), 2_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.archive_property(1), Err(Error::Unauthorized));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(700);
+            assert_eq!(contract.archive_property(1), Ok(2));
+            assert_eq!(contract.archive_property(1), Err(Error::PropertyArchived));
+            assert!(contract.is_property_archived(1));
+            assert_eq!(contract.get_archived_at(1), Some(700));
+            assert_eq!(contract.get_property_pinned_size(1), 0);
+            let document = contract.get_document(deed).unwrap();
+            assert!(document.is_archived && !document.is_pinned);
+
+            // Even the contract admin cannot write to an archived property
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                contract.register_ipfs_document(
+                    1,
+                    String::from("bafy00000009"),
+                    DocumentType::Other,
+                    Hash::from([9; 32]),
+                    1_000,
+                    String::from("application/pdf"),
+                    false,
+                ),
+                Err(Error::PropertyArchived)
+            );
+            assert_eq!(contract.pin_document(deed), Err(Error::PropertyArchived));
+            assert_eq!(
+                contract.validate_and_register_metadata(1, sample_metadata()),
+                Err(Error::PropertyArchived)
+            );
+            assert_eq!(
+                contract.notarize_document(1, Hash::from([9; 32]), DocumentType::Deed),
+                Err(Error::PropertyArchived)
+            );
+            assert_eq!(
+                contract.grant_access(1, accounts.charlie, AccessLevel::Write),
+                Err(Error::PropertyArchived)
+            );
+            assert_eq!(
+                contract.grant_access_batch(vec![
+                    (2, accounts.charlie, AccessLevel::Write),
+                    (1, accounts.charlie, AccessLevel::Admin),
+                ]),
+                Err(Error::PropertyArchived)
+            );
+            assert_eq!(
+                contract.get_access_level(2, accounts.charlie),
+                AccessLevel::None
+            );
+            // Read access can still be handed out
+            contract
+                .grant_access(1, accounts.charlie, AccessLevel::Read)
+                .unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.log_access(deed).unwrap();
+        }
+
+        #[ink::test]
+        fn unarchive_restores_writes_and_pagination_follows() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let deed = register_doc(&mut contract, 1, 1, DocumentType::Deed);
+            contract.pin_document(deed).unwrap();
+            for property_id in [1, 2, 3] {
+                contract.archive_property(property_id).unwrap();
+            }
+            assert_eq!(contract.get_archived_properties(0, 10), vec![1, 2, 3]);
+            assert_eq!(contract.get_archived_properties(1, 1), vec![2]);
+
+            contract.unarchive_property(1).unwrap();
+            assert_eq!(
+                contract.unarchive_property(1),
+                Err(Error::PropertyNotArchived)
+            );
+            assert!(!contract.is_property_archived(1));
+            // The latest entry takes the freed slot
+            assert_eq!(contract.get_archived_properties(0, 10), vec![3, 2]);
+            assert_eq!(contract.get_archived_property_count(), 2);
+            contract.unarchive_property(2).unwrap();
+            assert_eq!(contract.get_archived_properties(0, 10), vec![3]);
+            assert_eq!(contract.get_archived_properties(1, 10), Vec::<u64>::new());
+            contract.archive_property(2).unwrap();
+            assert_eq!(contract.get_archived_properties(0, 10), vec![3, 2]);
+
+            // Documents come back unpinned and can be pinned again
+            let document = contract.get_document(deed).unwrap();
+            assert!(!document.is_archived && !document.is_pinned);
+            contract.pin_document(deed).unwrap();
+            assert_eq!(contract.get_property_pinned_size(1), 1_000);
+            register_doc(&mut contract, 1, 2, DocumentType::Title);
+
+            assert_eq!(
+                contract.sync_archive_from_registry(1),
+                Err(Error::RegistryNotConfigured)
+            );
+        }
+
+        #[ink::test]
+        fn metadata_content_hash_is_computed_on_registration() {
+            let mut contract = IpfsMetadataRegistry::new();
+            let metadata = sample_metadata();
+            let expected = metadata.compute_content_hash();
+
+            let mut forged = metadata.clone();
+            forged.content_hash = Hash::from([0x11; 32]);
+            assert_eq!(
+                contract.validate_and_register_metadata(1, forged),
+                Err(Error::ContentHashMismatch)
+            );
+
+            contract
+                .validate_and_register_metadata(1, metadata.clone())
+                .unwrap();
+            assert_eq!(contract.get_metadata(1).unwrap().content_hash, expected);
+            let mut supplied = metadata.clone();
+            supplied.content_hash = expected;
+            contract
+                .validate_and_register_metadata(2, supplied)
+                .unwrap();
+
+            assert!(contract.verify_metadata_integrity(1, metadata.clone()));
+            let mut tampered = metadata.clone();
+            tampered.valuation += 1;
+            tampered.content_hash = expected;
+            assert!(!contract.verify_metadata_integrity(1, tampered));
+            assert!(!contract.verify_metadata_integrity(3, metadata));
+        }
+
+        #[ink::test]
+        fn metadata_content_hash_encoding_is_stable() {
+            let metadata = sample_metadata();
+            // Pinned so that any change to the canonical encoding is a
+            // deliberate, versioned one.
+            assert_eq!(
+                metadata.compute_content_hash(),
+                Hash::from(CANONICAL_SAMPLE_HASH)
+            );
+
+            let mut rehashed = metadata.clone();
+            rehashed.content_hash = Hash::from([0xff; 32]);
+            assert_eq!(
+                rehashed.compute_content_hash(),
+                metadata.compute_content_hash()
+            );
+
+            let variants: [fn(&mut PropertyMetadata); 9] = [
+                |m| m.location.push('!'),
+                |m| m.size += 1,
+                |m| m.legal_description.push('!'),
+                |m| m.valuation += 1,
+                |m| m.documents_ipfs_cid = Some(String::from("bafydocs")),
+                |m| m.images_ipfs_cid = Some(String::from("bafyimages")),
+                |m| m.legal_docs_ipfs_cid = Some(String::from("bafylegal")),
+                |m| m.created_at += 1,
+                |m| m.is_encrypted = !m.is_encrypted,
+            ];
+            for mutate in variants {
+                let mut changed = metadata.clone();
+                mutate(&mut changed);
+                assert_ne!(
+                    changed.compute_content_hash(),
+                    metadata.compute_content_hash()
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,473 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+//! # PropChain Reference Marketplace
+//!
+//! Minimal implementation of the [`Marketplace`] trait, kept outside the
+//! property registry. Sellers list properties they own at a fixed price or
+//! as an auction; sales are completed through the registry's
+//! [`TransferAgent`] hook, so the registry admin must trust this contract
+//! with `set_transfer_agent` before anything can be sold.
+//!
+//! Payments are held by this contract only while an auction bid is standing;
+//! fixed-price proceeds go straight to the seller.
+
+use propchain_traits::marketplace::{
+    Listing, ListingKind, Marketplace, MarketplaceError, TransferAgent,
+};
+
+#[ink::contract]
+pub mod propchain_marketplace {
+    use super::*;
+    use ink::storage::Mapping;
+
+    // ── Events ────────────────────────────────────────────────────────────
+
+    #[ink(event)]
+    pub struct Listed {
+        #[ink(topic)]
+        pub listing_id: u64,
+        #[ink(topic)]
+        pub property_id: u64,
+        #[ink(topic)]
+        pub seller: AccountId,
+        pub price: u128,
+        pub kind: ListingKind,
+    }
+
+    #[ink(event)]
+    pub struct Delisted {
+        #[ink(topic)]
+        pub listing_id: u64,
+    }
+
+    #[ink(event)]
+    pub struct BidPlaced {
+        #[ink(topic)]
+        pub listing_id: u64,
+        #[ink(topic)]
+        pub bidder: AccountId,
+        pub amount: u128,
+    }
+
+    /// Emitted when a property changes hands through a purchase or a
+    /// settled auction.
+    #[ink(event)]
+    pub struct Sold {
+        #[ink(topic)]
+        pub listing_id: u64,
+        #[ink(topic)]
+        pub property_id: u64,
+        pub seller: AccountId,
+        #[ink(topic)]
+        pub buyer: AccountId,
+        pub price: u128,
+    }
+
+    // ── Storage ───────────────────────────────────────────────────────────
+
+    #[ink(storage)]
+    pub struct PropertyMarketplace {
+        /// Property registry that owns the property records.
+        registry: AccountId,
+        listings: Mapping<u64, Listing>,
+        listing_count: u64,
+        /// Active listing of each property, if any.
+        active_listing: Mapping<u64, u64>,
+    }
+
+    // ── Implementation ────────────────────────────────────────────────────
+
+    impl PropertyMarketplace {
+        /// Deploy a marketplace selling properties of `registry`.
+        #[ink(constructor)]
+        pub fn new(registry: AccountId) -> Self {
+            Self {
+                registry,
+                listings: Mapping::default(),
+                listing_count: 0,
+                active_listing: Mapping::default(),
+            }
+        }
+
+        /// Returns the property registry this marketplace sells from.
+        #[ink(message)]
+        pub fn registry(&self) -> AccountId {
+            self.registry
+        }
+
+        /// Returns the active listing of `property_id`, if any.
+        #[ink(message)]
+        pub fn get_active_listing(&self, property_id: u64) -> Option<u64> {
+            self.active_listing.get(property_id)
+        }
+
+        // ── Internal helpers ──────────────────────────────────────────────
+
+        fn registry_ref(&self) -> ink::contract_ref!(TransferAgent) {
+            use ink::env::call::FromAccountId;
+            FromAccountId::from_account_id(self.registry)
+        }
+
+        fn active(&self, listing_id: u64) -> Result<Listing, MarketplaceError> {
+            self.listings
+                .get(listing_id)
+                .filter(|listing| listing.active)
+                .ok_or(MarketplaceError::ListingNotFound)
+        }
+
+        fn close(&mut self, mut listing: Listing) {
+            listing.active = false;
+            self.active_listing.remove(listing.property_id);
+            self.listings.insert(listing.listing_id, &listing);
+        }
+
+        fn pay(&self, to: AccountId, amount: u128) -> Result<(), MarketplaceError> {
+            if amount == 0 {
+                return Ok(());
+            }
+            self.env()
+                .transfer(to, amount)
+                .map_err(|_| MarketplaceError::PaymentFailed)
+        }
+
+        /// Moves the property to `buyer` through the registry, pays the
+        /// seller and closes the listing.
+        fn complete_sale(
+            &mut self,
+            listing: Listing,
+            buyer: AccountId,
+            price: u128,
+        ) -> Result<(), MarketplaceError> {
+            self.registry_ref()
+                .agent_transfer(listing.property_id, listing.seller, buyer)
+                .map_err(MarketplaceError::TransferFailed)?;
+            self.pay(listing.seller, price)?;
+
+            self.env().emit_event(Sold {
+                listing_id: listing.listing_id,
+                property_id: listing.property_id,
+                seller: listing.seller,
+                buyer,
+                price,
+            });
+            self.close(listing);
+            Ok(())
+        }
+    }
+
+    impl Marketplace for PropertyMarketplace {
+        #[ink(message)]
+        fn list(
+            &mut self,
+            property_id: u64,
+            price: u128,
+            auction_blocks: Option<u32>,
+        ) -> Result<u64, MarketplaceError> {
+            if price == 0 || auction_blocks == Some(0) {
+                return Err(MarketplaceError::InvalidParameters);
+            }
+            let caller = self.env().caller();
+            let existing = self
+                .active_listing
+                .get(property_id)
+                .and_then(|listing_id| self.listings.get(listing_id));
+            if existing
+                .as_ref()
+                .is_some_and(|listing| listing.seller == caller)
+            {
+                return Err(MarketplaceError::AlreadyListed);
+            }
+            if self.registry_ref().property_owner(property_id) != Some(caller) {
+                return Err(MarketplaceError::Unauthorized);
+            }
+            // A listing left behind by a previous owner can never sell;
+            // replace it, returning any standing bid.
+            if let Some(stale) = existing {
+                if let Some((bidder, amount)) = stale.highest_bid {
+                    self.pay(bidder, amount)?;
+                }
+                let listing_id = stale.listing_id;
+                self.close(stale);
+                self.env().emit_event(Delisted { listing_id });
+            }
+
+            let kind = match auction_blocks {
+                Some(blocks) => ListingKind::Auction {
+                    ends_at: self.env().block_number().saturating_add(blocks),
+                },
+                None => ListingKind::FixedPrice,
+            };
+            self.listing_count += 1;
+            let listing_id = self.listing_count;
+            let listing = Listing {
+                listing_id,
+                property_id,
+                seller: caller,
+                price,
+                kind,
+                highest_bid: None,
+                active: true,
+            };
+            self.listings.insert(listing_id, &listing);
+            self.active_listing.insert(property_id, &listing_id);
+
+            self.env().emit_event(Listed {
+                listing_id,
+                property_id,
+                seller: caller,
+                price,
+                kind,
+            });
+            Ok(listing_id)
+        }
+
+        #[ink(message)]
+        fn delist(&mut self, listing_id: u64) -> Result<(), MarketplaceError> {
+            let listing = self.active(listing_id)?;
+            if listing.seller != self.env().caller() {
+                return Err(MarketplaceError::Unauthorized);
+            }
+            if let Some((bidder, amount)) = listing.highest_bid {
+                self.pay(bidder, amount)?;
+            }
+            self.close(listing);
+            self.env().emit_event(Delisted { listing_id });
+            Ok(())
+        }
+
+        #[ink(message, payable)]
+        fn buy(&mut self, listing_id: u64) -> Result<(), MarketplaceError> {
+            let listing = self.active(listing_id)?;
+            if listing.kind != ListingKind::FixedPrice {
+                return Err(MarketplaceError::WrongListingKind);
+            }
+            let paid = self.env().transferred_value();
+            if paid != listing.price {
+                return Err(MarketplaceError::InsufficientPayment);
+            }
+            let buyer = self.env().caller();
+            self.complete_sale(listing, buyer, paid)
+        }
+
+        #[ink(message, payable)]
+        fn bid(&mut self, listing_id: u64) -> Result<(), MarketplaceError> {
+            let mut listing = self.active(listing_id)?;
+            let ListingKind::Auction { ends_at } = listing.kind else {
+                return Err(MarketplaceError::WrongListingKind);
+            };
+            if self.env().block_number() >= ends_at {
+                return Err(MarketplaceError::AuctionEnded);
+            }
+            let amount = self.env().transferred_value();
+            let beats_current = match listing.highest_bid {
+                Some((_, current)) => amount > current,
+                None => true,
+            };
+            if amount < listing.price || !beats_current {
+                return Err(MarketplaceError::InsufficientPayment);
+            }
+
+            let bidder = self.env().caller();
+            if let Some((previous, refund)) = listing.highest_bid.replace((bidder, amount)) {
+                self.pay(previous, refund)?;
+            }
+            self.listings.insert(listing_id, &listing);
+
+            self.env().emit_event(BidPlaced {
+                listing_id,
+                bidder,
+                amount,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn settle(&mut self, listing_id: u64) -> Result<(), MarketplaceError> {
+            let listing = self.active(listing_id)?;
+            let ListingKind::Auction { ends_at } = listing.kind else {
+                return Err(MarketplaceError::WrongListingKind);
+            };
+            if self.env().block_number() < ends_at {
+                return Err(MarketplaceError::AuctionNotEnded);
+            }
+            match listing.highest_bid {
+                Some((winner, amount)) => match self.complete_sale(listing.clone(), winner, amount) {
+                    // The registry refused the transfer, e.g. because the
+                    // seller no longer owns the property. Nothing else can
+                    // settle the auction, so close it and return the bid.
+                    Err(MarketplaceError::TransferFailed(_)) => {
+                        self.pay(winner, amount)?;
+                        self.close(listing);
+                        self.env().emit_event(Delisted { listing_id });
+                        Ok(())
+                    }
+                    result => result,
+                },
+                None => {
+                    self.close(listing);
+                    self.env().emit_event(Delisted { listing_id });
+                    Ok(())
+                }
+            }
+        }
+
+        #[ink(message)]
+        fn get_listing(&self, listing_id: u64) -> Option<Listing> {
+            self.listings.get(listing_id)
+        }
+    }
+
+    // ── Tests ─────────────────────────────────────────────────────────────
+    //
+    // Calls into the registry cannot run off-chain, so these tests seed
+    // listings directly. Sales, settlement after an owner change and
+    // replacing a stale listing are covered by the marketplace scenarios in
+    // `e2e-tests/tests/e2e.rs`.
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::test;
+        use ink::env::DefaultEnvironment;
+
+        fn setup() -> PropertyMarketplace {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_account_balance::<DefaultEnvironment>(
+                test::callee::<DefaultEnvironment>(),
+                1_000_000,
+            );
+            PropertyMarketplace::new(accounts.django)
+        }
+
+        fn seed(market: &mut PropertyMarketplace, kind: ListingKind) -> u64 {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            market.listing_count += 1;
+            let listing_id = market.listing_count;
+            let listing = Listing {
+                listing_id,
+                property_id: 7,
+                seller: accounts.alice,
+                price: 1_000,
+                kind,
+                highest_bid: None,
+                active: true,
+            };
+            market.listings.insert(listing_id, &listing);
+            market.active_listing.insert(7, &listing_id);
+            listing_id
+        }
+
+        #[ink::test]
+        fn list_validates_parameters() {
+            let mut market = setup();
+            assert_eq!(
+                market.list(7, 0, None),
+                Err(MarketplaceError::InvalidParameters)
+            );
+            assert_eq!(
+                market.list(7, 1_000, Some(0)),
+                Err(MarketplaceError::InvalidParameters)
+            );
+            seed(&mut market, ListingKind::FixedPrice);
+            assert_eq!(
+                market.list(7, 1_000, None),
+                Err(MarketplaceError::AlreadyListed)
+            );
+        }
+
+        #[ink::test]
+        fn buy_requires_exact_payment_on_fixed_price_listing() {
+            let mut market = setup();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let fixed = seed(&mut market, ListingKind::FixedPrice);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(999);
+            assert_eq!(
+                market.buy(fixed),
+                Err(MarketplaceError::InsufficientPayment)
+            );
+            assert_eq!(market.bid(fixed), Err(MarketplaceError::WrongListingKind));
+            assert_eq!(market.buy(99), Err(MarketplaceError::ListingNotFound));
+        }
+
+        #[ink::test]
+        fn bids_must_beat_reserve_and_refund_the_previous_bidder() {
+            let mut market = setup();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let auction = seed(&mut market, ListingKind::Auction { ends_at: 5 });
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(500);
+            assert_eq!(
+                market.bid(auction),
+                Err(MarketplaceError::InsufficientPayment)
+            );
+            test::set_value_transferred::<DefaultEnvironment>(1_000);
+            market.bid(auction).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                market.bid(auction),
+                Err(MarketplaceError::InsufficientPayment)
+            );
+            let bob_before = test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(1_500);
+            market.bid(auction).unwrap();
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap(),
+                bob_before + 1_000
+            );
+            assert_eq!(
+                market.get_listing(auction).unwrap().highest_bid,
+                Some((accounts.charlie, 1_500))
+            );
+            assert_eq!(
+                market.settle(auction),
+                Err(MarketplaceError::AuctionNotEnded)
+            );
+
+            for _ in 0..5 {
+                test::advance_block::<DefaultEnvironment>();
+            }
+            assert_eq!(market.bid(auction), Err(MarketplaceError::AuctionEnded));
+        }
+
+        #[ink::test]
+        fn delist_is_seller_only_and_refunds_standing_bid() {
+            let mut market = setup();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let auction = seed(&mut market, ListingKind::Auction { ends_at: 5 });
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(1_200);
+            market.bid(auction).unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(0);
+            assert_eq!(market.delist(auction), Err(MarketplaceError::Unauthorized));
+
+            let bob_before = test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            market.delist(auction).unwrap();
+            assert_eq!(
+                test::get_account_balance::<DefaultEnvironment>(accounts.bob).unwrap(),
+                bob_before + 1_200
+            );
+            assert!(!market.get_listing(auction).unwrap().active);
+            assert_eq!(market.get_active_listing(7), None);
+            assert_eq!(
+                market.delist(auction),
+                Err(MarketplaceError::ListingNotFound)
+            );
+        }
+
+        #[ink::test]
+        fn settle_without_bids_closes_the_auction() {
+            let mut market = setup();
+            let auction = seed(&mut market, ListingKind::Auction { ends_at: 1 });
+            test::advance_block::<DefaultEnvironment>();
+            market.settle(auction).unwrap();
+            assert!(!market.get_listing(auction).unwrap().active);
+        }
+    }
+}
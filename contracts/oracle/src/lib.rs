@@ -23,7 +23,7 @@ mod aggregation;
 
 /// Property Valuation Oracle Contract
 #[ink::contract]
-mod propchain_oracle {
+pub mod propchain_oracle {
     use super::*;
     include!("types.rs");
     use ink::prelude::{
@@ -61,8 +61,33 @@ mod propchain_oracle {
         /// Property valuations storage
         pub property_valuations: Mapping<u64, PropertyValuation>,
 
-        /// Historical valuations per property
-        historical_valuations: Mapping<u64, Vec<PropertyValuation>>,
+        /// Historical valuations per property, as a ring buffer sized by the
+        /// property's cursor: (property_id, slot) -> valuation
+        historical_valuations: Mapping<(u64, u32), PropertyValuation>,
+
+        /// Ring buffer position per property
+        history_cursors: Mapping<u64, HistoryCursor>,
+
+        /// Valuations kept per property without an override
+        default_history_depth: u32,
+
+        /// Per-property history depth overrides
+        history_depth_overrides: Mapping<u64, u32>,
+
+        /// Per-source breakdown of source-aggregated valuations among the
+        /// newest `VALUATION_BREAKDOWN_CAPACITY` history entries:
+        /// (property_id, history slot % capacity) -> breakdown
+        valuation_breakdowns: Mapping<(u64, u32), ValuationBreakdown>,
+
+        /// Rental data history per property, oldest first
+        rental_history: Mapping<u64, Vec<RentalData>>,
+
+        /// Municipal tax assessments per property, ordered by tax year
+        assessments: Mapping<u64, Vec<TaxAssessment>>,
+
+        /// Whether the latest assessment stands in as a low-weight source
+        /// when too few live sources respond
+        assessment_fallback_enabled: bool,
 
         /// Oracle sources configuration
         oracle_sources: Mapping<String, OracleSource>,
@@ -70,8 +95,21 @@ mod propchain_oracle {
         /// Active oracle sources list
         pub active_sources: Vec<String>,
 
+        /// Percentage alerts stored before alert kinds existed. Read as
+        /// `AlertKind::PercentChange` and moved to `alerts` on the next write.
+        pub price_alerts: Mapping<u64, Vec<LegacyPriceAlert>>,
+
         /// Price alerts configuration
-        pub price_alerts: Mapping<u64, Vec<PriceAlert>>,
+        alerts: Mapping<u64, Vec<PriceAlert>>,
+
+        /// Portfolio alert subscriptions by ID
+        portfolio_alerts: Mapping<u64, PortfolioAlert>,
+        /// Portfolio alerts evaluated on admin valuation updates
+        active_portfolio_alerts: Vec<u64>,
+        /// Portfolio alert IDs per subscriber
+        subscriber_portfolio_alerts: Mapping<AccountId, Vec<u64>>,
+        /// Number of portfolio alerts created; the next alert's ID
+        portfolio_alert_count: u64,
 
         /// Location-based adjustments
         pub location_adjustments: Mapping<String, LocationAdjustment>,
@@ -79,15 +117,24 @@ mod propchain_oracle {
         /// Market trends data
         pub market_trends: Mapping<String, MarketTrend>,
 
+        /// Location each property is indexed under
+        property_locations: Mapping<u64, String>,
+
+        /// Property IDs indexed under each location, in registration order
+        properties_by_location: Mapping<String, Vec<u64>>,
+
         /// Per-property trend metrics cache
         property_trends: Mapping<u64, TrendMetrics>,
 
         /// Configurable EMA smoothing factor in basis points (0-10000)
         ema_alpha_bps: u32,
 
-        /// Comparable properties cache
+        /// Comparable properties cache, oldest first
         comparable_cache: Mapping<u64, Vec<ComparableProperty>>,
 
+        /// Maximum comparables cached per property
+        comparable_retention: u32,
+
         /// Maximum staleness for price feeds (in seconds)
         max_price_staleness: u64,
 
@@ -111,6 +158,22 @@ mod propchain_oracle {
 
         /// AI valuation contract address
         ai_valuation_contract: Option<AccountId>,
+        /// Registry contract allowed to pin valuations alongside the admin
+        registry_contract: Option<AccountId>,
+        /// Registry pushed admin valuation updates that move by at least the
+        /// given basis points: (registry, min_change_bp)
+        registry_callback: Option<(AccountId, u32)>,
+        /// Flag a valuation collapse to the callback registry when a property
+        /// falls by more than the given basis points from its peak within
+        /// the window: (drop_bp, window_ms)
+        collapse_threshold: Option<(u32, u64)>,
+        /// Highest valuation per property since the collapse window last
+        /// restarted: (valuation, recorded_at)
+        valuation_peaks: Mapping<u64, (u128, u64)>,
+        /// Pinned valuations: (property_id, pin index) -> pin. Never overwritten.
+        pinned_valuations: Mapping<(u64, u32), PinnedValuation>,
+        /// Number of pins per property
+        pinned_valuation_counts: Mapping<u64, u32>,
         /// Maximum batch size for batch operations
         max_batch_size: u32,
 
@@ -245,6 +308,70 @@ mod propchain_oracle {
         timestamp: u64,
     }
 
+    #[ink(event)]
+    pub struct RentalDataUpdated {
+        #[ink(topic)]
+        property_id: u64,
+        monthly_rent: u128,
+        occupancy_bp: u32,
+        timestamp: u64,
+    }
+
+    #[ink(event)]
+    pub struct AssessmentSubmitted {
+        #[ink(topic)]
+        property_id: u64,
+        assessed_value: u128,
+        tax_year: u32,
+        authority: String,
+    }
+
+    #[ink(event)]
+    pub struct RegistryCallbackFailed {
+        #[ink(topic)]
+        property_id: u64,
+        registry: AccountId,
+        valuation: u128,
+    }
+
+    #[ink(event)]
+    pub struct ValuationCollapseDetected {
+        #[ink(topic)]
+        property_id: u64,
+        peak: u128,
+        valuation: u128,
+        drop_bp: u32,
+    }
+
+    #[ink(event)]
+    pub struct ValuationPinned {
+        #[ink(topic)]
+        property_id: u64,
+        pin_index: u32,
+        valuation: u128,
+        label: String,
+        block_number: u32,
+    }
+
+    #[ink(event)]
+    pub struct AggregationVerified {
+        #[ink(topic)]
+        property_id: u64,
+        valuation_index: u32,
+        /// Whether the claimed inputs reproduce the stored valuation
+        verified: bool,
+        #[ink(topic)]
+        verified_by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct HistoryArchived {
+        #[ink(topic)]
+        property_id: u64,
+        /// Pruned valuations, oldest first
+        entries: Vec<PropertyValuation>,
+    }
+
     #[ink(event)]
     pub struct PriceAlertTriggered {
         #[ink(topic)]
@@ -253,6 +380,19 @@ mod propchain_oracle {
         new_valuation: u128,
         change_percentage: u32,
         alert_address: AccountId,
+        kind: AlertKind,
+    }
+
+    /// Properties in a portfolio that moved by at least the subscription's
+    /// threshold since it last fired.
+    #[ink(event)]
+    pub struct PortfolioAlertTriggered {
+        #[ink(topic)]
+        alert_id: u64,
+        #[ink(topic)]
+        owner_hint: AccountId,
+        alert_address: AccountId,
+        property_ids: Vec<u64>,
     }
 
     #[ink(event)]
@@ -522,14 +662,29 @@ mod propchain_oracle {
                 aggregation_mode: AggregationMode::default(),
                 property_valuations: Mapping::default(),
                 historical_valuations: Mapping::default(),
+                history_cursors: Mapping::default(),
+                default_history_depth: VALUATION_HISTORY_CAPACITY,
+                history_depth_overrides: Mapping::default(),
+                valuation_breakdowns: Mapping::default(),
+                rental_history: Mapping::default(),
+                assessments: Mapping::default(),
+                assessment_fallback_enabled: true,
                 oracle_sources: Mapping::default(),
                 active_sources: Vec::new(),
                 price_alerts: Mapping::default(),
+                alerts: Mapping::default(),
+                portfolio_alerts: Mapping::default(),
+                active_portfolio_alerts: Vec::new(),
+                subscriber_portfolio_alerts: Mapping::default(),
+                portfolio_alert_count: 0,
                 location_adjustments: Mapping::default(),
+                property_locations: Mapping::default(),
+                properties_by_location: Mapping::default(),
                 market_trends: Mapping::default(),
                 property_trends: Mapping::default(),
                 ema_alpha_bps: 1000, // Default alpha = 0.10
                 comparable_cache: Mapping::default(),
+                comparable_retention: DEFAULT_COMPARABLE_RETENTION,
                 max_price_staleness: propchain_traits::constants::DEFAULT_MAX_PRICE_STALENESS,
                 min_sources_required: propchain_traits::constants::DEFAULT_MIN_SOURCES_REQUIRED,
                 outlier_threshold: propchain_traits::constants::DEFAULT_OUTLIER_THRESHOLD,
@@ -538,6 +693,12 @@ mod propchain_oracle {
                 pending_requests: Mapping::default(),
                 request_id_counter: 0,
                 ai_valuation_contract: None,
+                registry_contract: None,
+                registry_callback: None,
+                collapse_threshold: None,
+                valuation_peaks: Mapping::default(),
+                pinned_valuations: Mapping::default(),
+                pinned_valuation_counts: Mapping::default(),
                 max_batch_size: 50,
                 min_update_interval_blocks: 6, // ~36 seconds at 6s blocks
                 last_source_update: Mapping::default(),
@@ -651,7 +812,9 @@ mod propchain_oracle {
 
             // Calculate volatility and confidence interval
             let volatility = self.calculate_volatility(property_id)?;
-            let confidence_interval = self.calculate_confidence_interval(&valuation)?;
+            let staleness_penalty = self.calculate_staleness_penalty(&valuation);
+            let confidence_interval =
+                self.calculate_confidence_interval(&valuation, staleness_penalty)?;
             let outlier_sources = self.detect_outliers(property_id)?;
 
             Ok(ValuationWithConfidence {
@@ -659,9 +822,159 @@ mod propchain_oracle {
                 volatility_index: volatility,
                 confidence_interval,
                 outlier_sources,
+                yield_metrics: self.get_yield_metrics(property_id).ok(),
+                staleness_penalty,
+            })
+        }
+
+        /// Submit rental data for a property (admin only). Keeps as many
+        /// records as the property's history depth, dropping the oldest.
+        #[ink(message)]
+        pub fn submit_rental_data(
+            &mut self,
+            property_id: u64,
+            monthly_rent: u128,
+            occupancy_bp: u32,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if occupancy_bp > 10_000 {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            let timestamp = self.env().block_timestamp();
+            let mut history = self.rental_history.get(&property_id).unwrap_or_default();
+            history.push(RentalData {
+                monthly_rent,
+                occupancy_bp,
+                recorded_at: timestamp,
+            });
+            // Rental records follow the property's valuation history depth.
+            let depth = self.get_history_depth(property_id) as usize;
+            if history.len() > depth {
+                history.drain(..history.len() - depth);
+            }
+            self.rental_history.insert(&property_id, &history);
+
+            self.env().emit_event(RentalDataUpdated {
+                property_id,
+                monthly_rent,
+                occupancy_bp,
+                timestamp,
+            });
+            Ok(())
+        }
+
+        /// Get rental data history for a property, most recent first
+        #[ink(message)]
+        pub fn get_rental_history(&self, property_id: u64, limit: u32) -> Vec<RentalData> {
+            self.rental_history
+                .get(&property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .rev()
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Get the gross yield of the latest rent against the latest valuation.
+        ///
+        /// Fails with `PropertyNotFound` if no rental data was submitted and
+        /// with `InvalidValuation` if the property has no valuation to
+        /// measure against.
+        #[ink(message)]
+        pub fn get_yield_metrics(&self, property_id: u64) -> Result<YieldMetrics, OracleError> {
+            let rental = self
+                .rental_history
+                .get(&property_id)
+                .and_then(|history| history.last().cloned())
+                .ok_or(OracleError::PropertyNotFound)?;
+            let valuation = self
+                .property_valuations
+                .get(&property_id)
+                .map(|valuation| valuation.valuation)
+                .filter(|&valuation| valuation > 0)
+                .ok_or(OracleError::InvalidValuation)?;
+
+            let gross_yield_bp = rental.monthly_rent.saturating_mul(12 * 10_000) / valuation;
+            Ok(YieldMetrics {
+                gross_yield_bp: u32::try_from(gross_yield_bp).unwrap_or(u32::MAX),
+                occupancy_bp: rental.occupancy_bp,
+                last_updated: rental.recorded_at,
             })
         }
 
+        /// Submit a municipal tax assessment for a property (admin only).
+        ///
+        /// A later submission for the same tax year replaces the earlier one.
+        #[ink(message)]
+        pub fn submit_assessment(
+            &mut self,
+            property_id: u64,
+            assessed_value: u128,
+            tax_year: u32,
+            authority: String,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if assessed_value == 0 {
+                return Err(OracleError::InvalidValuation);
+            }
+            if authority.is_empty() {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            let assessment = TaxAssessment {
+                assessed_value,
+                tax_year,
+                authority: authority.clone(),
+                recorded_at: self.env().block_timestamp(),
+            };
+            let mut assessments = self.assessments.get(&property_id).unwrap_or_default();
+            match assessments.binary_search_by_key(&tax_year, |a| a.tax_year) {
+                Ok(index) => assessments[index] = assessment,
+                Err(index) => assessments.insert(index, assessment),
+            }
+            self.assessments.insert(&property_id, &assessments);
+
+            self.env().emit_event(AssessmentSubmitted {
+                property_id,
+                assessed_value,
+                tax_year,
+                authority,
+            });
+            Ok(())
+        }
+
+        /// Get assessments for tax years in `from_year..=to_year`, oldest first
+        #[ink(message)]
+        pub fn get_assessments(
+            &self,
+            property_id: u64,
+            from_year: u32,
+            to_year: u32,
+        ) -> Vec<TaxAssessment> {
+            self.assessments
+                .get(&property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|a| a.tax_year >= from_year && a.tax_year <= to_year)
+                .collect()
+        }
+
+        /// Enable or disable blending the latest assessment into aggregation
+        /// when fewer than `min_sources_required` live sources respond (admin only).
+        #[ink(message)]
+        pub fn set_assessment_fallback(&mut self, enabled: bool) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.assessment_fallback_enabled = enabled;
+            Ok(())
+        }
+
+        /// Returns whether assessments are blended in as a fallback source.
+        #[ink(message)]
+        pub fn is_assessment_fallback_enabled(&self) -> bool {
+            self.assessment_fallback_enabled
+        }
+
         /// Update property valuation (admin only)
         #[ink(message)]
         pub fn update_property_valuation(
@@ -719,12 +1032,17 @@ mod propchain_oracle {
                 valuation.confidence_score,
             );
 
+            // Check price alerts against the valuation being replaced
+            self.check_price_alerts(property_id, valuation.valuation)?;
+
+            let previous = self
+                .property_valuations
+                .get(&property_id)
+                .map(|existing| existing.valuation);
+
             // Update current valuation
             self.property_valuations.insert(&property_id, &valuation);
 
-            // Check price alerts
-            self.check_price_alerts(property_id, valuation.valuation)?;
-
             // Emit event
             self.env().emit_event(ValuationUpdated {
                 property_id,
@@ -734,6 +1052,9 @@ mod propchain_oracle {
             });
 
             self.update_trend_metrics(property_id);
+            self.check_portfolio_alerts(property_id, previous, valuation.valuation);
+            self.notify_registry(property_id, previous, &valuation);
+            self.check_valuation_collapse(property_id, valuation.valuation);
 
             Ok(())
         }
@@ -1163,7 +1484,14 @@ mod propchain_oracle {
             property_id: u64,
         ) -> Result<(), OracleError> {
             // Collect prices from all active sources
-            let prices = self.collect_prices_from_sources(property_id)?;
+            let mut prices = self.collect_prices_from_sources(property_id)?;
+
+            // Too few live sources: let the latest assessment stand in.
+            if prices.len() < self.min_sources_required as usize {
+                if let Some(assessment) = self.assessment_price(property_id) {
+                    prices.push(assessment);
+                }
+            }
 
             if prices.len() < self.min_sources_required as usize {
                 return Err(OracleError::InsufficientSources);
@@ -1172,6 +1500,7 @@ mod propchain_oracle {
             // Aggregate prices with outlier detection
             let aggregated_price = self.aggregate_prices(&prices)?;
             let confidence_score = self.calculate_confidence_score(&prices)?;
+            let contributions = self.source_contributions(&prices);
 
             let now = self.env().block_timestamp();
 
@@ -1208,10 +1537,142 @@ mod propchain_oracle {
             };
 
             self.update_property_valuation(property_id, valuation)?;
+            self.record_valuation_breakdown(
+                property_id,
+                ValuationBreakdown {
+                    property_id,
+                    valuation: aggregated_price,
+                    recorded_at: now,
+                    contributions,
+                    aggregation_method: self.aggregation_method.clone(),
+                    outlier_threshold: self.outlier_threshold,
+                },
+            );
             self.clear_pending_request(property_id);
             Ok(())
         }
 
+        /// Returns which source reported what for one of the newest
+        /// `VALUATION_BREAKDOWN_CAPACITY` valuations of a property
+        /// (`valuation_index` 0 is the latest). Restricted to the oracle
+        /// admin and auditors, since source prices are commercially
+        /// sensitive.
+        ///
+        /// Fails with `PropertyNotFound` when the valuation at that index was
+        /// set directly rather than aggregated from sources, or has aged out.
+        #[ink(message)]
+        pub fn get_valuation_breakdown(
+            &self,
+            property_id: u64,
+            valuation_index: u32,
+        ) -> Result<ValuationBreakdown, OracleError> {
+            let caller = self.env().caller();
+            if !self.access_control.has_role(caller, Role::Auditor)
+                && !self.access_control.has_role(caller, Role::OracleAdmin)
+            {
+                return Err(OracleError::Unauthorized);
+            }
+            let cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            if valuation_index >= cursor.len.min(VALUATION_BREAKDOWN_CAPACITY) {
+                return Err(OracleError::PropertyNotFound);
+            }
+            self.valuation_breakdowns
+                .get(&(
+                    property_id,
+                    Self::breakdown_key(Self::history_slot(cursor, valuation_index)),
+                ))
+                .ok_or(OracleError::PropertyNotFound)
+        }
+
+        /// Re-runs outlier filtering and aggregation over `claimed_inputs`
+        /// and reports whether the result exactly equals the valuation stored
+        /// at `valuation_index` (0 is the latest). Uses the method, threshold
+        /// and weights recorded with the valuation while its breakdown is
+        /// kept, and the current ones after that. Open to anyone; emits
+        /// `AggregationVerified`.
+        #[ink(message)]
+        pub fn verify_aggregation(
+            &mut self,
+            property_id: u64,
+            valuation_index: u32,
+            claimed_inputs: Vec<PriceData>,
+        ) -> bool {
+            let cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            let slot = Self::history_slot(cursor, valuation_index);
+            let stored = if valuation_index < cursor.len {
+                self.historical_valuations
+                    .get(&(property_id, slot))
+                    .map(|entry| entry.valuation)
+            } else {
+                None
+            };
+            let recorded = if valuation_index < VALUATION_BREAKDOWN_CAPACITY {
+                self.valuation_breakdowns
+                    .get(&(property_id, Self::breakdown_key(slot)))
+            } else {
+                None
+            };
+
+            let recomputed = match recorded.filter(|_| stored.is_some()) {
+                Some(breakdown) => Self::aggregate_prices_with(
+                    &breakdown.aggregation_method,
+                    breakdown.outlier_threshold,
+                    &claimed_inputs,
+                    |source| {
+                        breakdown
+                            .contributions
+                            .iter()
+                            .find(|contribution| contribution.source_id == source)
+                            .map(|contribution| contribution.weight)
+                            .ok_or(OracleError::OracleSourceNotFound)
+                    },
+                ),
+                None => Self::aggregate_prices_with(
+                    &self.aggregation_method,
+                    self.outlier_threshold,
+                    &claimed_inputs,
+                    |source| self.get_source_weight(source),
+                ),
+            };
+            let verified = stored.is_some() && recomputed.ok() == stored;
+
+            self.env().emit_event(AggregationVerified {
+                property_id,
+                valuation_index,
+                verified,
+                verified_by: self.env().caller(),
+            });
+            verified
+        }
+
+        /// Admin: allow `account` to read valuation breakdowns.
+        #[ink(message)]
+        pub fn add_auditor(&mut self, account: AccountId) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            let caller = self.env().caller();
+            let (block_number, now) = (self.env().block_number(), self.env().block_timestamp());
+            self.access_control
+                .grant_role(caller, account, Role::Auditor, block_number, now)
+                .map_err(|_| OracleError::Unauthorized)
+        }
+
+        /// Admin: withdraw `account`'s access to valuation breakdowns.
+        #[ink(message)]
+        pub fn remove_auditor(&mut self, account: AccountId) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            let caller = self.env().caller();
+            let (block_number, now) = (self.env().block_number(), self.env().block_timestamp());
+            self.access_control
+                .revoke_role(caller, account, Role::Auditor, block_number, now)
+                .map_err(|_| OracleError::Unauthorized)
+        }
+
+        /// Whether `account` holds the auditor role.
+        #[ink(message)]
+        pub fn is_auditor(&self, account: AccountId) -> bool {
+            self.access_control.has_role(account, Role::Auditor)
+        }
+
         /// Request a new valuation for a property
         #[ink(message)]
         pub fn request_property_valuation(&mut self, property_id: u64) -> Result<u64, OracleError> {
@@ -1564,13 +2025,51 @@ mod propchain_oracle {
             property_id: u64,
             limit: u32,
         ) -> Vec<PropertyValuation> {
-            self.historical_valuations
-                .get(&property_id)
-                .unwrap_or_default()
-                .into_iter()
-                .rev() // Most recent first
-                .take(limit as usize)
-                .collect()
+            self.history_newest_first(property_id, limit)
+        }
+
+        /// Prune valuations older than the history retention period (admin
+        /// only). The pruned entries are emitted in `HistoryArchived` for
+        /// off-chain archival before they are deleted; returns their number.
+        #[ink(message)]
+        pub fn archive_history(&mut self, property_id: u64) -> Result<u32, OracleError> {
+            self.ensure_admin()?;
+            let mut cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            let cutoff = self
+                .env()
+                .block_timestamp()
+                .saturating_sub(self.history_retention_ms);
+
+            let mut entries = Vec::new();
+            while cursor.len > 0 {
+                let slot = Self::history_slot(cursor, cursor.len - 1);
+                match self.historical_valuations.get(&(property_id, slot)) {
+                    Some(entry) if entry.last_updated < cutoff => {
+                        self.historical_valuations.remove(&(property_id, slot));
+                        self.valuation_breakdowns
+                            .remove(&(property_id, Self::breakdown_key(slot)));
+                        entries.push(entry);
+                        cursor.len -= 1;
+                    }
+                    _ => break,
+                }
+            }
+
+            if entries.is_empty() {
+                return Ok(0);
+            }
+            if cursor.len == 0 {
+                self.history_cursors.remove(&property_id);
+            } else {
+                self.history_cursors.insert(&property_id, &cursor);
+            }
+
+            let archived = entries.len() as u32;
+            self.env().emit_event(HistoryArchived {
+                property_id,
+                entries,
+            });
+            Ok(archived)
         }
 
         /// Get market volatility metrics
@@ -1594,28 +2093,131 @@ mod propchain_oracle {
                 .ok_or(OracleError::InvalidParameters)
         }
 
-        /// Set price alert for a property
+        /// Set a percentage-change price alert for a property
         #[ink(message)]
         pub fn set_price_alert(
             &mut self,
             property_id: u64,
             threshold_percentage: u32,
             alert_address: AccountId,
+        ) -> Result<(), OracleError> {
+            self.add_price_alert(
+                property_id,
+                AlertKind::PercentChange {
+                    bp: threshold_percentage.saturating_mul(100),
+                },
+                alert_address,
+            )
+        }
+
+        /// Add a price alert of any kind for a property
+        #[ink(message)]
+        pub fn add_price_alert(
+            &mut self,
+            property_id: u64,
+            kind: AlertKind,
+            alert_address: AccountId,
         ) -> Result<(), OracleError> {
             let alert = PriceAlert {
                 property_id,
-                threshold_percentage,
+                kind,
                 alert_address,
                 last_triggered: 0,
                 is_active: true,
+                level_crossed: false,
             };
 
-            let mut alerts = self.price_alerts.get(&property_id).unwrap_or_default();
+            let mut alerts = self.load_alerts(property_id);
             alerts.push(alert);
-            self.price_alerts.insert(&property_id, &alerts);
+            self.store_alerts(property_id, &alerts);
+
+            Ok(())
+        }
+
+        /// Get all price alerts for a property
+        #[ink(message)]
+        pub fn get_alerts(&self, property_id: u64) -> Vec<PriceAlert> {
+            self.load_alerts(property_id)
+        }
 
+        /// Subscribe `alert_address` to valuation moves of at least
+        /// `threshold_bp` on any property `owner_hint` holds. The owner's
+        /// property list is resolved through the linked registry whenever a
+        /// move is large enough to matter, and breaches are reported together
+        /// in one `PortfolioAlertTriggered` at most once per
+        /// `PORTFOLIO_ALERT_COOLDOWN_MS`. Requires a linked registry.
+        #[ink(message)]
+        pub fn set_portfolio_alert(
+            &mut self,
+            owner_hint: AccountId,
+            threshold_bp: u32,
+            alert_address: AccountId,
+        ) -> Result<u64, OracleError> {
+            if self.linked_registry().is_none()
+                || threshold_bp == 0
+                || self.active_portfolio_alerts.len() >= MAX_ACTIVE_PORTFOLIO_ALERTS
+            {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            let subscriber = self.env().caller();
+            let alert_id = self.portfolio_alert_count;
+            self.portfolio_alert_count += 1;
+            self.portfolio_alerts.insert(
+                &alert_id,
+                &PortfolioAlert {
+                    alert_id,
+                    subscriber,
+                    owner_hint,
+                    threshold_bp,
+                    alert_address,
+                    last_triggered: 0,
+                    is_active: true,
+                    pending_property_ids: Vec::new(),
+                },
+            );
+            self.active_portfolio_alerts.push(alert_id);
+            let mut ids = self
+                .subscriber_portfolio_alerts
+                .get(&subscriber)
+                .unwrap_or_default();
+            ids.push(alert_id);
+            self.subscriber_portfolio_alerts.insert(&subscriber, &ids);
+            Ok(alert_id)
+        }
+
+        /// Stop evaluating a portfolio alert (subscriber or admin only)
+        #[ink(message)]
+        pub fn deactivate_portfolio_alert(&mut self, alert_id: u64) -> Result<(), OracleError> {
+            let mut alert = self
+                .portfolio_alerts
+                .get(&alert_id)
+                .ok_or(OracleError::AlertNotFound)?;
+            if alert.subscriber != self.env().caller() {
+                self.ensure_admin()?;
+            }
+            alert.is_active = false;
+            self.portfolio_alerts.insert(&alert_id, &alert);
+            self.active_portfolio_alerts.retain(|id| *id != alert_id);
             Ok(())
         }
+
+        /// Get a portfolio alert by ID
+        #[ink(message)]
+        pub fn get_portfolio_alert(&self, alert_id: u64) -> Option<PortfolioAlert> {
+            self.portfolio_alerts.get(&alert_id)
+        }
+
+        /// Get every portfolio alert `subscriber` created, active or not
+        #[ink(message)]
+        pub fn get_portfolio_alerts(&self, subscriber: AccountId) -> Vec<PortfolioAlert> {
+            self.subscriber_portfolio_alerts
+                .get(&subscriber)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|alert_id| self.portfolio_alerts.get(&alert_id))
+                .collect()
+        }
         /// Set AI valuation contract address
         #[ink(message)]
         pub fn set_ai_valuation_contract(
@@ -1633,16 +2235,165 @@ mod propchain_oracle {
             self.ai_valuation_contract
         }
 
-        // ── Fallback Mechanism API (Issue #220) ───────────────────────────────
-
-        /// Update the fallback configuration (admin only).
+        /// Set the registry contract allowed to pin valuations (admin only)
         #[ink(message)]
-        pub fn set_fallback_config(&mut self, config: FallbackConfig) -> Result<(), OracleError> {
+        pub fn set_registry_contract(&mut self, registry: AccountId) -> Result<(), OracleError> {
             self.ensure_admin()?;
-            self.fallback_config = config;
-            self.env().emit_event(FallbackConfigUpdated {
-                enabled: self.fallback_config.enabled,
-                fallback_delay_blocks: self.fallback_config.fallback_delay_blocks,
+            self.registry_contract = Some(registry);
+            Ok(())
+        }
+
+        /// Get the registry contract allowed to pin valuations
+        #[ink(message)]
+        pub fn get_registry_contract(&self) -> Option<AccountId> {
+            self.registry_contract
+        }
+
+        /// Push admin valuation updates to `registry` whenever they move the
+        /// valuation by at least `min_change_bp` basis points (admin only).
+        /// A property's first valuation is always pushed.
+        #[ink(message)]
+        pub fn set_registry_callback(
+            &mut self,
+            registry: AccountId,
+            min_change_bp: u32,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if min_change_bp > 10_000 {
+                return Err(OracleError::InvalidParameters);
+            }
+            self.registry_callback = Some((registry, min_change_bp));
+            Ok(())
+        }
+
+        /// Stop pushing valuation updates to the registry (admin only)
+        #[ink(message)]
+        pub fn clear_registry_callback(&mut self) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.registry_callback = None;
+            Ok(())
+        }
+
+        /// Get the registry valuation updates are pushed to and the minimum
+        /// change in basis points
+        #[ink(message)]
+        pub fn get_registry_callback(&self) -> Option<(AccountId, u32)> {
+            self.registry_callback
+        }
+
+        /// Flag a `ValuationCollapse` to the callback registry whenever an
+        /// admin valuation update leaves a property more than `drop_bp` basis
+        /// points below its peak within the last `window_ms` (admin only).
+        #[ink(message)]
+        pub fn set_collapse_threshold(
+            &mut self,
+            drop_bp: u32,
+            window_ms: u64,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if drop_bp == 0 || drop_bp > 10_000 || window_ms == 0 {
+                return Err(OracleError::InvalidParameters);
+            }
+            self.collapse_threshold = Some((drop_bp, window_ms));
+            Ok(())
+        }
+
+        /// Stop flagging valuation collapses (admin only)
+        #[ink(message)]
+        pub fn clear_collapse_threshold(&mut self) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.collapse_threshold = None;
+            Ok(())
+        }
+
+        /// Get the collapse threshold in basis points and its window
+        #[ink(message)]
+        pub fn get_collapse_threshold(&self) -> Option<(u32, u64)> {
+            self.collapse_threshold
+        }
+
+        /// Pin the current valuation of a property under a label (admin or
+        /// registry contract). Pins are append-only; returns the pin index.
+        #[ink(message)]
+        pub fn pin_valuation(
+            &mut self,
+            property_id: u64,
+            label: String,
+        ) -> Result<u32, OracleError> {
+            let caller = self.env().caller();
+            if self.registry_contract != Some(caller) {
+                self.ensure_admin()?;
+            }
+            if label.is_empty()
+                || label.len() > propchain_traits::constants::MAX_PIN_LABEL_LENGTH as usize
+            {
+                return Err(OracleError::InvalidParameters);
+            }
+            let valuation = self
+                .property_valuations
+                .get(&property_id)
+                .ok_or(OracleError::PropertyNotFound)?;
+
+            let pin_index = self.pinned_valuation_counts.get(&property_id).unwrap_or(0);
+            if self.pinned_valuations.contains(&(property_id, pin_index)) {
+                return Err(OracleError::AlreadyExists);
+            }
+
+            let block_number = self.env().block_number();
+            self.env().emit_event(ValuationPinned {
+                property_id,
+                pin_index,
+                valuation: valuation.valuation,
+                label: label.clone(),
+                block_number,
+            });
+            self.pinned_valuations.insert(
+                &(property_id, pin_index),
+                &PinnedValuation {
+                    valuation,
+                    label,
+                    block_number,
+                    pinned_at: self.env().block_timestamp(),
+                    pinned_by: caller,
+                },
+            );
+            self.pinned_valuation_counts
+                .insert(&property_id, &pin_index.saturating_add(1));
+            Ok(pin_index)
+        }
+
+        /// Page through the pinned valuations of a property, oldest first.
+        /// `limit` is capped at the batch size.
+        #[ink(message)]
+        pub fn get_pinned_valuations(
+            &self,
+            property_id: u64,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<PinnedValuation> {
+            let count = self.get_pinned_valuation_count(property_id);
+            let end = offset.saturating_add(limit.min(self.max_batch_size)).min(count);
+            (offset..end)
+                .filter_map(|index| self.pinned_valuations.get(&(property_id, index)))
+                .collect()
+        }
+
+        /// Number of valuations pinned for a property
+        #[ink(message)]
+        pub fn get_pinned_valuation_count(&self, property_id: u64) -> u32 {
+            self.pinned_valuation_counts.get(&property_id).unwrap_or(0)
+        }
+
+        // ── Fallback Mechanism API (Issue #220) ───────────────────────────────
+
+        /// Update the fallback configuration (admin only).
+        #[ink(message)]
+        pub fn set_fallback_config(&mut self, config: FallbackConfig) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            self.fallback_config = config;
+            self.env().emit_event(FallbackConfigUpdated {
+                enabled: self.fallback_config.enabled,
+                fallback_delay_blocks: self.fallback_config.fallback_delay_blocks,
                 max_fallback_attempts: self.fallback_config.max_fallback_attempts,
             });
             Ok(())
@@ -2325,6 +3076,146 @@ mod propchain_oracle {
             Ok(())
         }
 
+        /// Index a property under `location` for market reports (admin only).
+        /// Re-registering a property moves it out of its previous location.
+        #[ink(message)]
+        pub fn register_property_location(
+            &mut self,
+            property_id: u64,
+            location: String,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if location.is_empty()
+                || location.len() > propchain_traits::constants::MAX_LOCATION_LENGTH as usize
+            {
+                return Err(OracleError::InvalidParameters);
+            }
+
+            if let Some(previous) = self.property_locations.get(&property_id) {
+                if previous == location {
+                    return Ok(());
+                }
+                let mut ids = self
+                    .properties_by_location
+                    .get(&previous)
+                    .unwrap_or_default();
+                ids.retain(|id| *id != property_id);
+                if ids.is_empty() {
+                    self.properties_by_location.remove(&previous);
+                } else {
+                    self.properties_by_location.insert(&previous, &ids);
+                }
+            }
+
+            let mut ids = self
+                .properties_by_location
+                .get(&location)
+                .unwrap_or_default();
+            ids.push(property_id);
+            self.properties_by_location.insert(&location, &ids);
+            self.property_locations.insert(property_id, &location);
+            Ok(())
+        }
+
+        /// Location a property is indexed under, if any
+        #[ink(message)]
+        pub fn get_property_location(&self, property_id: u64) -> Option<String> {
+            self.property_locations.get(&property_id)
+        }
+
+        /// Page through the properties in `location` that have a valuation.
+        /// `limit` is capped at the batch size.
+        #[ink(message)]
+        pub fn get_valued_properties_in(
+            &self,
+            location: String,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<u64> {
+            self.properties_by_location
+                .get(&location)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|id| self.property_valuations.contains(id))
+                .skip(offset as usize)
+                .take(limit.min(self.max_batch_size) as usize)
+                .collect()
+        }
+
+        /// Median valuation and mean confidence over the first
+        /// `LOCATION_STATS_SAMPLE` valued properties in `location`
+        #[ink(message)]
+        pub fn get_location_stats(&self, location: String) -> LocationStats {
+            let mut valuations: Vec<PropertyValuation> = self
+                .properties_by_location
+                .get(&location)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|id| self.property_valuations.get(&id))
+                .take(LOCATION_STATS_SAMPLE as usize)
+                .collect();
+            if valuations.is_empty() {
+                return LocationStats::default();
+            }
+
+            valuations.sort_by_key(|v| v.valuation);
+            let count = valuations.len();
+            let mid = count / 2;
+            let median_valuation = if count % 2 == 0 {
+                let (low, high) = (valuations[mid - 1].valuation, valuations[mid].valuation);
+                low / 2 + high / 2 + (low % 2 + high % 2) / 2
+            } else {
+                valuations[mid].valuation
+            };
+            let total_confidence: u64 = valuations
+                .iter()
+                .map(|v| u64::from(v.confidence_score))
+                .sum();
+
+            LocationStats {
+                count: count as u32,
+                median_valuation,
+                avg_confidence: (total_confidence / count as u64) as u32,
+            }
+        }
+
+        /// Cache a comparable property for AVM analysis (admin only). The
+        /// oldest comparables are dropped beyond the retention limit.
+        #[ink(message)]
+        pub fn add_comparable_property(
+            &mut self,
+            property_id: u64,
+            comparable: ComparableProperty,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            let mut comparables = self.comparable_cache.get(&property_id).unwrap_or_default();
+            comparables.push(comparable);
+            let excess = comparables
+                .len()
+                .saturating_sub(self.comparable_retention as usize);
+            comparables.drain(..excess);
+            self.comparable_cache.insert(&property_id, &comparables);
+            Ok(())
+        }
+
+        /// Set how many comparables are cached per property (admin only).
+        /// Takes effect on each property's next insert.
+        #[ink(message)]
+        pub fn set_comparable_retention(&mut self, max_entries: u32) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            if max_entries == 0 || max_entries > MAX_COMPARABLE_RETENTION {
+                return Err(OracleError::InvalidParameters);
+            }
+            self.comparable_retention = max_entries;
+            Ok(())
+        }
+
+        /// Get how many comparables are cached per property
+        #[ink(message)]
+        pub fn get_comparable_retention(&self) -> u32 {
+            self.comparable_retention
+        }
+
         /// Get comparable properties for AVM analysis
         #[ink(message)]
         pub fn get_comparable_properties(
@@ -2633,19 +3524,31 @@ mod propchain_oracle {
             Err(OracleError::PriceFeedError)
         }
 
+        /// The latest tax assessment as a synthetic price, if the fallback is
+        /// enabled. Assessments are annual, so no staleness check applies.
+        fn assessment_price(&self, property_id: u64) -> Option<PriceData> {
+            if !self.assessment_fallback_enabled {
+                return None;
+            }
+            let latest = self.assessments.get(&property_id)?.pop()?;
+            Some(PriceData {
+                price: latest.assessed_value,
+                timestamp: latest.recorded_at,
+                source: String::from(propchain_traits::constants::ASSESSMENT_SOURCE_ID),
+            })
+        }
+
         /// Retrieve the most recent manually-submitted price for a property.
         /// Converts from PropertyValuation (storage format) to PriceData (oracle format).
         fn get_latest_manual_price(&self, property_id: u64) -> Result<PriceData, OracleError> {
-            if let Some(history) = self.historical_valuations.get(property_id) {
-                if let Some(latest) = history.last() {
-                    let price_data = PriceData {
-                        price: latest.valuation,
-                        timestamp: latest.last_updated,
-                        source: ink::prelude::string::String::from("manual"),
-                    };
-                    if self.is_price_fresh(&price_data) {
-                        return Ok(price_data);
-                    }
+            if let Some(latest) = self.history_newest_first(property_id, 1).pop() {
+                let price_data = PriceData {
+                    price: latest.valuation,
+                    timestamp: latest.last_updated,
+                    source: ink::prelude::string::String::from("manual"),
+                };
+                if self.is_price_fresh(&price_data) {
+                    return Ok(price_data);
                 }
             }
             Err(OracleError::PriceFeedError)
@@ -2661,39 +3564,59 @@ mod propchain_oracle {
                 return Err(OracleError::InsufficientSources);
             }
 
+            // Pre-build source_id -> weight lookup from packed cache
+            // to avoid O(N²) position scans per source.
+            let weight_map: ink::prelude::collections::BTreeMap<String, u32> = if self
+                .batch_aggregation_enabled
+                && self.aggregation_method == AggregationMethod::WeightedMean
+            {
+                self.active_sources
+                    .iter()
+                    .enumerate()
+                    .map(|(i, sid)| (sid.clone(), self.get_packed_source_weight(i)))
+                    .collect()
+            } else {
+                ink::prelude::collections::BTreeMap::new()
+            };
+
+            Self::aggregate_prices_with(
+                &self.aggregation_method,
+                self.outlier_threshold,
+                prices,
+                |source| {
+                    if self.batch_aggregation_enabled {
+                        Ok(weight_map
+                            .get(source)
+                            .copied()
+                            .unwrap_or_else(|| self.get_source_weight(source).unwrap_or(0)))
+                    } else {
+                        self.get_source_weight(source)
+                    }
+                },
+            )
+        }
+
+        /// Outlier filtering and aggregation over `prices` with the given
+        /// parameters; `weight_of` resolves a source's weight.
+        fn aggregate_prices_with(
+            method: &AggregationMethod,
+            outlier_threshold: u32,
+            prices: &[PriceData],
+            weight_of: impl Fn(&str) -> Result<u32, OracleError>,
+        ) -> Result<u128, OracleError> {
             // Remove outliers
-            let filtered = self.filter_outliers(prices);
+            let filtered = Self::filter_outliers_with(outlier_threshold, prices);
 
             if filtered.is_empty() {
                 return Err(OracleError::InsufficientSources);
             }
 
-            match self.aggregation_method {
+            match method {
                 AggregationMethod::WeightedMean => {
-                    // Pre-build source_id -> weight lookup from packed cache
-                    // to avoid O(N²) position scans per source.
-                    let weight_map: ink::prelude::collections::BTreeMap<String, u32> =
-                        if self.batch_aggregation_enabled {
-                            self.active_sources
-                                .iter()
-                                .enumerate()
-                                .map(|(i, sid)| (sid.clone(), self.get_packed_source_weight(i)))
-                                .collect()
-                        } else {
-                            ink::prelude::collections::BTreeMap::new()
-                        };
-
                     let mut total_weighted = 0u128;
                     let mut total_weight = 0u32;
                     for p in filtered.iter() {
-                        let w = if self.batch_aggregation_enabled {
-                            weight_map
-                                .get(&p.source)
-                                .copied()
-                                .unwrap_or_else(|| self.get_source_weight(&p.source).unwrap_or(0))
-                        } else {
-                            self.get_source_weight(&p.source)?
-                        };
+                        let w = weight_of(&p.source)?;
                         total_weighted += p.price * w as u128;
                         total_weight += w;
                     }
@@ -2715,7 +3638,7 @@ mod propchain_oracle {
                 AggregationMethod::TrimmedMean(trim_count) => {
                     let mut sorted: Vec<u128> = filtered.iter().map(|p| p.price).collect();
                     sorted.sort();
-                    let trim = (trim_count as usize).min(sorted.len() / 3);
+                    let trim = (*trim_count as usize).min(sorted.len() / 3);
                     let trimmed = &sorted[trim..sorted.len() - trim];
                     if trimmed.is_empty() {
                         return Err(OracleError::InsufficientSources);
@@ -2727,6 +3650,10 @@ mod propchain_oracle {
         }
 
         pub fn filter_outliers(&self, prices: &[PriceData]) -> Vec<PriceData> {
+            Self::filter_outliers_with(self.outlier_threshold, prices)
+        }
+
+        fn filter_outliers_with(outlier_threshold: u32, prices: &[PriceData]) -> Vec<PriceData> {
             if prices.len() < 3 {
                 return prices.to_vec();
             }
@@ -2766,13 +3693,16 @@ mod propchain_oracle {
                 .iter()
                 .filter(|p| {
                     let diff = p.price.abs_diff(mean);
-                    diff <= std_dev * self.outlier_threshold as u128
+                    diff <= std_dev * outlier_threshold as u128
                 })
                 .cloned()
                 .collect()
         }
 
         fn get_source_weight(&self, source_id: &str) -> Result<u32, OracleError> {
+            if source_id == propchain_traits::constants::ASSESSMENT_SOURCE_ID {
+                return Ok(propchain_traits::constants::ASSESSMENT_SOURCE_WEIGHT);
+            }
             self.oracle_sources
                 .get(&source_id.to_string())
                 .map(|source| source.weight)
@@ -2888,24 +3818,26 @@ mod propchain_oracle {
             property_id: u64,
             window_days: u32,
         ) -> Vec<PropertyValuation> {
-            let history = self
-                .historical_valuations
-                .get(&property_id)
-                .unwrap_or_default();
-
-            if window_days == 0 {
-                return history;
-            }
-
-            let earliest = self
-                .env()
-                .block_timestamp()
-                .saturating_sub(window_days as u64 * 86_400);
+            let earliest = if window_days == 0 {
+                0
+            } else {
+                self.env()
+                    .block_timestamp()
+                    .saturating_sub(window_days as u64 * 86_400)
+            };
 
+            // Walk back from the newest entry and stop at the first one
+            // outside the window, so only in-window slots are read.
+            let cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            let mut history: Vec<PropertyValuation> = (0..cursor.len)
+                .map_while(|age| {
+                    self.historical_valuations
+                        .get(&(property_id, Self::history_slot(cursor, age)))
+                })
+                .take_while(|entry| entry.last_updated >= earliest)
+                .collect();
+            history.reverse();
             history
-                .into_iter()
-                .filter(|entry| entry.last_updated >= earliest)
-                .collect()
         }
 
         fn calculate_ema(&self, history: &[PropertyValuation]) -> u128 {
@@ -2974,69 +3906,448 @@ mod propchain_oracle {
             })
         }
 
+        /// Interval around the valuation whose half-width is
+        /// `CONFIDENCE_INTERVAL_BP_PER_POINT` basis points of the valuation for
+        /// each point the confidence, less `staleness_penalty`, falls short of
+        /// 100.
         fn calculate_confidence_interval(
             &self,
             valuation: &PropertyValuation,
+            staleness_penalty: u32,
         ) -> Result<(u128, u128), OracleError> {
-            // Simple confidence interval based on confidence score
-            let margin = valuation.valuation * (100 - valuation.confidence_score) as u128 / 10000; // 1% per confidence point
+            let confidence = valuation
+                .confidence_score
+                .min(100)
+                .saturating_sub(staleness_penalty);
+            let margin_bp = u128::from(100 - confidence)
+                * propchain_traits::constants::CONFIDENCE_INTERVAL_BP_PER_POINT;
+            let margin = valuation.valuation.saturating_mul(margin_bp) / 10_000;
 
             Ok((
                 valuation.valuation.saturating_sub(margin),
-                valuation.valuation + margin,
+                valuation.valuation.saturating_add(margin),
             ))
         }
 
+        /// Confidence points lost to age: `STALENESS_PENALTY_POINTS_PER_PERIOD`
+        /// for each full `max_price_staleness` period since the valuation was
+        /// last updated, up to its whole confidence score.
+        fn calculate_staleness_penalty(&self, valuation: &PropertyValuation) -> u32 {
+            if self.max_price_staleness == 0 {
+                return 0;
+            }
+            let age = self
+                .env()
+                .block_timestamp()
+                .saturating_sub(valuation.last_updated);
+            let penalty = (age / self.max_price_staleness).saturating_mul(u64::from(
+                propchain_traits::constants::STALENESS_PENALTY_POINTS_PER_PERIOD,
+            ));
+            penalty.min(u64::from(valuation.confidence_score.min(100))) as u32
+        }
+
         fn detect_outliers(&self, _property_id: u64) -> Result<u32, OracleError> {
             // This would implement outlier detection logic
             // For now, return 0
             Ok(0)
         }
 
+        /// Write a valuation into the property's ring buffer, dropping the
+        /// oldest entries once the property's history depth is reached.
+        ///
+        /// Depth changes are applied here rather than when they are made: a
+        /// ring of the wrong size is rebuilt, and entries beyond a lowered
+        /// depth are pruned.
         fn store_historical_valuation(&mut self, property_id: u64, valuation: PropertyValuation) {
-            let mut history = self
-                .historical_valuations
-                .get(&property_id)
-                .unwrap_or_default();
-            history.push(valuation);
+            let depth = self.get_history_depth(property_id);
+            let mut cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            let capacity = (depth + VALUATION_BREAKDOWN_CAPACITY - 1)
+                / VALUATION_BREAKDOWN_CAPACITY
+                * VALUATION_BREAKDOWN_CAPACITY;
+            if cursor.capacity != capacity {
+                cursor = self.resize_history(property_id, cursor, capacity);
+            }
+            // Make room for the new entry. Pruned entries own no breakdown
+            // once it is written: the youngest one that could shares its key
+            // with the head slot, cleared below.
+            while cursor.len >= depth {
+                let slot = Self::history_slot(cursor, cursor.len - 1);
+                self.historical_valuations.remove(&(property_id, slot));
+                cursor.len -= 1;
+            }
+
+            self.historical_valuations
+                .insert(&(property_id, cursor.head), &valuation);
+            // Drops the breakdown of the entry `VALUATION_BREAKDOWN_CAPACITY`
+            // writes back, which shares this key.
+            self.valuation_breakdowns
+                .remove(&(property_id, Self::breakdown_key(cursor.head)));
+            cursor.head = (cursor.head + 1) % cursor.capacity;
+            cursor.len += 1;
+            self.history_cursors.insert(&property_id, &cursor);
+        }
+
+        /// Moves a property's history into a ring of `capacity` slots,
+        /// keeping the newest entries that fit and their breakdowns. The
+        /// oldest kept entry lands in slot 0.
+        fn resize_history(
+            &mut self,
+            property_id: u64,
+            cursor: HistoryCursor,
+            capacity: u32,
+        ) -> HistoryCursor {
+            let kept = cursor.len.min(capacity);
+            let mut entries = Vec::new();
+            for age in (0..kept).rev() {
+                let slot = Self::history_slot(cursor, age);
+                let breakdown = if age < VALUATION_BREAKDOWN_CAPACITY {
+                    self.valuation_breakdowns
+                        .get(&(property_id, Self::breakdown_key(slot)))
+                } else {
+                    None
+                };
+                if let Some(entry) = self.historical_valuations.get(&(property_id, slot)) {
+                    entries.push((entry, breakdown));
+                }
+            }
+            for age in 0..cursor.len {
+                self.historical_valuations
+                    .remove(&(property_id, Self::history_slot(cursor, age)));
+            }
+            for key in 0..VALUATION_BREAKDOWN_CAPACITY {
+                self.valuation_breakdowns.remove(&(property_id, key));
+            }
 
-            // Keep only last 100 valuations
-            if history.len() > 100 {
-                let start_index = history.len() - 100;
-                history = history.into_iter().skip(start_index).collect();
+            let len = entries.len() as u32;
+            for (slot, (entry, breakdown)) in (0u32..).zip(entries) {
+                self.historical_valuations
+                    .insert(&(property_id, slot), &entry);
+                if let Some(breakdown) = breakdown {
+                    self.valuation_breakdowns
+                        .insert(&(property_id, Self::breakdown_key(slot)), &breakdown);
+                }
+            }
+            HistoryCursor {
+                head: len % capacity,
+                len,
+                capacity,
             }
+        }
+
+        /// Breakdown key of a history slot. `VALUATION_BREAKDOWN_CAPACITY`
+        /// divides every ring's capacity, so the newest entries never share a
+        /// key.
+        fn breakdown_key(slot: u32) -> u32 {
+            slot % VALUATION_BREAKDOWN_CAPACITY
+        }
+
+        /// Attaches `breakdown` to the newest stored valuation of a property.
+        fn record_valuation_breakdown(&mut self, property_id: u64, breakdown: ValuationBreakdown) {
+            let cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            self.valuation_breakdowns.insert(
+                &(
+                    property_id,
+                    Self::breakdown_key(Self::history_slot(cursor, 0)),
+                ),
+                &breakdown,
+            );
+        }
+
+        /// Each price's source, weight and whether aggregation discards it
+        /// as an outlier.
+        fn source_contributions(&self, prices: &[PriceData]) -> Vec<SourceContribution> {
+            let kept = self.filter_outliers(prices);
+            prices
+                .iter()
+                .map(|price| SourceContribution {
+                    source_id: price.source.clone(),
+                    price: price.price,
+                    weight: self.get_source_weight(&price.source).unwrap_or(0),
+                    outlier: !kept.contains(price),
+                })
+                .collect()
+        }
+
+        /// Ring buffer slot holding the entry `age` writes before the newest
+        fn history_slot(cursor: HistoryCursor, age: u32) -> u32 {
+            (cursor.head + cursor.capacity - 1 - age) % cursor.capacity
+        }
+
+        /// Up to `limit` stored valuations of a property, most recent first
+        fn history_newest_first(&self, property_id: u64, limit: u32) -> Vec<PropertyValuation> {
+            let cursor = self.history_cursors.get(&property_id).unwrap_or_default();
+            (0..cursor.len.min(limit))
+                .filter_map(|age| {
+                    self.historical_valuations
+                        .get(&(property_id, Self::history_slot(cursor, age)))
+                })
+                .collect()
+        }
+
+        /// Alerts for a property, with legacy percentage alerts mapped to
+        /// `AlertKind::PercentChange`.
+        fn load_alerts(&self, property_id: u64) -> Vec<PriceAlert> {
+            let mut alerts: Vec<PriceAlert> = self
+                .price_alerts
+                .get(&property_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(PriceAlert::from)
+                .collect();
+            alerts.extend(self.alerts.get(&property_id).unwrap_or_default());
+            alerts
+        }
 
-            self.historical_valuations.insert(&property_id, &history);
+        fn store_alerts(&mut self, property_id: u64, alerts: &Vec<PriceAlert>) {
+            self.price_alerts.remove(&property_id);
+            self.alerts.insert(&property_id, alerts);
         }
 
+        /// Evaluate a property's alerts against the valuation about to be
+        /// stored. `Above`/`Below` alerts fire once per crossing of their level.
         fn check_price_alerts(
             &mut self,
             property_id: u64,
             new_valuation: u128,
         ) -> Result<(), OracleError> {
-            if let Some(last_valuation) = self.property_valuations.get(&property_id) {
-                let change_percentage =
-                    self.calculate_percentage_change(last_valuation.valuation, new_valuation);
-
-                if let Some(alerts) = self.price_alerts.get(&property_id) {
-                    for alert in alerts {
-                        if alert.is_active
-                            && change_percentage >= alert.threshold_percentage as u128
-                        {
-                            self.env().emit_event(PriceAlertTriggered {
-                                property_id,
-                                old_valuation: last_valuation.valuation,
-                                new_valuation,
-                                change_percentage: change_percentage as u32,
-                                alert_address: alert.alert_address,
-                            });
-                        }
+            let mut alerts = self.load_alerts(property_id);
+            if alerts.is_empty() {
+                return Ok(());
+            }
+
+            let old_valuation = self
+                .property_valuations
+                .get(&property_id)
+                .map(|valuation| valuation.valuation);
+            let now = self.env().block_timestamp();
+
+            for alert in alerts.iter_mut().filter(|alert| alert.is_active) {
+                let fire = match alert.kind {
+                    AlertKind::PercentChange { bp } => old_valuation.is_some_and(|old| {
+                        old > 0 && new_valuation.abs_diff(old) * 10_000 / old >= bp as u128
+                    }),
+                    AlertKind::Above { value } => {
+                        let crossed = new_valuation > value;
+                        let fire = crossed && !alert.level_crossed;
+                        alert.level_crossed = crossed;
+                        fire
+                    }
+                    AlertKind::Below { value } => {
+                        let crossed = new_valuation < value;
+                        let fire = crossed && !alert.level_crossed;
+                        alert.level_crossed = crossed;
+                        fire
                     }
+                };
+
+                if fire {
+                    alert.last_triggered = now;
+                    let old_valuation = old_valuation.unwrap_or(0);
+                    self.env().emit_event(PriceAlertTriggered {
+                        property_id,
+                        old_valuation,
+                        new_valuation,
+                        change_percentage: self
+                            .calculate_percentage_change(old_valuation, new_valuation)
+                            as u32,
+                        alert_address: alert.alert_address,
+                        kind: alert.kind.clone(),
+                    });
                 }
             }
+
+            self.store_alerts(property_id, &alerts);
             Ok(())
         }
 
+        /// Registry portfolio alerts resolve owners' property lists through:
+        /// the pinning registry, else the callback registry.
+        fn linked_registry(&self) -> Option<AccountId> {
+            self.registry_contract
+                .or(self.registry_callback.map(|(registry, _)| registry))
+        }
+
+        /// Record `property_id` against every active portfolio alert whose
+        /// threshold its move from `previous` meets and whose owner holds
+        /// it, then fire the alerts that are out of their cooldown. Each
+        /// owner's property list is fetched at most once. Best effort: an
+        /// unreachable registry skips evaluation without reverting the
+        /// update.
+        fn check_portfolio_alerts(
+            &mut self,
+            property_id: u64,
+            previous: Option<u128>,
+            new_valuation: u128,
+        ) {
+            if self.active_portfolio_alerts.is_empty() {
+                return;
+            }
+            let Some(old_valuation) = previous.filter(|old_valuation| *old_valuation > 0) else {
+                return;
+            };
+            let Some(registry_addr) = self.linked_registry() else {
+                return;
+            };
+            let change_bp =
+                new_valuation.abs_diff(old_valuation).saturating_mul(10_000) / old_valuation;
+            let now = self.env().block_timestamp();
+
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            let registry: ink::contract_ref!(PropertyOwnership) =
+                FromAccountId::from_account_id(registry_addr);
+            let mut holdings: Vec<(AccountId, bool)> = Vec::new();
+
+            for alert_id in self.active_portfolio_alerts.clone() {
+                let Some(mut alert) = self.portfolio_alerts.get(&alert_id) else {
+                    continue;
+                };
+                if change_bp < u128::from(alert.threshold_bp) {
+                    continue;
+                }
+                let held = match holdings
+                    .iter()
+                    .find(|(owner, _)| *owner == alert.owner_hint)
+                {
+                    Some((_, held)) => *held,
+                    None => {
+                        let held = match registry
+                            .call()
+                            .get_owner_properties(alert.owner_hint)
+                            .try_invoke()
+                        {
+                            Ok(Ok(property_ids)) => property_ids.contains(&property_id),
+                            _ => return,
+                        };
+                        holdings.push((alert.owner_hint, held));
+                        held
+                    }
+                };
+                if !held {
+                    continue;
+                }
+
+                if !alert.pending_property_ids.contains(&property_id) {
+                    alert.pending_property_ids.push(property_id);
+                }
+                if alert.last_triggered == 0
+                    || now.saturating_sub(alert.last_triggered) >= PORTFOLIO_ALERT_COOLDOWN_MS
+                {
+                    alert.last_triggered = now;
+                    self.env().emit_event(PortfolioAlertTriggered {
+                        alert_id,
+                        owner_hint: alert.owner_hint,
+                        alert_address: alert.alert_address,
+                        property_ids: core::mem::take(&mut alert.pending_property_ids),
+                    });
+                }
+                self.portfolio_alerts.insert(&alert_id, &alert);
+            }
+        }
+
+        /// Pushes `valuation` to the registry callback when it moved far
+        /// enough from `previous`. Best effort: a failing registry is
+        /// reported through `RegistryCallbackFailed` and never reverts the
+        /// update.
+        fn notify_registry(
+            &mut self,
+            property_id: u64,
+            previous: Option<u128>,
+            valuation: &PropertyValuation,
+        ) {
+            let Some((registry, min_change_bp)) = self.registry_callback else {
+                return;
+            };
+            if let Some(old_value) = previous.filter(|old_value| *old_value > 0) {
+                let change_bp = valuation
+                    .valuation
+                    .abs_diff(old_value)
+                    .saturating_mul(10_000)
+                    / old_value;
+                if change_bp < u128::from(min_change_bp) {
+                    return;
+                }
+            }
+
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            let mut sink: ink::contract_ref!(ValuationSink) =
+                FromAccountId::from_account_id(registry);
+            let synced = sink
+                .call_mut()
+                .sync_valuation_from_oracle(
+                    property_id,
+                    valuation.valuation,
+                    valuation.confidence_score,
+                )
+                .try_invoke();
+            if !matches!(synced, Ok(Ok(Ok(())))) {
+                self.env().emit_event(RegistryCallbackFailed {
+                    property_id,
+                    registry,
+                    valuation: valuation.valuation,
+                });
+            }
+        }
+
+        /// Tracks the property's peak within the collapse window and flags a
+        /// `ValuationCollapse` to the callback registry once `valuation` has
+        /// fallen more than the threshold below it. The window restarts
+        /// after a flag, so a further drop must cross the threshold again.
+        /// Delivery is best effort, like [`Self::notify_registry`].
+        fn check_valuation_collapse(&mut self, property_id: u64, valuation: u128) {
+            let Some((max_drop_bp, window_ms)) = self.collapse_threshold else {
+                return;
+            };
+            let now = self.env().block_timestamp();
+            let peak = self
+                .valuation_peaks
+                .get(&property_id)
+                .filter(|(peak, recorded_at)| {
+                    *peak > valuation && now.saturating_sub(*recorded_at) <= window_ms
+                })
+                .map(|(peak, _)| peak);
+            let Some(peak) = peak else {
+                self.valuation_peaks.insert(&property_id, &(valuation, now));
+                return;
+            };
+            let drop_bp = ((peak - valuation).saturating_mul(10_000) / peak) as u32;
+            if drop_bp <= max_drop_bp {
+                return;
+            }
+
+            self.valuation_peaks.insert(&property_id, &(valuation, now));
+            self.env().emit_event(ValuationCollapseDetected {
+                property_id,
+                peak,
+                valuation,
+                drop_bp,
+            });
+            let Some((registry, _)) = self.registry_callback else {
+                return;
+            };
+
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            let mut sink: ink::contract_ref!(ValuationSink) =
+                FromAccountId::from_account_id(registry);
+            let flagged = sink
+                .call_mut()
+                .oracle_flag_property(
+                    property_id,
+                    OracleFlag::ValuationCollapse,
+                    u128::from(drop_bp),
+                )
+                .try_invoke();
+            if !matches!(flagged, Ok(Ok(Ok(())))) {
+                self.env().emit_event(RegistryCallbackFailed {
+                    property_id,
+                    registry,
+                    valuation,
+                });
+            }
+        }
+
         pub fn calculate_percentage_change(&self, old_value: u128, new_value: u128) -> u128 {
             if old_value == 0 {
                 return 0;
@@ -3337,6 +4648,53 @@ mod propchain_oracle {
             Ok(())
         }
 
+        /// Set how many valuations and rental records are kept per property
+        /// without an override (admin only). Takes effect on each property's
+        /// next submission; a lower depth prunes the oldest entries then.
+        #[ink(message)]
+        pub fn set_history_depth(&mut self, depth: u32) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            Self::validate_history_depth(depth)?;
+            self.default_history_depth = depth;
+            Ok(())
+        }
+
+        /// Override how many valuations are kept for `property_id` (admin
+        /// only); `None` falls back to the default depth. Applied lazily,
+        /// like [`Self::set_history_depth`].
+        #[ink(message)]
+        pub fn set_property_history_depth(
+            &mut self,
+            property_id: u64,
+            depth: Option<u32>,
+        ) -> Result<(), OracleError> {
+            self.ensure_admin()?;
+            match depth {
+                Some(depth) => {
+                    Self::validate_history_depth(depth)?;
+                    self.history_depth_overrides.insert(&property_id, &depth);
+                }
+                None => self.history_depth_overrides.remove(&property_id),
+            }
+            Ok(())
+        }
+
+        /// Number of valuations kept for `property_id`: its override if set,
+        /// otherwise the default depth
+        #[ink(message)]
+        pub fn get_history_depth(&self, property_id: u64) -> u32 {
+            self.history_depth_overrides
+                .get(&property_id)
+                .unwrap_or(self.default_history_depth)
+        }
+
+        fn validate_history_depth(depth: u32) -> Result<(), OracleError> {
+            if !(MIN_VALUATION_HISTORY_DEPTH..=MAX_VALUATION_HISTORY_DEPTH).contains(&depth) {
+                return Err(OracleError::InvalidParameters);
+            }
+            Ok(())
+        }
+
         /// Check if history tracking is enabled
         #[ink(message)]
         pub fn is_history_tracking_enabled(&self) -> bool {
@@ -3370,6 +4728,11 @@ mod propchain_oracle {
             self.request_property_valuation(property_id)
         }
 
+        #[ink(message)]
+        fn pin_valuation(&mut self, property_id: u64, label: String) -> Result<u32, OracleError> {
+            self.pin_valuation(property_id, label)
+        }
+
         #[ink(message)]
         fn batch_request_valuations(
             &mut self,
@@ -3476,6 +4839,9 @@ mod propchain_oracle {
             Self::new(AccountId::from([0x0; 32]))
         }
     }
+
+    #[cfg(test)]
+    include!("tests.rs");
 }
 
 // Re-export the contract and error type
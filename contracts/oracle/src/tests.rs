@@ -85,13 +85,694 @@ mod oracle_tests {
         );
     }
 
+    fn valuation_of(property_id: u64, valuation: u128) -> PropertyValuation {
+        PropertyValuation {
+            property_id,
+            valuation,
+            confidence_score: 85,
+            sources_used: 3,
+            last_updated: ink::env::block_timestamp::<DefaultEnvironment>(),
+            valuation_method: ValuationMethod::MarketData,
+        }
+    }
+
     #[ink::test]
-    fn test_get_nonexistent_valuation_fails() {
-        let oracle = setup_oracle();
+    fn test_yield_metrics_hand_computed() {
+        let mut oracle = setup_oracle();
+        oracle
+            .update_property_valuation(1, valuation_of(1, 500_000))
+            .unwrap();
+        oracle
+            .update_property_valuation(2, valuation_of(2, 1_000_000))
+            .unwrap();
+
+        // 2_500 * 12 / 500_000 = 6%
+        oracle.submit_rental_data(1, 2_500, 9_500).unwrap();
+        let metrics = oracle.get_yield_metrics(1).unwrap();
+        assert_eq!(metrics.gross_yield_bp, 600);
+        assert_eq!(metrics.occupancy_bp, 9_500);
+
+        // 1_234 * 12 / 1_000_000 = 1.4808%, truncated to 148 bp
+        oracle.submit_rental_data(2, 1_234, 10_000).unwrap();
+        assert_eq!(oracle.get_yield_metrics(2).unwrap().gross_yield_bp, 148);
+
+        // The latest submission wins; history keeps both, newest first.
+        oracle.submit_rental_data(1, 3_000, 10_000).unwrap();
+        assert_eq!(oracle.get_yield_metrics(1).unwrap().gross_yield_bp, 720);
+        let history = oracle.get_rental_history(1, 10);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].monthly_rent, 3_000);
+
+        let with_confidence = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(
+            with_confidence.yield_metrics.map(|m| m.gross_yield_bp),
+            Some(720)
+        );
+    }
+
+    #[ink::test]
+    fn test_rental_history_follows_history_depth() {
+        let mut oracle = setup_oracle();
+        for rent in 1..=12 {
+            oracle.submit_rental_data(1, rent, 10_000).unwrap();
+        }
+        assert_eq!(oracle.get_rental_history(1, 100).len(), 12);
+
+        // Lowering the depth prunes the oldest records on the next submission.
+        oracle.set_property_history_depth(1, Some(10)).unwrap();
+        oracle.submit_rental_data(1, 13, 10_000).unwrap();
+        let history = oracle.get_rental_history(1, 100);
+        assert_eq!(history.len(), 10);
+        assert_eq!(history[0].monthly_rent, 13);
+        assert_eq!(history[9].monthly_rent, 4);
+    }
+
+    #[ink::test]
+    fn test_confidence_interval_hand_computed() {
+        let mut oracle = setup_oracle();
+        for (property_id, confidence_score) in [(1, 100), (2, 50), (3, 0)] {
+            let mut valuation = valuation_of(property_id, 1_000_000);
+            valuation.confidence_score = confidence_score;
+            oracle
+                .update_property_valuation(property_id, valuation)
+                .unwrap();
+        }
+
+        // 50 bp of the valuation per point short of 100:
+        // confidence 100 -> 0 bp, 50 -> 2_500 bp, 0 -> 5_000 bp.
+        let interval = |oracle: &PropertyValuationOracle, property_id| {
+            let result = oracle.get_valuation_with_confidence(property_id).unwrap();
+            assert_eq!(result.staleness_penalty, 0);
+            result.confidence_interval
+        };
+        assert_eq!(interval(&oracle, 1), (1_000_000, 1_000_000));
+        assert_eq!(interval(&oracle, 2), (750_000, 1_250_000));
+        assert_eq!(interval(&oracle, 3), (500_000, 1_500_000));
+    }
+
+    #[ink::test]
+    fn test_stale_valuation_loses_confidence() {
+        let mut oracle = setup_oracle();
+        let mut valuation = valuation_of(1, 1_000_000);
+        valuation.confidence_score = 80;
+        oracle.update_property_valuation(1, valuation).unwrap();
+
+        // Within the first staleness period nothing is deducted.
+        let staleness = oracle.max_price_staleness;
+        test::set_block_timestamp::<DefaultEnvironment>(staleness - 1);
+        let fresh = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(fresh.staleness_penalty, 0);
+        assert_eq!(fresh.confidence_interval, (900_000, 1_100_000));
+
+        // Three full periods cost 30 points: confidence 80 -> 50.
+        test::set_block_timestamp::<DefaultEnvironment>(3 * staleness + 5);
+        let stale = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(stale.staleness_penalty, 30);
+        assert_eq!(stale.confidence_interval, (750_000, 1_250_000));
+        assert_eq!(stale.valuation.confidence_score, 80);
+
+        // The penalty never exceeds the confidence score.
+        test::set_block_timestamp::<DefaultEnvironment>(100 * staleness);
+        let ancient = oracle.get_valuation_with_confidence(1).unwrap();
+        assert_eq!(ancient.staleness_penalty, 80);
+        assert_eq!(ancient.confidence_interval, (500_000, 1_500_000));
+    }
+
+    #[ink::test]
+    fn test_yield_metrics_missing_data() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        assert_eq!(
+            oracle.submit_rental_data(1, 1_000, 10_001),
+            Err(OracleError::InvalidParameters)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.submit_rental_data(1, 1_000, 5_000),
+            Err(OracleError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        // No rental data
+        assert_eq!(
+            oracle.get_yield_metrics(1),
+            Err(OracleError::PropertyNotFound)
+        );
+
+        // Rental data but no valuation to measure it against
+        oracle.submit_rental_data(1, 1_000, 5_000).unwrap();
+        assert_eq!(
+            oracle.get_yield_metrics(1),
+            Err(OracleError::InvalidValuation)
+        );
+        // The unvalued fallback reports a zero valuation and no yield.
+        assert_eq!(
+            oracle
+                .get_valuation_with_confidence(1)
+                .map(|v| v.yield_metrics),
+            Ok(None)
+        );
+    }
+
+    /// Oracle with a single live manual source, which reports the latest
+    /// stored valuation under the "manual" label.
+    fn setup_oracle_with_manual_source(valuation: u128) -> PropertyValuationOracle {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        oracle
+            .add_oracle_source(OracleSource {
+                id: "manual".to_string(),
+                source_type: OracleSourceType::Manual,
+                address: accounts.bob,
+                is_active: true,
+                weight: 50,
+                last_updated: ink::env::block_timestamp::<DefaultEnvironment>(),
+            })
+            .unwrap();
+        oracle
+            .update_property_valuation(1, valuation_of(1, valuation))
+            .unwrap();
+        oracle
+    }
+
+    #[ink::test]
+    fn test_assessment_blended_when_sources_insufficient() {
+        let mut oracle = setup_oracle_with_manual_source(500_000);
+        oracle
+            .submit_assessment(1, 380_000, 2024, "County Assessor".to_string())
+            .unwrap();
+        oracle
+            .submit_assessment(1, 400_000, 2025, "County Assessor".to_string())
+            .unwrap();
+
+        // One live source against two required: the 2025 assessment fills in
+        // at weight 10. (500_000 * 50 + 400_000 * 10) / 60 = 483_333
+        oracle.update_valuation_from_sources(1).unwrap();
+        let valuation = oracle.get_property_valuation(1).unwrap();
+        assert_eq!(valuation.valuation, 483_333);
+        assert_eq!(valuation.sources_used, 2);
+
+        let assessments = oracle.get_assessments(1, 2025, 2030);
+        assert_eq!(assessments.len(), 1);
+        assert_eq!(assessments[0].assessed_value, 400_000);
+        assert_eq!(oracle.get_assessments(1, 2020, 2030).len(), 2);
+        assert!(oracle.get_assessments(1, 2026, 2030).is_empty());
+    }
+
+    #[ink::test]
+    fn test_assessment_ignored_with_enough_live_sources() {
+        let mut oracle = setup_oracle_with_manual_source(500_000);
+        oracle
+            .submit_assessment(1, 400_000, 2025, "County Assessor".to_string())
+            .unwrap();
+        oracle.min_sources_required = 1;
+
+        oracle.update_valuation_from_sources(1).unwrap();
+        let valuation = oracle.get_property_valuation(1).unwrap();
+        assert_eq!(valuation.valuation, 500_000);
+        assert_eq!(valuation.sources_used, 1);
+    }
+
+    #[ink::test]
+    fn test_assessment_fallback_disabled() {
+        let mut oracle = setup_oracle_with_manual_source(500_000);
+        oracle
+            .submit_assessment(1, 400_000, 2025, "County Assessor".to_string())
+            .unwrap();
+        oracle.set_assessment_fallback(false).unwrap();
+        assert!(!oracle.is_assessment_fallback_enabled());
+
+        assert_eq!(
+            oracle.update_valuation_from_sources(1),
+            Err(OracleError::InsufficientSources)
+        );
+    }
+
+    #[ink::test]
+    fn test_submit_assessment_validation() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        assert_eq!(
+            oracle.submit_assessment(1, 0, 2025, "County Assessor".to_string()),
+            Err(OracleError::InvalidValuation)
+        );
         assert_eq!(
-            oracle.get_property_valuation(999),
+            oracle.submit_assessment(1, 400_000, 2025, String::new()),
+            Err(OracleError::InvalidParameters)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.submit_assessment(1, 400_000, 2025, "County Assessor".to_string()),
+            Err(OracleError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        // Resubmitting a tax year replaces the earlier record.
+        oracle
+            .submit_assessment(1, 400_000, 2025, "County Assessor".to_string())
+            .unwrap();
+        oracle
+            .submit_assessment(1, 410_000, 2025, "County Assessor".to_string())
+            .unwrap();
+        let assessments = oracle.get_assessments(1, 2025, 2025);
+        assert_eq!(assessments.len(), 1);
+        assert_eq!(assessments[0].assessed_value, 410_000);
+    }
+
+    #[ink::test]
+    fn test_valuation_breakdown_is_admin_and_auditor_only() {
+        let mut oracle = setup_oracle_with_manual_source(500_000);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        oracle
+            .submit_assessment(1, 400_000, 2025, "County Assessor".to_string())
+            .unwrap();
+        oracle.update_valuation_from_sources(1).unwrap();
+
+        let breakdown = oracle.get_valuation_breakdown(1, 0).unwrap();
+        assert_eq!(breakdown.valuation, 483_333);
+        assert_eq!(
+            breakdown.contributions,
+            vec![
+                SourceContribution {
+                    source_id: "manual".to_string(),
+                    price: 500_000,
+                    weight: 50,
+                    outlier: false,
+                },
+                SourceContribution {
+                    source_id: propchain_traits::constants::ASSESSMENT_SOURCE_ID.to_string(),
+                    price: 400_000,
+                    weight: propchain_traits::constants::ASSESSMENT_SOURCE_WEIGHT,
+                    outlier: false,
+                },
+            ]
+        );
+        // The valuation set directly by the admin has no source breakdown.
+        assert_eq!(
+            oracle.get_valuation_breakdown(1, 1),
+            Err(OracleError::PropertyNotFound)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.get_valuation_breakdown(1, 0),
+            Err(OracleError::Unauthorized)
+        );
+        assert_eq!(oracle.add_auditor(accounts.bob), Err(OracleError::Unauthorized));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        oracle.add_auditor(accounts.bob).unwrap();
+        assert!(oracle.is_auditor(accounts.bob));
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(oracle.get_valuation_breakdown(1, 0), Ok(breakdown));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        oracle.remove_auditor(accounts.bob).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            oracle.get_valuation_breakdown(1, 0),
+            Err(OracleError::Unauthorized)
+        );
+    }
+
+    fn price_from(source: &str, price: u128) -> PriceData {
+        PriceData {
+            price,
+            timestamp: ink::env::block_timestamp::<DefaultEnvironment>(),
+            source: source.to_string(),
+        }
+    }
+
+    #[ink::test]
+    fn test_verify_aggregation_reproduces_stored_valuation() {
+        let mut oracle = setup_oracle_with_manual_source(500_000);
+        oracle
+            .submit_assessment(1, 400_000, 2025, "County Assessor".to_string())
+            .unwrap();
+        oracle.update_valuation_from_sources(1).unwrap();
+        let assessment = propchain_traits::constants::ASSESSMENT_SOURCE_ID;
+        let honest = vec![price_from("manual", 500_000), price_from(assessment, 400_000)];
+
+        let events_before = test::recorded_events().count();
+        assert!(oracle.verify_aggregation(1, 0, honest.clone()));
+        assert_eq!(test::recorded_events().count(), events_before + 1);
+
+        // Any altered, missing or foreign input fails the exact comparison.
+        assert!(!oracle.verify_aggregation(
+            1,
+            0,
+            vec![price_from("manual", 500_001), price_from(assessment, 400_000)]
+        ));
+        assert!(!oracle.verify_aggregation(1, 0, vec![price_from("manual", 500_000)]));
+        assert!(!oracle.verify_aggregation(
+            1,
+            0,
+            vec![price_from("manual", 500_000), price_from("unknown", 400_000)]
+        ));
+        assert!(!oracle.verify_aggregation(1, 5, honest.clone()));
+        assert!(!oracle.verify_aggregation(2, 0, honest.clone()));
+
+        // The recorded parameters keep verification stable after a config
+        // change that would aggregate the same inputs differently.
+        oracle.aggregation_method = AggregationMethod::Median;
+        assert_eq!(oracle.aggregate_prices(&honest), Ok(450_000));
+        assert!(oracle.verify_aggregation(1, 0, honest));
+
+        // Without a recorded breakdown the current parameters apply.
+        assert!(oracle.verify_aggregation(1, 1, vec![price_from("manual", 500_000)]));
+    }
+
+    #[ink::test]
+    fn test_valuation_breakdowns_kept_for_newest_valuations_only() {
+        let mut oracle = setup_oracle_with_manual_source(500_000);
+        oracle.min_sources_required = 1;
+        for _ in 0..=VALUATION_BREAKDOWN_CAPACITY {
+            oracle.update_valuation_from_sources(1).unwrap();
+        }
+        assert!(oracle
+            .get_valuation_breakdown(1, VALUATION_BREAKDOWN_CAPACITY - 1)
+            .is_ok());
+        assert_eq!(
+            oracle.get_valuation_breakdown(1, VALUATION_BREAKDOWN_CAPACITY),
             Err(OracleError::PropertyNotFound)
         );
+
+        // A direct update takes the newest slot without a breakdown.
+        oracle
+            .update_property_valuation(1, valuation_of(1, 500_000))
+            .unwrap();
+        assert_eq!(
+            oracle.get_valuation_breakdown(1, 0),
+            Err(OracleError::PropertyNotFound)
+        );
+        assert_eq!(
+            oracle.get_valuation_breakdown(1, 1).unwrap().contributions[0].price,
+            500_000
+        );
+    }
+
+    #[ink::test]
+    fn test_pinned_valuations_are_immutable() {
+        let mut oracle = setup_oracle();
+        assert_eq!(
+            oracle.pin_valuation(1, "escrow:1".to_string()),
+            Err(OracleError::PropertyNotFound)
+        );
+
+        oracle
+            .update_property_valuation(1, valuation_of(1, 500_000))
+            .unwrap();
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(oracle.pin_valuation(1, "escrow:1".to_string()), Ok(0));
+        let block_number = ink::env::block_number::<DefaultEnvironment>();
+
+        // Later valuations and pins leave the first pin untouched.
+        oracle
+            .update_property_valuation(1, valuation_of(1, 550_000))
+            .unwrap();
+        test::advance_block::<DefaultEnvironment>();
+        assert_eq!(oracle.pin_valuation(1, "escrow:1".to_string()), Ok(1));
+
+        let pins = oracle.get_pinned_valuations(1, 0, 10);
+        assert_eq!(pins.len(), 2);
+        assert_eq!(pins[0].valuation.valuation, 500_000);
+        assert_eq!(pins[0].label, "escrow:1");
+        assert_eq!(pins[0].block_number, block_number);
+        assert_eq!(pins[1].valuation.valuation, 550_000);
+        assert_eq!(pins[1].block_number, block_number + 1);
+        assert!(oracle.get_pinned_valuations(2, 0, 10).is_empty());
+
+        // Pages start at `offset` and stop at the last pin.
+        assert_eq!(oracle.get_pinned_valuation_count(1), 2);
+        assert_eq!(oracle.get_pinned_valuations(1, 1, 10), vec![pins[1].clone()]);
+        assert!(oracle.get_pinned_valuations(1, 2, 10).is_empty());
+        assert!(oracle.get_pinned_valuations(1, 0, 0).is_empty());
+    }
+
+    #[ink::test]
+    fn test_pin_valuation_authorization() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        oracle
+            .update_property_valuation(1, valuation_of(1, 500_000))
+            .unwrap();
+        assert_eq!(
+            oracle.pin_valuation(1, String::new()),
+            Err(OracleError::InvalidParameters)
+        );
+        let too_long = "x".repeat(propchain_traits::constants::MAX_PIN_LABEL_LENGTH as usize + 1);
+        assert_eq!(
+            oracle.pin_valuation(1, too_long),
+            Err(OracleError::InvalidParameters)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            oracle.pin_valuation(1, "closing".to_string()),
+            Err(OracleError::Unauthorized)
+        );
+        assert_eq!(
+            oracle.set_registry_contract(accounts.django),
+            Err(OracleError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        oracle.set_registry_contract(accounts.django).unwrap();
+        assert_eq!(oracle.get_registry_contract(), Some(accounts.django));
+
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(oracle.pin_valuation(1, "escrow:7".to_string()), Ok(0));
+        assert_eq!(
+            oracle.get_pinned_valuations(1, 0, 1)[0].pinned_by,
+            accounts.django
+        );
+    }
+
+    #[ink::test]
+    fn test_registry_callback_configuration() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(oracle.get_registry_callback(), None);
+        assert_eq!(
+            oracle.set_registry_callback(accounts.django, 10_001),
+            Err(OracleError::InvalidParameters)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            oracle.set_registry_callback(accounts.django, 500),
+            Err(OracleError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        oracle.set_registry_callback(accounts.django, 500).unwrap();
+        assert_eq!(oracle.get_registry_callback(), Some((accounts.django, 500)));
+        oracle.clear_registry_callback().unwrap();
+        assert_eq!(oracle.get_registry_callback(), None);
+    }
+
+    #[ink::test]
+    fn test_portfolio_alert_subscription_management() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        // Subscriptions need a registry to resolve the portfolio through.
+        assert_eq!(
+            oracle.set_portfolio_alert(accounts.bob, 500, accounts.eve),
+            Err(OracleError::InvalidParameters)
+        );
+        oracle.set_registry_contract(accounts.django).unwrap();
+        assert_eq!(
+            oracle.set_portfolio_alert(accounts.bob, 0, accounts.eve),
+            Err(OracleError::InvalidParameters)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        let first = oracle
+            .set_portfolio_alert(accounts.bob, 500, accounts.eve)
+            .unwrap();
+        let second = oracle
+            .set_portfolio_alert(accounts.charlie, 1_000, accounts.eve)
+            .unwrap();
+        let alerts = oracle.get_portfolio_alerts(accounts.eve);
+        assert_eq!(
+            alerts
+                .iter()
+                .map(|alert| alert.alert_id)
+                .collect::<Vec<_>>(),
+            vec![first, second]
+        );
+        assert_eq!(alerts[0].owner_hint, accounts.bob);
+        assert!(alerts
+            .iter()
+            .all(|alert| alert.is_active && alert.last_triggered == 0));
+
+        // Only the subscriber or the admin may deactivate.
+        test::set_caller::<DefaultEnvironment>(accounts.frank);
+        assert_eq!(
+            oracle.deactivate_portfolio_alert(first),
+            Err(OracleError::Unauthorized)
+        );
+        assert_eq!(
+            oracle.deactivate_portfolio_alert(99),
+            Err(OracleError::AlertNotFound)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        oracle.deactivate_portfolio_alert(first).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        oracle.deactivate_portfolio_alert(second).unwrap();
+        assert!(oracle
+            .get_portfolio_alerts(accounts.eve)
+            .iter()
+            .all(|alert| !alert.is_active));
+
+        // Inactive subscriptions free their evaluation slot.
+        for _ in 0..MAX_ACTIVE_PORTFOLIO_ALERTS {
+            oracle
+                .set_portfolio_alert(accounts.bob, 500, accounts.eve)
+                .unwrap();
+        }
+        assert_eq!(
+            oracle.set_portfolio_alert(accounts.bob, 500, accounts.eve),
+            Err(OracleError::InvalidParameters)
+        );
+    }
+
+    #[ink::test]
+    fn test_collapse_threshold_detects_drop_within_window() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            oracle.set_collapse_threshold(0, 1_000),
+            Err(OracleError::InvalidParameters)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            oracle.set_collapse_threshold(2_500, 1_000),
+            Err(OracleError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        oracle.set_collapse_threshold(2_500, 1_000).unwrap();
+        assert_eq!(oracle.get_collapse_threshold(), Some((2_500, 1_000)));
+
+        // ValuationCollapseDetected { property_id, peak, valuation, drop_bp }
+        let collapses = || -> Vec<(u64, u128, u128, u32)> {
+            test::recorded_events()
+                .filter_map(|event| scale::DecodeAll::decode_all(&mut &event.data[..]).ok())
+                .filter(|(property_id, _, _, _): &(u64, u128, u128, u32)| *property_id == 7)
+                .collect()
+        };
+
+        // Each step stays under the circuit breaker; together they exceed 25%.
+        for valuation in [1_000_000, 850_000, 760_000] {
+            oracle
+                .update_property_valuation(7, valuation_of(7, valuation))
+                .unwrap();
+        }
+        assert!(collapses().is_empty());
+        oracle
+            .update_property_valuation(7, valuation_of(7, 700_000))
+            .unwrap();
+        assert_eq!(collapses(), vec![(7, 1_000_000, 700_000, 3_000)]);
+
+        // The window restarts at the flagged valuation, and an old peak
+        // outside the window no longer counts.
+        test::set_block_timestamp::<DefaultEnvironment>(2_000);
+        oracle
+            .update_property_valuation(7, valuation_of(7, 600_000))
+            .unwrap();
+        assert_eq!(collapses().len(), 1);
+
+        oracle.clear_collapse_threshold().unwrap();
+        assert_eq!(oracle.get_collapse_threshold(), None);
+    }
+
+    #[ink::test]
+    fn test_location_index_follows_relocation() {
+        let mut oracle = setup_oracle();
+        for id in 1..=3 {
+            oracle
+                .register_property_location(id, "Lagos".to_string())
+                .unwrap();
+        }
+        assert_eq!(
+            oracle.register_property_location(4, String::new()),
+            Err(OracleError::InvalidParameters)
+        );
+
+        // Only valued properties are listed.
+        oracle
+            .update_property_valuation(1, valuation_of(1, 100_000))
+            .unwrap();
+        oracle
+            .update_property_valuation(3, valuation_of(3, 300_000))
+            .unwrap();
+        assert_eq!(
+            oracle.get_valued_properties_in("Lagos".to_string(), 0, 10),
+            vec![1, 3]
+        );
+        assert_eq!(
+            oracle.get_valued_properties_in("Lagos".to_string(), 1, 10),
+            vec![3]
+        );
+
+        oracle
+            .register_property_location(3, "Abuja".to_string())
+            .unwrap();
+        assert_eq!(
+            oracle.get_valued_properties_in("Lagos".to_string(), 0, 10),
+            vec![1]
+        );
+        assert_eq!(
+            oracle.get_valued_properties_in("Abuja".to_string(), 0, 10),
+            vec![3]
+        );
+        assert_eq!(oracle.get_property_location(3), Some("Abuja".to_string()));
+    }
+
+    #[ink::test]
+    fn test_location_stats_median_on_odd_and_even_counts() {
+        let mut oracle = setup_oracle();
+        assert_eq!(
+            oracle.get_location_stats("Nairobi".to_string()),
+            LocationStats::default()
+        );
+
+        for (id, valuation) in [(1, 300_000), (2, 100_000), (3, 200_000)] {
+            oracle
+                .register_property_location(id, "Nairobi".to_string())
+                .unwrap();
+            oracle
+                .update_property_valuation(id, valuation_of(id, valuation))
+                .unwrap();
+        }
+        let stats = oracle.get_location_stats("Nairobi".to_string());
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.median_valuation, 200_000);
+        assert_eq!(stats.avg_confidence, 85);
+
+        oracle
+            .register_property_location(4, "Nairobi".to_string())
+            .unwrap();
+        oracle
+            .update_property_valuation(4, valuation_of(4, 250_001))
+            .unwrap();
+        let stats = oracle.get_location_stats("Nairobi".to_string());
+        assert_eq!(stats.count, 4);
+        assert_eq!(stats.median_valuation, 225_000);
+    }
+
+    #[ink::test]
+    fn test_get_nonexistent_valuation_reads_as_unsourced() {
+        let oracle = setup_oracle();
+        // An unvalued property reads as a zero valuation backed by no
+        // sources rather than an error.
+        let valuation = oracle.get_property_valuation(999).unwrap();
+        assert_eq!(valuation.valuation, 0);
+        assert_eq!(valuation.sources_used, 0);
+        assert_eq!(valuation.confidence_score, 0);
     }
 
     #[ink::test]
@@ -101,10 +782,107 @@ mod oracle_tests {
 
         assert!(oracle.set_price_alert(1, 5, accounts.bob).is_ok());
 
-        let alerts = oracle.price_alerts.get(&1).unwrap_or_default();
+        let alerts = oracle.get_alerts(1);
         assert_eq!(alerts.len(), 1);
-        assert_eq!(alerts[0].threshold_percentage, 5);
+        assert_eq!(alerts[0].kind, AlertKind::PercentChange { bp: 500 });
+        assert_eq!(alerts[0].alert_address, accounts.bob);
+    }
+
+    /// Store a valuation at the given timestamp and return when each of the
+    /// property's alerts last fired.
+    fn value_at(
+        oracle: &mut PropertyValuationOracle,
+        timestamp: u64,
+        valuation: u128,
+    ) -> Vec<u64> {
+        test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+        oracle
+            .update_property_valuation(1, valuation_of(1, valuation))
+            .unwrap();
+        oracle
+            .get_alerts(1)
+            .iter()
+            .map(|alert| alert.last_triggered)
+            .collect()
+    }
+
+    #[ink::test]
+    fn test_percent_change_alert() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        oracle
+            .add_price_alert(1, AlertKind::PercentChange { bp: 250 }, accounts.bob)
+            .unwrap();
+
+        // No previous valuation to compare against
+        assert_eq!(value_at(&mut oracle, 1, 100_000), vec![0]);
+        // 2% move stays under 2.5%
+        assert_eq!(value_at(&mut oracle, 2, 102_000), vec![0]);
+        // 102_000 -> 99_450 is exactly 2.5%
+        assert_eq!(value_at(&mut oracle, 3, 99_450), vec![3]);
+    }
+
+    #[ink::test]
+    fn test_above_alert_fires_once_per_crossing() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        oracle
+            .add_price_alert(1, AlertKind::Above { value: 100_000 }, accounts.bob)
+            .unwrap();
+
+        assert_eq!(value_at(&mut oracle, 1, 100_000), vec![0]);
+        assert_eq!(value_at(&mut oracle, 2, 101_000), vec![2]);
+        // Still above: no re-fire
+        assert_eq!(value_at(&mut oracle, 3, 105_000), vec![2]);
+        // Back below rearms the alert, the next crossing fires again
+        assert_eq!(value_at(&mut oracle, 4, 99_000), vec![2]);
+        assert_eq!(value_at(&mut oracle, 5, 100_500), vec![5]);
+    }
+
+    #[ink::test]
+    fn test_below_alert_fires_once_per_crossing() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        // Lender's loan amount
+        oracle
+            .add_price_alert(1, AlertKind::Below { value: 90_000 }, accounts.bob)
+            .unwrap();
+
+        assert_eq!(value_at(&mut oracle, 1, 95_000), vec![0]);
+        assert_eq!(value_at(&mut oracle, 2, 89_000), vec![2]);
+        assert_eq!(value_at(&mut oracle, 3, 85_000), vec![2]);
+        assert_eq!(value_at(&mut oracle, 4, 88_000), vec![2]);
+        assert_eq!(value_at(&mut oracle, 5, 91_000), vec![2]);
+        assert_eq!(value_at(&mut oracle, 6, 89_500), vec![6]);
+    }
+
+    #[ink::test]
+    fn test_legacy_alerts_read_as_percent_change() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        oracle.price_alerts.insert(
+            1,
+            &vec![LegacyPriceAlert {
+                property_id: 1,
+                threshold_percentage: 5,
+                alert_address: accounts.bob,
+                last_triggered: 0,
+                is_active: true,
+            }],
+        );
+        oracle
+            .add_price_alert(1, AlertKind::Below { value: 90_000 }, accounts.charlie)
+            .unwrap();
+
+        let alerts = oracle.get_alerts(1);
+        assert_eq!(alerts.len(), 2);
+        assert_eq!(alerts[0].kind, AlertKind::PercentChange { bp: 500 });
         assert_eq!(alerts[0].alert_address, accounts.bob);
+        // Writing the alerts back migrated the legacy entry.
+        assert!(oracle.price_alerts.get(1).is_none());
+
+        assert_eq!(value_at(&mut oracle, 1, 100_000), vec![0, 0]);
+        assert_eq!(value_at(&mut oracle, 2, 94_000), vec![2, 0]);
     }
 
     #[ink::test]
@@ -268,6 +1046,176 @@ mod oracle_tests {
         assert_eq!(history.len(), 0);
     }
 
+    /// Record `count` valuations of property 1, each 0.1% above the last.
+    fn record_valuations(oracle: &mut PropertyValuationOracle, start: u128, count: u32) -> u128 {
+        let mut valuation = start;
+        for _ in 0..count {
+            valuation += valuation / 1_000;
+            oracle
+                .update_property_valuation(1, valuation_of(1, valuation))
+                .unwrap();
+        }
+        valuation
+    }
+
+    #[ink::test]
+    fn test_history_ring_buffer_keeps_latest_entries() {
+        let mut oracle = setup_oracle();
+        let first = record_valuations(&mut oracle, 100_000, 1);
+        let last = record_valuations(&mut oracle, first, 104);
+
+        let history = oracle.get_historical_valuations(1, 500);
+        assert_eq!(history.len(), VALUATION_HISTORY_CAPACITY as usize);
+        assert_eq!(history[0].valuation, last);
+        // Entries are strictly decreasing going back in time.
+        assert!(history.windows(2).all(|w| w[0].valuation > w[1].valuation));
+        assert_eq!(oracle.get_historical_valuations(1, 3).len(), 3);
+    }
+
+    #[ink::test]
+    fn test_history_depth_override_precedence_and_lazy_pruning() {
+        let mut oracle = setup_oracle();
+        assert_eq!(oracle.get_history_depth(1), VALUATION_HISTORY_CAPACITY);
+        assert_eq!(
+            oracle.set_history_depth(MIN_VALUATION_HISTORY_DEPTH - 1),
+            Err(OracleError::InvalidParameters)
+        );
+        assert_eq!(
+            oracle.set_property_history_depth(1, Some(MAX_VALUATION_HISTORY_DEPTH + 1)),
+            Err(OracleError::InvalidParameters)
+        );
+
+        // An override beats the default until it is cleared.
+        oracle.set_history_depth(365).unwrap();
+        oracle.set_property_history_depth(1, Some(12)).unwrap();
+        assert_eq!(oracle.get_history_depth(1), 12);
+        assert_eq!(oracle.get_history_depth(2), 365);
+        oracle.set_property_history_depth(1, None).unwrap();
+        assert_eq!(oracle.get_history_depth(1), 365);
+
+        let last = record_valuations(&mut oracle, 100_000, 365);
+        assert_eq!(oracle.get_historical_valuations(1, 500).len(), 365);
+
+        // Lowering the depth prunes nothing until the next valuation.
+        oracle.set_property_history_depth(1, Some(12)).unwrap();
+        assert_eq!(oracle.get_historical_valuations(1, 500).len(), 365);
+        let newest = record_valuations(&mut oracle, last, 1);
+        let history = oracle.get_historical_valuations(1, 500);
+        assert_eq!(history.len(), 12);
+        assert_eq!(history[0].valuation, newest);
+        assert!(history.windows(2).all(|w| w[0].valuation > w[1].valuation));
+
+        let newest = record_valuations(&mut oracle, newest, 30);
+        let history = oracle.get_historical_valuations(1, 500);
+        assert_eq!(history.len(), 12);
+        assert_eq!(history[0].valuation, newest);
+        assert!(history.windows(2).all(|w| w[0].valuation > w[1].valuation));
+    }
+
+    #[ink::test]
+    fn test_history_insert_storage_cost_is_constant() {
+        let mut oracle = setup_oracle();
+        let contract = test::callee::<DefaultEnvironment>();
+        let rw_of_one_insert = |oracle: &mut PropertyValuationOracle, valuation: u128| {
+            let (reads, writes) = test::get_contract_storage_rw::<DefaultEnvironment>(&contract);
+            oracle.store_historical_valuation(1, valuation_of(1, valuation));
+            let (reads_after, writes_after) =
+                test::get_contract_storage_rw::<DefaultEnvironment>(&contract);
+            (reads_after - reads, writes_after - writes)
+        };
+
+        // The Vec-backed history decoded and re-encoded every entry on each
+        // insert; the ring buffer reads the property's depth override and
+        // the cursor, writes one slot plus the cursor and clears the
+        // breakdown sharing the slot's key. A full buffer also prunes the
+        // oldest entry, one more write however long the history is.
+        let valuation = record_valuations(&mut oracle, 100_000, 1);
+        assert_eq!(rw_of_one_insert(&mut oracle, valuation), (2, 3));
+        let valuation = record_valuations(&mut oracle, valuation, 150);
+        assert_eq!(rw_of_one_insert(&mut oracle, valuation), (2, 4));
+
+        // Once wrapped, further inserts reuse slots instead of adding cells.
+        let cells = test::count_used_storage_cells::<DefaultEnvironment>(&contract).unwrap();
+        record_valuations(&mut oracle, valuation, 20);
+        assert_eq!(
+            test::count_used_storage_cells::<DefaultEnvironment>(&contract).unwrap(),
+            cells
+        );
+    }
+
+    #[ink::test]
+    fn test_archive_history_prunes_entries_past_retention() {
+        let mut oracle = setup_oracle();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let day_ms = 24 * 60 * 60 * 1000;
+
+        test::set_block_timestamp::<DefaultEnvironment>(day_ms);
+        test::advance_block::<DefaultEnvironment>();
+        let valuation = record_valuations(&mut oracle, 100_000, 3);
+        // Default retention is 30 days.
+        test::set_block_timestamp::<DefaultEnvironment>(40 * day_ms);
+        test::advance_block::<DefaultEnvironment>();
+        let latest = record_valuations(&mut oracle, valuation, 2);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(oracle.archive_history(1), Err(OracleError::Unauthorized));
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+        assert_eq!(oracle.archive_history(1), Ok(3));
+        let history = oracle.get_historical_valuations(1, 10);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].valuation, latest);
+        assert_eq!(oracle.archive_history(1), Ok(0));
+
+        // New entries land after the surviving ones.
+        let newest = record_valuations(&mut oracle, latest, 1);
+        let history = oracle.get_historical_valuations(1, 10);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0].valuation, newest);
+        assert_eq!(history[1].valuation, latest);
+    }
+
+    #[ink::test]
+    fn test_comparable_cache_is_capped() {
+        let mut oracle = setup_oracle();
+        assert_eq!(
+            oracle.get_comparable_retention(),
+            DEFAULT_COMPARABLE_RETENTION
+        );
+        assert_eq!(
+            oracle.set_comparable_retention(0),
+            Err(OracleError::InvalidParameters)
+        );
+        assert_eq!(
+            oracle.set_comparable_retention(MAX_COMPARABLE_RETENTION + 1),
+            Err(OracleError::InvalidParameters)
+        );
+        oracle.set_comparable_retention(3).unwrap();
+
+        for comparable_id in 10..15 {
+            oracle
+                .add_comparable_property(
+                    1,
+                    ComparableProperty {
+                        property_id: comparable_id,
+                        distance_km: 2,
+                        price_per_sqm: 3_000,
+                        size_sqm: 100,
+                        sale_date: 0,
+                        adjustment_factor: 0,
+                    },
+                )
+                .unwrap();
+        }
+
+        let cached: Vec<u64> = oracle
+            .get_comparable_properties(1, 10)
+            .iter()
+            .map(|c| c.property_id)
+            .collect();
+        assert_eq!(cached, vec![12, 13, 14]);
+    }
+
     #[ink::test]
     fn test_insufficient_sources_error() {
         let oracle = setup_oracle();
@@ -306,15 +1254,15 @@ mod oracle_tests {
         let mut oracle = setup_oracle();
 
         // Initially disabled
-        assert_eq!(oracle.is_batch_aggregation_enabled(), false);
+        assert!(!oracle.is_batch_aggregation_enabled());
 
         // Enable batch aggregation
         assert!(oracle.set_batch_aggregation(true).is_ok());
-        assert_eq!(oracle.is_batch_aggregation_enabled(), true);
+        assert!(oracle.is_batch_aggregation_enabled());
 
         // Disable batch aggregation
         assert!(oracle.set_batch_aggregation(false).is_ok());
-        assert_eq!(oracle.is_batch_aggregation_enabled(), false);
+        assert!(!oracle.is_batch_aggregation_enabled());
     }
 
     #[ink::test]
@@ -541,7 +1489,7 @@ mod oracle_tests {
     fn test_property_trend_metrics_and_direction() {
         let mut oracle = setup_oracle();
         let property_id = 2;
-        let prices = vec![100u128, 120, 140, 160, 180, 200, 220];
+        let prices = [100u128, 120, 140, 160, 180, 200, 220];
         let base_timestamp = 1_000_000u64;
 
         assert!(oracle.set_ema_alpha(5000).is_ok());
@@ -573,7 +1521,7 @@ mod oracle_tests {
     fn test_property_trend_direction_stable() {
         let mut oracle = setup_oracle();
         let property_id = 3;
-        let prices = vec![100u128, 101, 100, 100, 101, 100, 100];
+        let prices = [100u128, 101, 100, 100, 101, 100, 100];
         let base_timestamp = 2_000_000u64;
 
         assert!(oracle.set_ema_alpha(3000).is_ok());
@@ -601,7 +1549,7 @@ mod oracle_tests {
     fn test_volatility_index_window_calculation() {
         let mut oracle = setup_oracle();
         let property_id = 4;
-        let prices = vec![100u128, 110, 90, 105];
+        let prices = [100u128, 110, 90, 105];
         let base_timestamp = 3_000_000u64;
 
         for (index, price) in prices.iter().enumerate() {
@@ -719,10 +1667,11 @@ mod auto_slash_tests {
             .set_auto_slash_config(true, 1, false, 2000, false, 3)
             .unwrap();
 
-        // Record the source as having reported at time 0
+        // Record the source as having reported at time 1 (0 means it never
+        // reported, which staleness checks skip)
         oracle
             .source_last_report_time
-            .insert(&"stale_src".to_string(), &0u64);
+            .insert(&"stale_src".to_string(), &1u64);
 
         // Set block timestamp to 100 (> staleness threshold of 1)
         test::set_block_timestamp::<DefaultEnvironment>(100);
@@ -847,6 +1796,12 @@ mod oracle_source_multisig_tests {
     use crate::propchain_oracle::PropertyValuationOracle;
     use ink::env::{test, DefaultEnvironment};
 
+    fn setup_oracle() -> PropertyValuationOracle {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        PropertyValuationOracle::new(accounts.alice)
+    }
+
     fn setup_with_signers() -> PropertyValuationOracle {
         let accounts = test::default_accounts::<DefaultEnvironment>();
         test::set_caller::<DefaultEnvironment>(accounts.alice);
@@ -1118,7 +2073,7 @@ mod oracle_source_multisig_tests {
         let mut oracle = setup_oracle();
         let accounts = test::default_accounts::<DefaultEnvironment>();
 
-        let weights = vec![10u32, 20, 30, 40, 50];
+        let weights = [10u32, 20, 30, 40, 50];
         for (i, w) in weights.iter().enumerate() {
             oracle
                 .add_oracle_source(OracleSource {
@@ -1249,7 +2204,7 @@ mod oracle_benchmarks {
                     source_type: OracleSourceType::Manual,
                     address: accounts.bob,
                     is_active: true,
-                    weight: (100 / count.max(1)) as u32,
+                    weight: 100 / count.max(1),
                     last_updated: ink::env::block_timestamp::<DefaultEnvironment>(),
                 })
                 .expect("add source");
@@ -1277,20 +2232,20 @@ mod oracle_benchmarks {
 
         // Sequential aggregation
         assert!(oracle.set_batch_aggregation(false).is_ok());
-        let start = test::get_block_timestamp::<DefaultEnvironment>();
+        let start = ink::env::block_timestamp::<DefaultEnvironment>();
         for _ in 0..100 {
             let _ = oracle.aggregate_prices(&prices);
         }
-        let seq_end = test::get_block_timestamp::<DefaultEnvironment>();
+        let seq_end = ink::env::block_timestamp::<DefaultEnvironment>();
         let seq_duration = seq_end.saturating_sub(start);
 
         // Batched aggregation (packed weights)
         assert!(oracle.set_batch_aggregation(true).is_ok());
-        let start = test::get_block_timestamp::<DefaultEnvironment>();
+        let start = ink::env::block_timestamp::<DefaultEnvironment>();
         for _ in 0..100 {
             let _ = oracle.aggregate_prices(&prices);
         }
-        let batch_end = test::get_block_timestamp::<DefaultEnvironment>();
+        let batch_end = ink::env::block_timestamp::<DefaultEnvironment>();
         let batch_duration = batch_end.saturating_sub(start);
 
         // Batched must not be slower than sequential
@@ -1313,21 +2268,18 @@ mod oracle_benchmarks {
 
     #[ink::test]
     fn benchmark_aggregation_scales_with_sources() {
-        let mut oracle = setup_oracle();
-
         for count in [2, 5, 10, 20] {
-            // Reset oracle for each batch size
-            let accounts = test::default_accounts::<DefaultEnvironment>();
-            oracle = PropertyValuationOracle::new(accounts.alice);
+            // Fresh oracle for each batch size
+            let mut oracle = setup_oracle();
             add_sources(&mut oracle, count);
             let prices = make_prices(count as usize);
 
             assert!(oracle.set_batch_aggregation(true).is_ok());
-            let start = test::get_block_timestamp::<DefaultEnvironment>();
+            let start = ink::env::block_timestamp::<DefaultEnvironment>();
             for _ in 0..50 {
                 let _ = oracle.aggregate_prices(&prices);
             }
-            let end = test::get_block_timestamp::<DefaultEnvironment>();
+            let end = ink::env::block_timestamp::<DefaultEnvironment>();
             let duration = end.saturating_sub(start);
 
             // Each iteration should complete within a reasonable bound
@@ -1351,25 +2303,25 @@ mod oracle_benchmarks {
         assert!(oracle.set_batch_aggregation(true).is_ok());
 
         // Packed weight lookups
-        let start = test::get_block_timestamp::<DefaultEnvironment>();
+        let start = ink::env::block_timestamp::<DefaultEnvironment>();
         for _ in 0..500 {
             for i in 0..10 {
                 let _ = oracle.get_packed_source_weight(i);
             }
         }
-        let packed_end = test::get_block_timestamp::<DefaultEnvironment>();
+        let packed_end = ink::env::block_timestamp::<DefaultEnvironment>();
         let packed_duration = packed_end.saturating_sub(start);
 
         // Direct storage lookups via get_source_weight
         assert!(oracle.set_batch_aggregation(false).is_ok());
-        let start = test::get_block_timestamp::<DefaultEnvironment>();
+        let start = ink::env::block_timestamp::<DefaultEnvironment>();
         for _ in 0..500 {
             for i in 0..10 {
                 let sid = format!("bench_src_{}", i);
                 let _ = oracle.get_source_weight(&sid);
             }
         }
-        let direct_end = test::get_block_timestamp::<DefaultEnvironment>();
+        let direct_end = ink::env::block_timestamp::<DefaultEnvironment>();
         let direct_duration = direct_end.saturating_sub(start);
 
         // Packed lookups should be at least as fast (fewer storage reads)
@@ -1390,20 +2342,20 @@ mod oracle_benchmarks {
 
         // Sequential
         assert!(oracle.set_batch_aggregation(false).is_ok());
-        let start = test::get_block_timestamp::<DefaultEnvironment>();
+        let start = ink::env::block_timestamp::<DefaultEnvironment>();
         for _ in 0..20 {
             let _ = oracle.collect_prices_from_sources(1);
         }
-        let seq_end = test::get_block_timestamp::<DefaultEnvironment>();
+        let seq_end = ink::env::block_timestamp::<DefaultEnvironment>();
         let seq_duration = seq_end.saturating_sub(start);
 
         // Batched
         assert!(oracle.set_batch_aggregation(true).is_ok());
-        let start = test::get_block_timestamp::<DefaultEnvironment>();
+        let start = ink::env::block_timestamp::<DefaultEnvironment>();
         for _ in 0..20 {
             let _ = oracle.collect_prices_from_sources(1);
         }
-        let batch_end = test::get_block_timestamp::<DefaultEnvironment>();
+        let batch_end = ink::env::block_timestamp::<DefaultEnvironment>();
         let batch_duration = batch_end.saturating_sub(start);
 
         // Both must produce the same results
@@ -308,6 +308,104 @@ pub struct AutoSlashReason {
 
 // ── Oracle Data History Tracking Types ─────────────────────────────────────────
 
+/// Default number of valuations kept per property, and the ring size of
+/// every history stored before the depth became configurable
+pub const VALUATION_HISTORY_CAPACITY: u32 = 100;
+
+/// Lower bound on the admin-tunable history depth; the newest
+/// `VALUATION_BREAKDOWN_CAPACITY` valuations always stay in history
+pub const MIN_VALUATION_HISTORY_DEPTH: u32 = VALUATION_BREAKDOWN_CAPACITY;
+
+/// Upper bound on the admin-tunable history depth, about a year of daily
+/// valuations
+pub const MAX_VALUATION_HISTORY_DEPTH: u32 = 400;
+
+/// Default number of comparables cached per property
+pub const DEFAULT_COMPARABLE_RETENTION: u32 = 50;
+
+/// Upper bound on the admin-tunable comparable retention
+pub const MAX_COMPARABLE_RETENTION: u32 = 200;
+
+/// Position of a property's valuation ring buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct HistoryCursor {
+    /// Slot the next valuation is written to
+    pub head: u32,
+    /// Number of valuations stored, at most the property's history depth
+    pub len: u32,
+    /// Number of slots in the ring. Always a multiple of
+    /// `VALUATION_BREAKDOWN_CAPACITY`, so the newest entries never share a
+    /// breakdown key.
+    pub capacity: u32,
+}
+
+impl Default for HistoryCursor {
+    fn default() -> Self {
+        Self {
+            head: 0,
+            len: 0,
+            capacity: VALUATION_HISTORY_CAPACITY,
+        }
+    }
+}
+
+impl scale::Decode for HistoryCursor {
+    /// Cursors stored before `capacity` was recorded all belong to rings of
+    /// `VALUATION_HISTORY_CAPACITY` slots.
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let head = u32::decode(input)?;
+        let len = u32::decode(input)?;
+        let capacity = match input.remaining_len()? {
+            Some(0) => VALUATION_HISTORY_CAPACITY,
+            _ => u32::decode(input)?,
+        };
+        Ok(Self {
+            head,
+            len,
+            capacity,
+        })
+    }
+}
+
+/// Most recent valuations per property whose per-source breakdown is kept
+pub const VALUATION_BREAKDOWN_CAPACITY: u32 = 10;
+
+/// One source's part in an aggregated valuation
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct SourceContribution {
+    pub source_id: String,
+    pub price: u128,
+    /// Weight the source carried when the valuation was aggregated
+    pub weight: u32,
+    /// Whether the price was discarded as an outlier
+    pub outlier: bool,
+}
+
+/// Per-source snapshot behind a valuation aggregated from oracle sources
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ValuationBreakdown {
+    pub property_id: u64,
+    pub valuation: u128,
+    pub recorded_at: u64,
+    pub contributions: Vec<SourceContribution>,
+    /// Aggregation method in force when the valuation was computed
+    pub aggregation_method: AggregationMethod,
+    /// Outlier threshold in force when the valuation was computed
+    pub outlier_threshold: u32,
+}
+
 /// Minimum retention period for history data (7 days in milliseconds)
 pub const HISTORY_MIN_RETENTION_MS: u64 = 7 * 24 * 60 * 60 * 1000;
 
@@ -317,5 +415,51 @@ pub const HISTORY_MAX_RETENTION_MS: u64 = 730 * 24 * 60 * 60 * 1000;
 /// Default retention period for history data (90 days in milliseconds)
 pub const HISTORY_DEFAULT_RETENTION_MS: u64 = 90 * 24 * 60 * 60 * 1000;
 
+// ── Location Index Types ──────────────────────────────────────────────────────
 
+/// Valued properties sampled per location when computing location stats
+pub const LOCATION_STATS_SAMPLE: u32 = 100;
 
+/// Summary of the valued properties indexed under one location
+#[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct LocationStats {
+    /// Valued properties in the sample
+    pub count: u32,
+    pub median_valuation: u128,
+    /// Mean confidence score (0-100) across the sample
+    pub avg_confidence: u32,
+}
+
+// ── Portfolio Alert Types ─────────────────────────────────────────────────────
+
+/// Minimum time between two `PortfolioAlertTriggered` events of one
+/// subscription (1 hour in milliseconds)
+pub const PORTFOLIO_ALERT_COOLDOWN_MS: u64 = 60 * 60 * 1000;
+
+/// Active portfolio alerts evaluated per valuation update; each may cost a
+/// registry call
+pub const MAX_ACTIVE_PORTFOLIO_ALERTS: usize = 16;
+
+/// Alert on valuation moves across every property an owner holds
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PortfolioAlert {
+    pub alert_id: u64,
+    /// Account that created the subscription
+    pub subscriber: AccountId,
+    /// Owner whose properties are watched, resolved through the registry
+    pub owner_hint: AccountId,
+    /// Minimum move, in basis points of the previous valuation
+    pub threshold_bp: u32,
+    pub alert_address: AccountId,
+    /// Block timestamp of the last `PortfolioAlertTriggered`, 0 if never
+    pub last_triggered: u64,
+    pub is_active: bool,
+    /// Properties that breached the threshold during the cooldown, reported
+    /// with the next trigger
+    pub pending_property_ids: Vec<u64>,
+}
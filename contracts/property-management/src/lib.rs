@@ -3,7 +3,9 @@
 
 use ink::prelude::string::String;
 use ink::storage::Mapping;
-use propchain_traits::{non_reentrant, ComplianceChecker, ReentrancyError, ReentrancyGuard};
+use propchain_traits::{
+    compliance_checker, non_reentrant, ComplianceChecker, ReentrancyError, ReentrancyGuard,
+};
 
 #[ink::contract]
 mod property_management {
@@ -1267,10 +1269,7 @@ mod property_management {
 
         fn require_compliant(&self, account: AccountId) -> Result<(), Error> {
             if let Some(registry) = self.compliance_registry {
-                use ink::env::call::FromAccountId;
-                let checker: ink::contract_ref!(ComplianceChecker) =
-                    FromAccountId::from_account_id(registry);
-                if !checker.is_compliant(account) {
+                if !compliance_checker(registry).is_compliant(account) {
                     return Err(Error::NotCompliant);
                 }
             }
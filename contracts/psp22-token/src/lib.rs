@@ -0,0 +1,181 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+//! # PropChain Reference PSP22 Token
+//!
+//! Minimal implementation of the [`PSP22`] fungible token standard, used as
+//! a stable settlement token for registry escrows in tests and local
+//! deployments. The whole supply is minted to the deployer; there is no
+//! further minting or burning.
+
+use propchain_traits::psp22::{PSP22Error, PSP22};
+
+#[ink::contract]
+pub mod propchain_psp22_token {
+    use super::*;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    // ── Events ────────────────────────────────────────────────────────────
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: u128,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub amount: u128,
+    }
+
+    // ── Storage ───────────────────────────────────────────────────────────
+
+    #[ink(storage)]
+    pub struct Psp22Token {
+        total_supply: u128,
+        balances: Mapping<AccountId, u128>,
+        /// Remaining allowance per (owner, spender).
+        allowances: Mapping<(AccountId, AccountId), u128>,
+    }
+
+    // ── Implementation ────────────────────────────────────────────────────
+
+    impl Psp22Token {
+        /// Deploy a token minting `total_supply` to the caller.
+        #[ink(constructor)]
+        pub fn new(total_supply: u128) -> Self {
+            let caller = Self::env().caller();
+            let mut balances = Mapping::default();
+            balances.insert(caller, &total_supply);
+            Self::env().emit_event(Transfer {
+                from: None,
+                to: Some(caller),
+                value: total_supply,
+            });
+            Self {
+                total_supply,
+                balances,
+                allowances: Mapping::default(),
+            }
+        }
+
+        fn move_balance(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+        ) -> Result<(), PSP22Error> {
+            let from_balance = self.balances.get(from).unwrap_or(0);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            if from == to || value == 0 {
+                return Ok(());
+            }
+            self.balances.insert(from, &(from_balance - value));
+            let to_balance = self.balances.get(to).unwrap_or(0);
+            self.balances.insert(to, &to_balance.saturating_add(value));
+            self.env().emit_event(Transfer {
+                from: Some(from),
+                to: Some(to),
+                value,
+            });
+            Ok(())
+        }
+    }
+
+    impl PSP22 for Psp22Token {
+        #[ink(message)]
+        fn total_supply(&self) -> u128 {
+            self.total_supply
+        }
+
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128 {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let from = self.env().caller();
+            self.move_balance(from, to, value)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: u128,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            let spender = self.env().caller();
+            let allowance = self.allowance(from, spender);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.move_balance(from, to, value)?;
+            self.allowances
+                .insert((from, spender), &(allowance - value));
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            self.allowances.insert((owner, spender), &value);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                amount: value,
+            });
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn transfer_from_spends_allowance() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut token = Psp22Token::new(1_000);
+            assert_eq!(token.balance_of(accounts.alice), 1_000);
+
+            token.approve(accounts.bob, 300).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                token.transfer_from(accounts.alice, accounts.charlie, 400, Vec::new()),
+                Err(PSP22Error::InsufficientAllowance)
+            );
+            token
+                .transfer_from(accounts.alice, accounts.charlie, 250, Vec::new())
+                .unwrap();
+            assert_eq!(token.allowance(accounts.alice, accounts.bob), 50);
+            assert_eq!(token.balance_of(accounts.alice), 750);
+            assert_eq!(token.balance_of(accounts.charlie), 250);
+            assert_eq!(
+                token.transfer(accounts.alice, 1, Vec::new()),
+                Err(PSP22Error::InsufficientBalance)
+            );
+        }
+    }
+}
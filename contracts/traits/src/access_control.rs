@@ -18,6 +18,7 @@ pub enum Role {
     PauseGuardian,
     Manager,
     EscrowAdmin,
+    Auditor,
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -223,6 +224,49 @@ impl AccessControl {
         Ok(())
     }
 
+    /// Move each of `roles` that `from` holds directly over to `to`.
+    /// Returns the number of roles moved.
+    pub fn transfer_roles(
+        &mut self,
+        actor: AccountId,
+        from: AccountId,
+        to: AccountId,
+        roles: &[Role],
+        block_number: u32,
+        timestamp: u64,
+    ) -> Result<u32, AccessControlError> {
+        self.ensure_has_role(actor, Role::Admin)?;
+        let mut moved = 0u32;
+        for role in roles {
+            if !self.role_assignments.get((from, *role)).unwrap_or(false) {
+                continue;
+            }
+            self.role_assignments.remove((from, *role));
+            self.role_assignments.insert((to, *role), &true);
+            self.write_audit(
+                actor,
+                from,
+                AuditAction::RoleRevoked,
+                Some(*role),
+                None,
+                block_number,
+                timestamp,
+            );
+            self.write_audit(
+                actor,
+                to,
+                AuditAction::RoleGranted,
+                Some(*role),
+                None,
+                block_number,
+                timestamp,
+            );
+            moved += 1;
+        }
+        self.invalidate_cache();
+        Ok(moved)
+    }
+
     pub fn grant_permission_to_role(
         &mut self,
         actor: AccountId,
@@ -473,10 +517,11 @@ impl AccessControl {
             Role::PauseGuardian => vec![Role::Admin, Role::SuperAdmin],
             Role::Manager => vec![Role::Admin, Role::SuperAdmin],
             Role::EscrowAdmin => vec![Role::Admin, Role::SuperAdmin],
+            Role::Auditor => vec![Role::Admin, Role::SuperAdmin],
         }
     }
 
-    fn all_roles(&self) -> [Role; 10] {
+    fn all_roles(&self) -> [Role; 11] {
         [
             Role::SuperAdmin,
             Role::Admin,
@@ -488,6 +533,7 @@ impl AccessControl {
             Role::PauseGuardian,
             Role::Manager,
             Role::EscrowAdmin,
+            Role::Auditor,
         ]
     }
 
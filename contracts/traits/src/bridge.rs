@@ -169,8 +169,11 @@ pub struct ChainBridgeInfo {
     pub is_active: bool,
     pub gas_multiplier: u32,      // Gas cost multiplier for this chain
     pub confirmation_blocks: u32, // Blocks to wait for confirmation
+    /// Unused; the bridge keeps per-chain allow-lists in its own storage.
     pub supported_tokens: Vec<TokenId>,
     pub chain_daily_limit: u128, // Max volume allowed to be routed to this chain per day
+    /// Per-chain override of `BridgeConfig::gas_limit_per_bridge`.
+    pub gas_limit: Option<u64>,
 }
 
 /// Bridge fee quote for cross-chain operations
@@ -451,10 +454,140 @@ pub struct BridgeHealthStatus {
     pub guardian_count: u32,
 }
 
+// =========================================================================
+// Shared bridge lifecycle events
+// =========================================================================
+//
+// Every contract that takes part in bridging (the bridge itself and the
+// property token) emits these same types, so indexers need one decoder.
+//
+// Breaking change for indexers: ink! derives an event's signature topic
+// from its path and field types, so adding `contract_kind` and
+// `event_version` gave all three events new signature topics. Indexers
+// that matched the topics the bridge contract emitted before these types
+// moved here must switch to the new ones. Any later change to a layout
+// changes its topic again and comes with a bump of `BRIDGE_EVENT_VERSION`.
+
+/// Layout version carried in every shared bridge event.
+pub const BRIDGE_EVENT_VERSION: u8 = 1;
+/// `contract_kind` of events emitted by the bridge contract.
+pub const BRIDGE_CONTRACT_KIND_BRIDGE: u8 = 1;
+/// `contract_kind` of events emitted by the property token contract.
+pub const BRIDGE_CONTRACT_KIND_PROPERTY_TOKEN: u8 = 2;
+
+/// A bridge request was created, or a multi-hop request moved on to its
+/// next leg. Batch requests emit one per token.
+#[ink::event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeRequestCreated {
+    #[ink(topic)]
+    pub request_id: u64,
+    #[ink(topic)]
+    pub token_id: TokenId,
+    #[ink(topic)]
+    pub source_chain: ChainId,
+    #[ink(topic)]
+    pub destination_chain: ChainId,
+    #[ink(topic)]
+    pub requester: AccountId,
+    pub contract_kind: u8,
+    pub event_version: u8,
+}
+
+/// An operator signed a bridge request.
+#[ink::event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeRequestSigned {
+    #[ink(topic)]
+    pub request_id: u64,
+    #[ink(topic)]
+    pub signer: AccountId,
+    pub signatures_collected: u8,
+    pub signatures_required: u8,
+    pub contract_kind: u8,
+    pub event_version: u8,
+}
+
+/// A bridge request was executed. Batch requests emit one per token.
+#[ink::event]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeExecuted {
+    #[ink(topic)]
+    pub request_id: u64,
+    #[ink(topic)]
+    pub token_id: TokenId,
+    #[ink(topic)]
+    pub transaction_hash: ink::primitives::Hash,
+    pub contract_kind: u8,
+    pub event_version: u8,
+}
+
+/// A shared bridge lifecycle event, decoded without knowing which contract
+/// emitted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeLifecycleEvent {
+    Created(BridgeRequestCreated),
+    Signed(BridgeRequestSigned),
+    Executed(BridgeExecuted),
+}
+
+impl BridgeLifecycleEvent {
+    /// Decodes a raw contract event from its signature topic (the first
+    /// topic) and its data. Returns `None` for any other event.
+    pub fn decode(signature_topic: &[u8], data: &[u8]) -> Option<Self> {
+        use ink::env::Event;
+        use scale::Decode;
+
+        let is = |topic: Option<[u8; 32]>| topic.is_some_and(|t| t[..] == *signature_topic);
+        let mut input = data;
+        if is(BridgeRequestCreated::SIGNATURE_TOPIC) {
+            BridgeRequestCreated::decode(&mut input)
+                .ok()
+                .map(Self::Created)
+        } else if is(BridgeRequestSigned::SIGNATURE_TOPIC) {
+            BridgeRequestSigned::decode(&mut input)
+                .ok()
+                .map(Self::Signed)
+        } else if is(BridgeExecuted::SIGNATURE_TOPIC) {
+            BridgeExecuted::decode(&mut input).ok().map(Self::Executed)
+        } else {
+            None
+        }
+    }
+
+    /// The `contract_kind` the event was emitted with.
+    pub fn contract_kind(&self) -> u8 {
+        match self {
+            Self::Created(event) => event.contract_kind,
+            Self::Signed(event) => event.contract_kind,
+            Self::Executed(event) => event.contract_kind,
+        }
+    }
+}
+
 // =========================================================================
 // Trait Definitions
 // =========================================================================
 
+/// Receiving side of an inbound cross-chain transfer.
+///
+/// Implemented by the property token contract so the bridge can hand over a
+/// relayed token once the operator quorum has been verified. Returns the
+/// local token ID, or `None` if the token contract refused the mint. While
+/// metadata preservation is enabled the bridge has already checked
+/// `metadata` against the hash recorded on the source chain.
+#[ink::trait_definition]
+pub trait BridgedTokenReceiver {
+    #[ink(message)]
+    fn receive_bridged_token(
+        &mut self,
+        source_chain: ChainId,
+        original_token_id: TokenId,
+        recipient: AccountId,
+        metadata: PropertyMetadata,
+    ) -> Option<TokenId>;
+}
+
 /// Core cross-chain bridge interface for property tokens.
 ///
 /// Provides the fundamental operations required to move a property token
@@ -586,6 +719,11 @@ pub trait AdvancedBridge {
 
     /// Submit a validator signature approving or rejecting a pending bridge request.
     ///
+    /// Signing a request past its expiry block must fail without changing the
+    /// request's status; the transition to `Expired` (and any refund) happens
+    /// only through the implementation's explicit expiry path, so a rejected
+    /// signature never leaves partial state behind.
+    ///
     /// # Parameters
     /// - `bridge_request_id` — ID returned by [`initiate_bridge_multisig`].
     /// - `approve` — `true` to approve, `false` to reject.
@@ -635,3 +773,58 @@ pub trait AdvancedBridge {
     /// account has no prior bridge activity.
     fn get_bridge_history(&self, account: AccountId) -> Vec<BridgeTransaction>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::env::Event;
+    use scale::Encode;
+
+    #[test]
+    fn lifecycle_decoder_reads_events_from_any_contract() {
+        let executed = BridgeExecuted {
+            request_id: 7,
+            token_id: 42,
+            transaction_hash: ink::primitives::Hash::from([3; 32]),
+            contract_kind: BRIDGE_CONTRACT_KIND_PROPERTY_TOKEN,
+            event_version: BRIDGE_EVENT_VERSION,
+        };
+        let topic = BridgeExecuted::SIGNATURE_TOPIC.unwrap();
+        let decoded = BridgeLifecycleEvent::decode(&topic, &executed.encode()).unwrap();
+        assert_eq!(decoded.contract_kind(), BRIDGE_CONTRACT_KIND_PROPERTY_TOKEN);
+        assert_eq!(decoded, BridgeLifecycleEvent::Executed(executed.clone()));
+
+        // Unknown topics and truncated data are not guessed at.
+        assert_eq!(
+            BridgeLifecycleEvent::decode(&[0; 32], &executed.encode()),
+            None
+        );
+        let created = BridgeRequestCreated::SIGNATURE_TOPIC.unwrap();
+        assert_eq!(BridgeLifecycleEvent::decode(&created, &[1, 2]), None);
+    }
+
+    /// The signature topics indexers match on. A change here breaks every
+    /// indexer and must come with a bump of `BRIDGE_EVENT_VERSION`.
+    #[test]
+    fn lifecycle_signature_topics_are_pinned() {
+        let hex = |topic: Option<[u8; 32]>| -> String {
+            topic
+                .unwrap()
+                .iter()
+                .map(|byte| ink::prelude::format!("{byte:02x}"))
+                .collect()
+        };
+        assert_eq!(
+            hex(BridgeRequestCreated::SIGNATURE_TOPIC),
+            "4f62f3d7286e3c053d9a2ff8a0e22d20a55840fa5c1f7cd86811f4da5ea2e1b9"
+        );
+        assert_eq!(
+            hex(BridgeRequestSigned::SIGNATURE_TOPIC),
+            "7c4cee7782ed24634b9cb0b06143c5f38c13c798e0ac93fc6685273080c0faae"
+        );
+        assert_eq!(
+            hex(BridgeExecuted::SIGNATURE_TOPIC),
+            "589d77d176310d3848dab5a5659c68a843bcfaaac2dccb3ec372635907d3c14b"
+        );
+    }
+}
@@ -43,6 +43,26 @@ pub trait ComplianceChecker {
     /// Returns true if the account meets current compliance requirements
     #[ink(message)]
     fn is_compliant(&self, account: ink::primitives::AccountId) -> bool;
+
+    /// Returns a reference to the compliance record that makes `account`
+    /// compliant, or `None` if it is not. Callers store the reference as
+    /// evidence of the check.
+    #[ink(message)]
+    fn attest_compliance(
+        &self,
+        account: ink::primitives::AccountId,
+    ) -> Option<ink::primitives::Hash>;
+}
+
+/// Builds a call reference to the [`ComplianceChecker`] deployed at `registry`.
+///
+/// Shared by every contract that gates operations on the compliance registry
+/// so the cross-contract call is constructed in one place.
+pub fn compliance_checker(
+    registry: ink::primitives::AccountId,
+) -> ink::contract_ref!(ComplianceChecker, ink::env::DefaultEnvironment) {
+    use ink::env::call::FromAccountId;
+    FromAccountId::from_account_id(registry)
 }
 
 /// Trait for automated tax withholding in property transactions
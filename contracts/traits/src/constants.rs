@@ -10,6 +10,15 @@
 /// Default: 3600 (1 hour).
 pub const DEFAULT_MAX_PRICE_STALENESS: u64 = 3600;
 
+/// Half-width of a valuation's confidence interval per confidence point
+/// short of 100, in basis points of the valuation. 50 bp gives ±50% at
+/// confidence 0 and an exact value at confidence 100.
+pub const CONFIDENCE_INTERVAL_BP_PER_POINT: u128 = 50;
+
+/// Confidence points a valuation loses for each full `max_price_staleness`
+/// period that has passed since it was last updated.
+pub const STALENESS_PENALTY_POINTS_PER_PERIOD: u32 = 10;
+
 /// Minimum number of oracle sources required for a valid valuation.
 pub const DEFAULT_MIN_SOURCES_REQUIRED: u32 = 2;
 
@@ -34,6 +43,12 @@ pub const ORACLE_REPUTATION_PENALTY: u32 = 50;
 /// Multiplier for coefficient of variance calculations (basis points).
 pub const COEFFICIENT_VARIANCE_MULTIPLIER: u32 = 10_000;
 
+/// Source identifier used when a tax assessment stands in for a live source.
+pub const ASSESSMENT_SOURCE_ID: &str = "assessment";
+
+/// Aggregation weight of the assessment fallback source (live sources use 0-100).
+pub const ASSESSMENT_SOURCE_WEIGHT: u32 = 10;
+
 // ── Bridge Constants ─────────────────────────────────────────────────────────
 
 /// Default gas multiplier for bridge operations (100 = 1.0x).
@@ -46,6 +61,13 @@ pub const DEFAULT_CONFIRMATION_BLOCKS: u32 = 6;
 /// Base gas cost for a bridge operation (in gas units).
 pub const BRIDGE_BASE_GAS: u64 = 100_000;
 
+/// Gas charged per byte of SCALE-encoded property metadata carried by a
+/// bridge request.
+pub const BRIDGE_METADATA_GAS_PER_BYTE: u64 = 100;
+
+/// Gas charged per signature a bridge request has to collect.
+pub const BRIDGE_SIGNATURE_GAS: u64 = 5_000;
+
 // ── IPFS / Metadata Constants ────────────────────────────────────────────────
 
 /// Maximum length for property location strings.
@@ -72,6 +94,15 @@ pub const MAX_DOCUMENTS_PER_PROPERTY: u32 = 100;
 /// Maximum total pinned size per property (500 MB).
 pub const MAX_PINNED_SIZE_PER_PROPERTY: u64 = 500_000_000;
 
+/// Maximum number of key-value extensions on versioned property metadata.
+pub const MAX_METADATA_EXTENSIONS: u32 = 32;
+
+/// Maximum length of a metadata extension key.
+pub const MAX_EXTENSION_KEY_LENGTH: u32 = 64;
+
+/// Maximum length of a metadata extension value.
+pub const MAX_EXTENSION_VALUE_LENGTH: u32 = 1_024;
+
 // ── Token Constants ──────────────────────────────────────────────────────────
 
 /// Precision scaling factor for token amounts (1e12).
@@ -197,11 +228,63 @@ pub const MAX_REASON_LENGTH: u32 = 2_000;
 /// Maximum length for URL strings (evidence_url, metadata_url, documents_url).
 pub const MAX_URL_LENGTH: u32 = 2_048;
 
+/// Maximum length in bytes of an encrypted evidence pointer on a badge
+/// verification request.
+pub const MAX_ENCRYPTED_POINTER_LENGTH: u32 = 4_096;
+
+/// Maximum length for property bundle names.
+pub const MAX_BUNDLE_NAME_LENGTH: u32 = 128;
+
+/// Maximum length for insurer names on property insurance records.
+pub const MAX_INSURER_NAME_LENGTH: u32 = 128;
+
+/// Maximum length in bytes of the label on a pinned oracle valuation.
+pub const MAX_PIN_LABEL_LENGTH: u32 = 64;
+
+/// Blocks a scheduled registry treasury withdrawal must wait before it can
+/// be executed (~10 minutes at 6-second block time).
+pub const TREASURY_WITHDRAWAL_TIMELOCK_BLOCKS: u32 = 100;
+
+/// Blocks a buyer's purchase offer stays open for the owner to accept, and
+/// after which an accepted but unsettled offer can be withdrawn (~24 hours at
+/// 6-second block time).
+pub const PURCHASE_OFFER_TIMEOUT_BLOCKS: u32 = 14_400;
+
+/// Milliseconds per day bucket in the registry's time-window indexes (block
+/// timestamps are in milliseconds).
+pub const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// Maximum number of days a single time-window query may span.
+pub const MAX_TIME_WINDOW_DAYS: u32 = 366;
+
+/// Default time a badge verification request may wait for review before it
+/// counts as overdue, in milliseconds (14 days).
+pub const DEFAULT_VERIFICATION_SLA_MS: u64 = 14 * MILLIS_PER_DAY;
+
+/// How long an encumbrance holder's consent to a transfer stays valid, in
+/// milliseconds (7 days).
+pub const ENCUMBRANCE_CONSENT_TTL_MS: u64 = 7 * MILLIS_PER_DAY;
+
+/// Maximum number of open encumbrances recorded against one property.
+pub const MAX_ENCUMBRANCES_PER_PROPERTY: u32 = 16;
+
+/// How long the registry remembers the result of a call made with an
+/// idempotency key, in milliseconds (1 day).
+pub const IDEMPOTENCY_KEY_TTL_MS: u64 = MILLIS_PER_DAY;
+
+/// Maximum number of idempotency keys the registry stores at once; the
+/// oldest key is evicted when a new one would exceed it.
+pub const MAX_IDEMPOTENCY_KEYS: u32 = 1_024;
+
 /// Maximum pause duration in seconds (30 days).
 pub const MAX_PAUSE_DURATION: u64 = 2_592_000;
 
 /// Minimum pause duration in seconds (1 minute).
 pub const MIN_PAUSE_DURATION: u64 = 60;
+
+/// Default longest pause a guardian may impose, in seconds (24 hours).
+/// Guardian pauses always auto-resume; only the admin may pause indefinitely.
+pub const DEFAULT_GUARDIAN_MAX_PAUSE_DURATION: u64 = 86_400;
 /// Default early withdrawal penalty in basis points (10% = 1000 bps).
 pub const DEFAULT_EARLY_WITHDRAWAL_PENALTY_BPS: u128 = 1_000;
 
@@ -220,3 +220,38 @@ pub fn finalize_randomness(secrets: &[[u8; 32]]) -> Hash {
     }
     hash_blake2b256(&xored)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Encode;
+
+    /// Truncate an encoding to 32 bytes, as record hashes were once built.
+    fn truncated(value: &impl Encode) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        let encoded = value.encode();
+        let len = encoded.len().min(32);
+        out[..len].copy_from_slice(&encoded[..len]);
+        out
+    }
+
+    #[test]
+    fn test_hash_encoded_matches_blake2b_of_encoding() {
+        let record = (7u64, AccountId::from([3u8; 32]), 1_000u128);
+        assert_eq!(hash_encoded(&record), hash_blake2b256(&record.encode()));
+        assert_eq!(hash_encoded(&record), hash_encoded(&record));
+    }
+
+    #[test]
+    fn test_inputs_sharing_a_32_byte_prefix_hash_differently() {
+        // Same owner; the token ID lies past the first 32 bytes.
+        let first = (AccountId::from([9u8; 32]), 1u64);
+        let second = (AccountId::from([9u8; 32]), 2u64);
+        assert_eq!(truncated(&first), truncated(&second));
+        assert_ne!(hash_encoded(&first), hash_encoded(&second));
+
+        // Short encodings were zero padded, so `1u8` and `1u64` collided.
+        assert_eq!(truncated(&1u8), truncated(&1u64));
+        assert_ne!(hash_encoded(&1u8), hash_encoded(&1u64));
+    }
+}
@@ -68,6 +68,7 @@ pub trait ContractError: fmt::Debug + fmt::Display + Encode + Decode {
             9000..=9999 => ErrorCategory::Staking,
             10000..=10999 => ErrorCategory::Monitoring,
             11000..=11999 => ErrorCategory::EventBus,
+            12000..=12999 => ErrorCategory::Registry,
             _ => ErrorCategory::Unknown,
         }
     }
@@ -109,6 +110,7 @@ pub enum ErrorCategory {
     Staking,
     Monitoring,
     EventBus,
+    Registry,
     Unknown,
 }
 
@@ -127,6 +129,7 @@ impl fmt::Display for ErrorCategory {
             ErrorCategory::Staking => write!(f, "Staking"),
             ErrorCategory::Monitoring => write!(f, "Monitoring"),
             ErrorCategory::EventBus => write!(f, "EventBus"),
+            ErrorCategory::Registry => write!(f, "Registry"),
             ErrorCategory::Unknown => write!(f, "Unknown"),
         }
     }
@@ -223,6 +226,131 @@ impl ContractError for CommonError {
     }
 }
 
+// =============================================================================
+// Shared Cross-Contract Error
+// =============================================================================
+
+/// Error type shared by every PropChain contract for propagating failures
+/// across contract boundaries.
+///
+/// Causes that several contracts report under their own names (an
+/// unauthorized caller, a missing property, bad metadata, ...) map to one
+/// variant with a stable code in the common range. Anything without a
+/// shared equivalent is carried as [`PropChainError::Contract`] holding the
+/// callee's own error code, so the original cause is never lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(TypeInfo))]
+pub enum PropChainError {
+    /// Caller lacks the required permissions
+    Unauthorized,
+    /// Invalid parameters provided to function
+    InvalidParameters,
+    /// Resource not found (generic)
+    NotFound,
+    /// Insufficient funds or balance
+    InsufficientFunds,
+    /// Operation not allowed in current state
+    InvalidState,
+    /// Property does not exist
+    PropertyNotFound,
+    /// Property metadata is invalid or malformed
+    InvalidMetadata,
+    /// The callee is paused
+    Paused,
+    /// Reentrancy guard detected a reentrant call
+    ReentrantCall,
+    /// Contract-specific error identified by its numeric code
+    Contract(u32),
+}
+
+impl PropChainError {
+    /// Rebuilds the shared error from a numeric code.
+    ///
+    /// Codes of shared variants map back to that variant; every other code
+    /// becomes [`PropChainError::Contract`]. `from_code(e.error_code())`
+    /// always returns `e`.
+    pub fn from_code(code: u32) -> Self {
+        match code {
+            common_codes::UNAUTHORIZED => PropChainError::Unauthorized,
+            common_codes::INVALID_PARAMETERS => PropChainError::InvalidParameters,
+            common_codes::NOT_FOUND => PropChainError::NotFound,
+            common_codes::INSUFFICIENT_FUNDS => PropChainError::InsufficientFunds,
+            common_codes::INVALID_STATE => PropChainError::InvalidState,
+            common_codes::PROPERTY_NOT_FOUND => PropChainError::PropertyNotFound,
+            common_codes::INVALID_METADATA => PropChainError::InvalidMetadata,
+            common_codes::PAUSED => PropChainError::Paused,
+            common_codes::REENTRANT_CALL => PropChainError::ReentrantCall,
+            other => PropChainError::Contract(other),
+        }
+    }
+}
+
+impl fmt::Display for PropChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropChainError::Contract(code) => write!(f, "Contract error {code}"),
+            other => write!(f, "{}", other.error_description()),
+        }
+    }
+}
+
+impl ContractError for PropChainError {
+    fn error_code(&self) -> u32 {
+        match self {
+            PropChainError::Unauthorized => common_codes::UNAUTHORIZED,
+            PropChainError::InvalidParameters => common_codes::INVALID_PARAMETERS,
+            PropChainError::NotFound => common_codes::NOT_FOUND,
+            PropChainError::InsufficientFunds => common_codes::INSUFFICIENT_FUNDS,
+            PropChainError::InvalidState => common_codes::INVALID_STATE,
+            PropChainError::PropertyNotFound => common_codes::PROPERTY_NOT_FOUND,
+            PropChainError::InvalidMetadata => common_codes::INVALID_METADATA,
+            PropChainError::Paused => common_codes::PAUSED,
+            PropChainError::ReentrantCall => common_codes::REENTRANT_CALL,
+            PropChainError::Contract(code) => *code,
+        }
+    }
+
+    fn error_description(&self) -> &'static str {
+        match self {
+            PropChainError::Unauthorized => {
+                "Caller does not have permission to perform this operation"
+            }
+            PropChainError::InvalidParameters => "One or more function parameters are invalid",
+            PropChainError::NotFound => "The requested resource does not exist",
+            PropChainError::InsufficientFunds => {
+                "Account has insufficient balance for this operation"
+            }
+            PropChainError::InvalidState => "Cannot perform this operation in the current state",
+            PropChainError::PropertyNotFound => "The requested property does not exist",
+            PropChainError::InvalidMetadata => "The property metadata is invalid or malformed",
+            PropChainError::Paused => "The called contract is paused",
+            PropChainError::ReentrantCall => "Reentrant call detected",
+            PropChainError::Contract(_) => "The called contract returned an error",
+        }
+    }
+
+    fn error_i18n_key(&self) -> &'static str {
+        match self {
+            PropChainError::Unauthorized => "common.unauthorized",
+            PropChainError::InvalidParameters => "common.invalid_parameters",
+            PropChainError::NotFound => "common.not_found",
+            PropChainError::InsufficientFunds => "common.insufficient_funds",
+            PropChainError::InvalidState => "common.invalid_state",
+            PropChainError::PropertyNotFound => "common.property_not_found",
+            PropChainError::InvalidMetadata => "common.invalid_metadata",
+            PropChainError::Paused => "common.paused",
+            PropChainError::ReentrantCall => "common.reentrant_call",
+            PropChainError::Contract(_) => "common.contract_error",
+        }
+    }
+}
+
+impl From<CommonError> for PropChainError {
+    fn from(error: CommonError) -> Self {
+        PropChainError::from_code(error.error_code())
+    }
+}
+
 // =============================================================================
 // Error Code Constants
 // =============================================================================
@@ -239,6 +367,10 @@ pub mod common_codes {
     pub const NOT_IMPLEMENTED: u32 = 8;
     pub const TIMEOUT: u32 = 9;
     pub const DUPLICATE: u32 = 10;
+    pub const PROPERTY_NOT_FOUND: u32 = 11;
+    pub const INVALID_METADATA: u32 = 12;
+    pub const PAUSED: u32 = 13;
+    pub const REENTRANT_CALL: u32 = 14;
 }
 
 /// PropertyToken error codes (1000-1999)
@@ -336,6 +468,18 @@ pub mod bridge_codes {
     pub const BRIDGE_NOT_GUARDIAN: u32 = 3018;
     pub const BRIDGE_TRAVEL_RULE_DATA_REQUIRED: u32 = 3019;
     pub const BRIDGE_TRAVEL_RULE_DATA_ALREADY_SUBMITTED: u32 = 3020;
+    pub const BRIDGE_INVALID_SIGNATURE: u32 = 3021;
+    pub const BRIDGE_INBOUND_ALREADY_PROCESSED: u32 = 3022;
+    pub const BRIDGE_CHAIN_PAUSED: u32 = 3023;
+    pub const BRIDGE_INSUFFICIENT_FEE: u32 = 3024;
+    pub const BRIDGE_FEE_TRANSFER_FAILED: u32 = 3025;
+    pub const BRIDGE_TOO_MANY_OPEN_REQUESTS: u32 = 3026;
+    pub const BRIDGE_CHAIN_RATE_LIMITED: u32 = 3027;
+    pub const BRIDGE_METADATA_CORRUPTION: u32 = 3028;
+    pub const BRIDGE_MULTI_HOP_NOT_ALLOWED: u32 = 3029;
+    pub const BRIDGE_BATCH_TOO_LARGE: u32 = 3030;
+    pub const BRIDGE_REQUEST_FROZEN: u32 = 3031;
+    pub const BRIDGE_INVALID_CONFIG: u32 = 3032;
 }
 
 /// Oracle error codes (4000-4999)
@@ -474,6 +618,14 @@ pub mod event_bus_codes {
     pub const EVENT_BUS_REENTRANT_CALL: u32 = 11007;
 }
 
+/// Property registry error codes (12000-12999)
+///
+/// Registry errors are numbered by their position in the registry's `Error`
+/// enum, offset by [`REGISTRY_ERROR_BASE`](registry_codes::REGISTRY_ERROR_BASE).
+pub mod registry_codes {
+    pub const REGISTRY_ERROR_BASE: u32 = 12000;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -530,4 +682,64 @@ mod tests {
             "duplicate compliance error codes detected"
         );
     }
+
+    #[test]
+    fn prop_chain_error_codes_round_trip() {
+        let all = [
+            PropChainError::Unauthorized,
+            PropChainError::InvalidParameters,
+            PropChainError::NotFound,
+            PropChainError::InsufficientFunds,
+            PropChainError::InvalidState,
+            PropChainError::PropertyNotFound,
+            PropChainError::InvalidMetadata,
+            PropChainError::Paused,
+            PropChainError::ReentrantCall,
+            PropChainError::Contract(oracle_codes::ORACLE_PRICE_FEED_ERROR),
+        ];
+        for error in all {
+            assert_eq!(PropChainError::from_code(error.error_code()), error);
+        }
+        assert_eq!(
+            PropChainError::from_code(bridge_codes::BRIDGE_PAUSED),
+            PropChainError::Contract(bridge_codes::BRIDGE_PAUSED)
+        );
+    }
+
+    #[test]
+    fn common_errors_convert_to_shared_error() {
+        assert_eq!(
+            PropChainError::from(CommonError::Unauthorized),
+            PropChainError::Unauthorized
+        );
+        assert_eq!(
+            PropChainError::from(CommonError::InvalidState),
+            PropChainError::InvalidState
+        );
+        assert_eq!(
+            PropChainError::from(CommonError::Timeout),
+            PropChainError::Contract(common_codes::TIMEOUT)
+        );
+    }
+
+    #[test]
+    fn oracle_errors_convert_to_shared_error() {
+        use crate::oracle::OracleError;
+        assert_eq!(
+            PropChainError::from(OracleError::Unauthorized),
+            PropChainError::Unauthorized
+        );
+        assert_eq!(
+            PropChainError::from(OracleError::PropertyNotFound),
+            PropChainError::PropertyNotFound
+        );
+        assert_eq!(
+            PropChainError::from(OracleError::InvalidParameters),
+            PropChainError::InvalidParameters
+        );
+        assert_eq!(
+            PropChainError::from(OracleError::PriceFeedError),
+            PropChainError::Contract(oracle_codes::ORACLE_PRICE_FEED_ERROR)
+        );
+    }
 }
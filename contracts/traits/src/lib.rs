@@ -10,6 +10,7 @@ pub mod di;
 pub mod errors;
 pub mod randomness;
 pub mod reentrancy_guard;
+pub mod validation;
 
 pub use access_control::*;
 pub use crypto::*;
@@ -26,9 +27,12 @@ pub mod compliance;
 pub mod dex;
 pub mod event_bus;
 pub mod fee;
+pub mod marketplace;
 pub mod multicall;
 pub mod oracle;
 pub mod property;
+pub mod psp22;
+pub mod psp34;
 
 // =========================================================================
 // Re-exports for backward compatibility
@@ -45,11 +49,14 @@ pub use bridge::*;
 pub use dex::*;
 pub use oracle::*;
 pub use property::*;
+pub use psp22::{PSP22Error, PSP22};
+pub use psp34::{Id, PSP34Enumerable, PSP34Error, PSP34Metadata, PSP34};
 
 // Re-export compliance and fee module contents (types are defined in those modules)
 pub use compliance::*;
 pub use event_bus::*;
 pub use fee::*;
+pub use marketplace::*;
 pub use multicall::*;
 
 #[cfg(not(feature = "std"))]
@@ -393,8 +400,11 @@ pub struct ChainBridgeInfo {
     pub is_active: bool,
     pub gas_multiplier: u32,
     pub confirmation_blocks: u32,
+    /// Unused; the bridge keeps per-chain allow-lists in its own storage.
     pub supported_tokens: Vec<TokenId>,
     pub chain_daily_limit: u128,
+    /// Per-chain override of `BridgeConfig::gas_limit_per_bridge`.
+    pub gas_limit: Option<u64>,
 }
 
 /// Event emitted when an operator is caught double-signing or equivocating
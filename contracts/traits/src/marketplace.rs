@@ -0,0 +1,142 @@
+//! Marketplace type definitions and trait interfaces.
+//!
+//! Listings and auctions live in a marketplace contract separate from the
+//! property registry. The registry exposes [`TransferAgent`] so that a
+//! marketplace it trusts can move a sold property without a per-property
+//! approval; every other transfer check still runs on the registry side.
+
+use crate::errors::PropChainError;
+use ink::primitives::AccountId;
+
+// =========================================================================
+// Types
+// =========================================================================
+
+/// How a listed property is sold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ListingKind {
+    /// Sold to the first buyer paying the asking price.
+    FixedPrice,
+    /// Sold to the highest bidder once bidding closes at `ends_at` (block
+    /// number). The listing price is the reserve.
+    Auction { ends_at: u32 },
+}
+
+/// A property offered for sale on a marketplace.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Listing {
+    pub listing_id: u64,
+    pub property_id: u64,
+    pub seller: AccountId,
+    /// Asking price, or reserve price for auctions.
+    pub price: u128,
+    pub kind: ListingKind,
+    /// Highest bid so far, for auctions.
+    pub highest_bid: Option<(AccountId, u128)>,
+    /// `false` once the listing is sold, settled or delisted.
+    pub active: bool,
+}
+
+/// Errors returned by [`Marketplace`] implementations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum MarketplaceError {
+    /// Caller is not allowed to perform this operation
+    Unauthorized,
+    /// Listing does not exist or is no longer active
+    ListingNotFound,
+    /// The property is already listed
+    AlreadyListed,
+    /// Price or auction duration is invalid
+    InvalidParameters,
+    /// Operation does not apply to this kind of listing
+    WrongListingKind,
+    /// Value sent does not match the price, or does not beat the current bid
+    InsufficientPayment,
+    /// Bidding is closed for this auction
+    AuctionEnded,
+    /// Bidding is still open for this auction
+    AuctionNotEnded,
+    /// Paying the seller or refunding a bidder failed
+    PaymentFailed,
+    /// The registry rejected the transfer; carries the registry's cause
+    TransferFailed(PropChainError),
+}
+
+// =========================================================================
+// Trait Definitions
+// =========================================================================
+
+/// Marketplace for listing and selling registered properties.
+#[ink::trait_definition]
+pub trait Marketplace {
+    /// Lists `property_id` at `price`. Passing `auction_blocks` opens an
+    /// auction closing that many blocks from now, with `price` as reserve.
+    /// Only the current owner may list. A listing left by a previous owner
+    /// is closed and replaced. Returns the listing ID.
+    #[ink(message)]
+    fn list(
+        &mut self,
+        property_id: u64,
+        price: u128,
+        auction_blocks: Option<u32>,
+    ) -> Result<u64, MarketplaceError>;
+
+    /// Withdraws an active listing (seller only). Any standing bid is refunded.
+    #[ink(message)]
+    fn delist(&mut self, listing_id: u64) -> Result<(), MarketplaceError>;
+
+    /// Buys a fixed-price listing; the value sent must equal the price.
+    #[ink(message, payable)]
+    fn buy(&mut self, listing_id: u64) -> Result<(), MarketplaceError>;
+
+    /// Bids on an auction with the value sent; it must meet the reserve and
+    /// beat the current highest bid, which is refunded.
+    #[ink(message, payable)]
+    fn bid(&mut self, listing_id: u64) -> Result<(), MarketplaceError>;
+
+    /// Closes an auction once bidding has ended, transferring the property
+    /// to the highest bidder and paying the seller. If the registry refuses
+    /// the transfer, the auction is closed and the bid refunded instead.
+    /// Callable by anyone.
+    #[ink(message)]
+    fn settle(&mut self, listing_id: u64) -> Result<(), MarketplaceError>;
+
+    /// Returns a listing by ID.
+    #[ink(message)]
+    fn get_listing(&self, listing_id: u64) -> Option<Listing>;
+}
+
+/// Registry-side hook letting trusted agents (such as a marketplace)
+/// transfer properties.
+#[ink::trait_definition]
+pub trait TransferAgent {
+    /// Transfers `property_id` from `from` to `to` on behalf of its owner.
+    ///
+    /// The caller must be a trusted agent and `from` the current owner. The
+    /// per-property approval is skipped, but pause, compliance and identity
+    /// checks still apply.
+    #[ink(message)]
+    fn agent_transfer(
+        &mut self,
+        property_id: u64,
+        from: AccountId,
+        to: AccountId,
+    ) -> Result<(), PropChainError>;
+
+    /// Returns the current owner of `property_id`.
+    #[ink(message)]
+    fn property_owner(&self, property_id: u64) -> Option<AccountId>;
+
+    /// Returns whether `agent` may call [`TransferAgent::agent_transfer`].
+    #[ink(message)]
+    fn is_transfer_agent(&self, agent: AccountId) -> bool;
+}
@@ -4,7 +4,7 @@
 //! definitions used across the PropChain ecosystem for property valuations,
 //! price feeds, and market analysis.
 
-use crate::errors::{ContractError, ErrorCategory};
+use crate::errors::{ContractError, ErrorCategory, PropChainError};
 use crate::property::PropertyType;
 use ink::prelude::string::String;
 use ink::prelude::vec::Vec;
@@ -78,6 +78,18 @@ impl core::fmt::Display for OracleError {
     }
 }
 
+impl From<OracleError> for PropChainError {
+    fn from(error: OracleError) -> Self {
+        match error {
+            OracleError::Unauthorized => PropChainError::Unauthorized,
+            OracleError::PropertyNotFound => PropChainError::PropertyNotFound,
+            OracleError::InvalidParameters => PropChainError::InvalidParameters,
+            OracleError::CircuitBreakerActive => PropChainError::Paused,
+            other => PropChainError::Contract(other.error_code()),
+        }
+    }
+}
+
 impl ContractError for OracleError {
     fn error_code(&self) -> u32 {
         use crate::errors::oracle_codes;
@@ -216,6 +228,67 @@ pub struct ValuationWithConfidence {
     pub volatility_index: u32,             // Market volatility 0-100
     pub confidence_interval: (u128, u128), // Min and max valuation range
     pub outlier_sources: u32,              // Number of outlier sources detected
+    /// Rental yield against this valuation, if rental data was submitted
+    pub yield_metrics: Option<YieldMetrics>,
+    /// Confidence points deducted because the valuation is stale; already
+    /// reflected in `confidence_interval`
+    pub staleness_penalty: u32,
+}
+
+/// Valuation frozen at a point of interest, such as an escrow closing
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PinnedValuation {
+    pub valuation: PropertyValuation,
+    /// Why the valuation was pinned, e.g. `escrow:42`
+    pub label: String,
+    pub block_number: u32,
+    pub pinned_at: u64,
+    pub pinned_by: AccountId,
+}
+
+/// Rental income reported for a property
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RentalData {
+    pub monthly_rent: u128,
+    /// Share of the property let, in basis points (0-10000)
+    pub occupancy_bp: u32,
+    pub recorded_at: u64,
+}
+
+/// Municipal tax assessment of a property for one tax year
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct TaxAssessment {
+    pub assessed_value: u128,
+    pub tax_year: u32,
+    /// Assessing authority, e.g. the county assessor's office
+    pub authority: String,
+    pub recorded_at: u64,
+}
+
+/// Yield of a property's latest rent against its latest valuation
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct YieldMetrics {
+    /// Annual rent as a share of valuation, in basis points
+    pub gross_yield_bp: u32,
+    pub occupancy_bp: u32,
+    /// When the rental data was submitted
+    pub last_updated: u64,
 }
 
 /// Volatility metrics for market analysis
@@ -248,6 +321,21 @@ pub struct ComparableProperty {
     pub adjustment_factor: i32, // Adjustment factor (+/- percentage)
 }
 
+/// Condition under which a price alert fires
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum AlertKind {
+    /// Valuation moved by at least `bp` basis points in one update
+    PercentChange { bp: u32 },
+    /// Valuation rose above `value`
+    Above { value: u128 },
+    /// Valuation fell below `value`
+    Below { value: u128 },
+}
+
 /// Price alert configuration
 #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -255,6 +343,23 @@ pub struct ComparableProperty {
     derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
 )]
 pub struct PriceAlert {
+    pub property_id: u64,
+    pub kind: AlertKind,
+    pub alert_address: AccountId, // Address to notify
+    pub last_triggered: u64,      // Last time alert was triggered
+    pub is_active: bool,
+    /// Whether an `Above`/`Below` level is currently crossed. The alert
+    /// fires again only after the valuation has moved back across it.
+    pub level_crossed: bool,
+}
+
+/// Percentage-only price alert, as stored before `AlertKind` existed
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LegacyPriceAlert {
     pub property_id: u64,
     pub threshold_percentage: u32, // Alert threshold (e.g., 5 for 5%)
     pub alert_address: AccountId,  // Address to notify
@@ -262,6 +367,21 @@ pub struct PriceAlert {
     pub is_active: bool,
 }
 
+impl From<LegacyPriceAlert> for PriceAlert {
+    fn from(legacy: LegacyPriceAlert) -> Self {
+        Self {
+            property_id: legacy.property_id,
+            kind: AlertKind::PercentChange {
+                bp: legacy.threshold_percentage.saturating_mul(100),
+            },
+            alert_address: legacy.alert_address,
+            last_triggered: legacy.last_triggered,
+            is_active: legacy.is_active,
+            level_crossed: false,
+        }
+    }
+}
+
 /// Oracle source configuration
 #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -391,6 +511,10 @@ pub trait Oracle {
     #[ink(message)]
     fn request_valuation(&mut self, property_id: u64) -> Result<u64, OracleError>;
 
+    /// Pin the current valuation under a label; returns the pin index
+    #[ink(message)]
+    fn pin_valuation(&mut self, property_id: u64, label: String) -> Result<u32, OracleError>;
+
     /// Batch request valuations for multiple properties
     #[ink(message)]
     fn batch_request_valuations(&mut self, property_ids: Vec<u64>)
@@ -461,3 +585,40 @@ pub trait OracleRegistry {
     #[ink(message)]
     fn detect_anomalies(&self, property_id: u64, new_valuation: u128) -> bool;
 }
+
+/// Condition the oracle reports against a property in the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum OracleFlag {
+    /// Valuation fell by more than the oracle's collapse threshold within
+    /// its window; the flag value is the drop in basis points
+    ValuationCollapse,
+}
+
+/// Registry-side hook the oracle pushes significant valuation changes to.
+#[ink::trait_definition]
+pub trait ValuationSink {
+    /// Records `valuation` for `property_id`. Only the registry's configured
+    /// oracle may call this.
+    #[ink(message)]
+    fn sync_valuation_from_oracle(
+        &mut self,
+        property_id: u64,
+        valuation: u128,
+        confidence: u32,
+    ) -> Result<(), PropChainError>;
+
+    /// Records `flag` against `property_id`, with `value` measuring the
+    /// condition. Only the registry's configured oracle or an account
+    /// holding `Role::OracleAdmin` on it may call this.
+    #[ink(message)]
+    fn oracle_flag_property(
+        &mut self,
+        property_id: u64,
+        flag: OracleFlag,
+        value: u128,
+    ) -> Result<(), PropChainError>;
+}
@@ -26,6 +26,107 @@ pub struct PropertyMetadata {
     pub documents_url: String,
 }
 
+/// Layout version of a property's stored metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum MetadataVersion {
+    /// [`PropertyMetadata`] only.
+    V1,
+    /// [`PropertyMetadata`] plus a [`MetadataExtension`].
+    V2,
+}
+
+/// Fields added to property metadata in [`MetadataVersion::V2`].
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct MetadataExtension {
+    pub property_type: PropertyType,
+    /// ISO 3166-1 alpha-2 country code, e.g. `*b"DE"`.
+    pub country_code: [u8; 2],
+    /// Free-form key-value attributes.
+    pub extensions: Vec<(String, String)>,
+}
+
+impl Default for MetadataExtension {
+    /// The extension implied by V1 metadata: no type, country or attributes.
+    fn default() -> Self {
+        Self {
+            property_type: PropertyType::Unspecified,
+            country_code: [0u8; 2],
+            extensions: Vec::new(),
+        }
+    }
+}
+
+/// Property metadata, version 2.
+///
+/// Carries every [`PropertyMetadata`] field plus the [`MetadataExtension`]
+/// fields. Contracts keep storing the V1 struct and hold the extension
+/// separately, so existing storage and callers are unaffected.
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PropertyMetadataV2 {
+    pub location: String,
+    pub size: u64,
+    pub legal_description: String,
+    pub valuation: u128,
+    pub documents_url: String,
+    pub property_type: PropertyType,
+    /// ISO 3166-1 alpha-2 country code, e.g. `*b"DE"`; zeroes when unknown.
+    pub country_code: [u8; 2],
+    /// Free-form key-value attributes.
+    pub extensions: Vec<(String, String)>,
+}
+
+impl PropertyMetadataV2 {
+    /// Joins V1 metadata with its extension.
+    pub fn from_parts(metadata: PropertyMetadata, extension: MetadataExtension) -> Self {
+        Self {
+            location: metadata.location,
+            size: metadata.size,
+            legal_description: metadata.legal_description,
+            valuation: metadata.valuation,
+            documents_url: metadata.documents_url,
+            property_type: extension.property_type,
+            country_code: extension.country_code,
+            extensions: extension.extensions,
+        }
+    }
+
+    /// Splits into the V1 metadata and the extension fields.
+    pub fn into_parts(self) -> (PropertyMetadata, MetadataExtension) {
+        (
+            PropertyMetadata {
+                location: self.location,
+                size: self.size,
+                legal_description: self.legal_description,
+                valuation: self.valuation,
+                documents_url: self.documents_url,
+            },
+            MetadataExtension {
+                property_type: self.property_type,
+                country_code: self.country_code,
+                extensions: self.extensions,
+            },
+        )
+    }
+}
+
+impl From<PropertyMetadata> for PropertyMetadataV2 {
+    fn from(metadata: PropertyMetadata) -> Self {
+        Self::from_parts(metadata, MetadataExtension::default())
+    }
+}
+
 /// Property information structure
 #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -53,6 +154,8 @@ pub enum PropertyType {
     MultiFamily,
     Retail,
     Office,
+    /// Not recorded; metadata written before property types were tracked.
+    Unspecified,
 }
 
 /// Approval type for multi-signature operations
@@ -102,6 +205,37 @@ pub trait PropertyRegistry {
     fn get_approved(&self, property_id: u64) -> Option<AccountId>;
 }
 
+/// Cheap existence and ownership lookups for other PropChain contracts,
+/// served without loading property metadata.
+#[ink::trait_definition]
+pub trait PropertyOwnership {
+    /// Returns whether `property_id` is registered.
+    #[ink(message)]
+    fn property_exists(&self, property_id: u64) -> bool;
+
+    /// Returns the current owner of `property_id`.
+    #[ink(message)]
+    fn owner_of(&self, property_id: u64) -> Option<AccountId>;
+
+    /// Returns the owner of each property in `property_ids`, in order.
+    #[ink(message)]
+    fn owners_of(&self, property_ids: Vec<u64>) -> Vec<Option<AccountId>>;
+
+    /// Returns the IDs of all properties currently owned by `owner`.
+    #[ink(message)]
+    fn get_owner_properties(&self, owner: AccountId) -> Vec<u64>;
+
+    /// Returns whether `owner` currently owns `property_id`, without
+    /// loading `owner`'s property list.
+    #[ink(message)]
+    fn owns_property(&self, owner: AccountId, property_id: u64) -> bool;
+
+    /// Returns the number of properties owned by `owner`, without loading
+    /// the list itself.
+    #[ink(message)]
+    fn owner_property_count(&self, owner: AccountId) -> u32;
+}
+
 /// Escrow trait for secure property transfers
 pub trait Escrow {
     /// Error type for escrow operations
@@ -186,3 +320,51 @@ pub trait AdvancedEscrow {
         release_to_seller: bool,
     ) -> Result<(), Self::Error>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::{Decode, Encode};
+
+    fn v1() -> PropertyMetadata {
+        PropertyMetadata {
+            location: String::from("1 Harbour Road"),
+            size: 250,
+            legal_description: String::from("Lot 4"),
+            valuation: 400_000,
+            documents_url: String::from("ipfs://harbour"),
+        }
+    }
+
+    #[test]
+    fn v1_converts_to_v2_with_empty_extension() {
+        let v2 = PropertyMetadataV2::from(v1());
+        assert_eq!(v2.property_type, PropertyType::Unspecified);
+        assert_eq!(v2.country_code, [0u8; 2]);
+        assert!(v2.extensions.is_empty());
+        assert_eq!(v2.into_parts(), (v1(), MetadataExtension::default()));
+    }
+
+    #[test]
+    fn v2_round_trips_through_parts_and_scale() {
+        let extension = MetadataExtension {
+            property_type: PropertyType::Office,
+            country_code: *b"NL",
+            extensions: vec![(String::from("floors"), String::from("12"))],
+        };
+        let v2 = PropertyMetadataV2::from_parts(v1(), extension.clone());
+        assert_eq!(v2.clone().into_parts(), (v1(), extension));
+
+        let decoded = PropertyMetadataV2::decode(&mut &v2.encode()[..]).unwrap();
+        assert_eq!(decoded, v2);
+    }
+
+    #[test]
+    fn v1_encoding_is_a_prefix_of_v2() {
+        // V2 appends its fields, so the V1 bytes of stored records stay valid.
+        let v1_bytes = v1().encode();
+        let v2_bytes = PropertyMetadataV2::from(v1()).encode();
+        assert_eq!(&v2_bytes[..v1_bytes.len()], &v1_bytes[..]);
+        assert_eq!(PropertyMetadata::decode(&mut &v2_bytes[..]).unwrap(), v1());
+    }
+}
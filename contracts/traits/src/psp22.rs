@@ -0,0 +1,106 @@
+//! PSP22 fungible token standard.
+//!
+//! The registry settles escrows in PSP22 stable tokens, calling the token
+//! contract through the message selectors fixed by the PSP22 specification
+//! (`blake2b("PSP22::<message>")[..4]`). Only the messages the registry
+//! needs are declared here.
+
+use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+// =========================================================================
+// Types
+// =========================================================================
+
+/// Errors defined by the PSP22 standard.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22Error {
+    /// Custom error type for implementation-specific errors.
+    Custom(String),
+    /// Returned if not enough balance to fulfill a request is available.
+    InsufficientBalance,
+    /// Returned if not enough allowance to fulfill a request is available.
+    InsufficientAllowance,
+    /// Returned if recipient's address is zero.
+    ZeroRecipientAddress,
+    /// Returned if sender's address is zero.
+    ZeroSenderAddress,
+    /// Returned if a safe transfer check fails.
+    SafeTransferCheckFailed(String),
+}
+
+/// Message selectors fixed by the PSP22 specification.
+pub mod selectors {
+    pub const TOTAL_SUPPLY: [u8; 4] = [0x16, 0x2d, 0xf8, 0xc2];
+    pub const BALANCE_OF: [u8; 4] = [0x65, 0x68, 0x38, 0x2f];
+    pub const ALLOWANCE: [u8; 4] = [0x4d, 0x47, 0xd9, 0x21];
+    pub const TRANSFER: [u8; 4] = [0xdb, 0x20, 0xf9, 0xf5];
+    pub const TRANSFER_FROM: [u8; 4] = [0x54, 0xb3, 0xc7, 0x6e];
+    pub const APPROVE: [u8; 4] = [0xb2, 0x0f, 0x1b, 0xbd];
+}
+
+// =========================================================================
+// Trait Definitions
+// =========================================================================
+
+/// Core PSP22 interface.
+#[ink::trait_definition]
+pub trait PSP22 {
+    /// Returns the total token supply.
+    #[ink(message, selector = 0x162df8c2)]
+    fn total_supply(&self) -> u128;
+
+    /// Returns the balance of `owner`.
+    #[ink(message, selector = 0x6568382f)]
+    fn balance_of(&self, owner: AccountId) -> u128;
+
+    /// Returns how much `spender` may still withdraw from `owner`.
+    #[ink(message, selector = 0x4d47d921)]
+    fn allowance(&self, owner: AccountId, spender: AccountId) -> u128;
+
+    /// Transfers `value` from the caller to `to`.
+    #[ink(message, selector = 0xdb20f9f5)]
+    fn transfer(&mut self, to: AccountId, value: u128, data: Vec<u8>) -> Result<(), PSP22Error>;
+
+    /// Transfers `value` from `from` to `to` against the caller's allowance.
+    #[ink(message, selector = 0x54b3c76e)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: u128,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allowance of `spender` over the caller's tokens to `value`.
+    #[ink(message, selector = 0xb20f1bbd)]
+    fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector_of(label: &str) -> [u8; 4] {
+        let mut output = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(label.as_bytes(), &mut output);
+        [output[0], output[1], output[2], output[3]]
+    }
+
+    #[test]
+    fn selectors_match_specification() {
+        let expected = [
+            ("PSP22::total_supply", selectors::TOTAL_SUPPLY),
+            ("PSP22::balance_of", selectors::BALANCE_OF),
+            ("PSP22::allowance", selectors::ALLOWANCE),
+            ("PSP22::transfer", selectors::TRANSFER),
+            ("PSP22::transfer_from", selectors::TRANSFER_FROM),
+            ("PSP22::approve", selectors::APPROVE),
+        ];
+        for (label, selector) in expected {
+            assert_eq!(selector_of(label), selector, "{label}");
+        }
+    }
+}
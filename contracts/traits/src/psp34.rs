@@ -0,0 +1,186 @@
+//! PSP34 non-fungible token standard.
+//!
+//! Polkadot wallets and marketplaces discover NFTs through the PSP34 message
+//! selectors, so the property token exposes this surface alongside its own
+//! ERC-721-style API. The selectors below are the ones fixed by the PSP34
+//! specification (`blake2b("PSP34::<message>")[..4]`) and must not change.
+
+use crate::property::TokenId;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+// =========================================================================
+// Types
+// =========================================================================
+
+/// PSP34 token identifier.
+///
+/// Property tokens are numbered with a `u64`, which maps to [`Id::U64`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Id {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Bytes(Vec<u8>),
+}
+
+impl From<TokenId> for Id {
+    fn from(token_id: TokenId) -> Self {
+        Id::U64(token_id)
+    }
+}
+
+impl Id {
+    /// Returns the property token ID for this identifier.
+    ///
+    /// Only the `U64` variant names a property token; every other variant
+    /// returns `None`.
+    pub fn as_token_id(&self) -> Option<TokenId> {
+        match self {
+            Id::U64(token_id) => Some(*token_id),
+            _ => None,
+        }
+    }
+}
+
+/// Errors defined by the PSP34 standard.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP34Error {
+    /// Custom error type for implementation-specific errors.
+    Custom(Vec<u8>),
+    /// Returned if owner approves self.
+    SelfApprove,
+    /// Returned if the caller doesn't have allowance for transferring.
+    NotApproved,
+    /// Returned if the owner already owns the token.
+    TokenExists,
+    /// Returned if the token doesn't exist.
+    TokenNotExists,
+    /// Returned if the safe transfer check fails.
+    SafeTransferCheckFailed(Vec<u8>),
+}
+
+/// Message selectors fixed by the PSP34 specification.
+pub mod selectors {
+    pub const COLLECTION_ID: [u8; 4] = [0xff, 0xa2, 0x7a, 0x5f];
+    pub const BALANCE_OF: [u8; 4] = [0xcd, 0xe7, 0xe5, 0x5f];
+    pub const OWNER_OF: [u8; 4] = [0x11, 0x68, 0x62, 0x4d];
+    pub const ALLOWANCE: [u8; 4] = [0x47, 0x90, 0xf5, 0x5a];
+    pub const APPROVE: [u8; 4] = [0x19, 0x32, 0xa8, 0xb0];
+    pub const TRANSFER: [u8; 4] = [0x31, 0x28, 0xd6, 0x1b];
+    pub const TOTAL_SUPPLY: [u8; 4] = [0x62, 0x84, 0x13, 0xfe];
+    pub const GET_ATTRIBUTE: [u8; 4] = [0xf1, 0x9d, 0x48, 0xd1];
+    pub const OWNERS_TOKEN_BY_INDEX: [u8; 4] = [0x3b, 0xcf, 0xb5, 0x11];
+    pub const TOKEN_BY_INDEX: [u8; 4] = [0xcd, 0x03, 0x40, 0xd0];
+}
+
+// =========================================================================
+// Trait Definitions
+// =========================================================================
+
+/// Core PSP34 interface.
+#[ink::trait_definition]
+pub trait PSP34 {
+    /// Returns the collection ID.
+    #[ink(message, selector = 0xffa27a5f)]
+    fn collection_id(&self) -> Id;
+
+    /// Returns the number of tokens owned by `owner`.
+    #[ink(message, selector = 0xcde7e55f)]
+    fn balance_of(&self, owner: AccountId) -> u32;
+
+    /// Returns the owner of the token, if it exists.
+    #[ink(message, selector = 0x1168624d)]
+    fn owner_of(&self, id: Id) -> Option<AccountId>;
+
+    /// Returns whether `operator` may transfer `id` (or every token of
+    /// `owner` when `id` is `None`).
+    #[ink(message, selector = 0x4790f55a)]
+    fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool;
+
+    /// Approves or disapproves `operator` for `id`, or for every token of
+    /// the caller when `id` is `None`.
+    #[ink(message, selector = 0x1932a8b0)]
+    fn approve(
+        &mut self,
+        operator: AccountId,
+        id: Option<Id>,
+        approved: bool,
+    ) -> Result<(), PSP34Error>;
+
+    /// Transfers `id` from the caller to `to`.
+    #[ink(message, selector = 0x3128d61b)]
+    fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error>;
+
+    /// Returns the number of tokens in existence.
+    #[ink(message, selector = 0x628413fe)]
+    fn total_supply(&self) -> u128;
+}
+
+/// PSP34 metadata extension.
+#[ink::trait_definition]
+pub trait PSP34Metadata {
+    /// Returns the value of attribute `key` for token `id`.
+    #[ink(message, selector = 0xf19d48d1)]
+    fn get_attribute(&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>>;
+}
+
+/// PSP34 enumerable extension.
+#[ink::trait_definition]
+pub trait PSP34Enumerable {
+    /// Returns the `index`-th token owned by `owner`.
+    #[ink(message, selector = 0x3bcfb511)]
+    fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Result<Id, PSP34Error>;
+
+    /// Returns the `index`-th token in existence.
+    #[ink(message, selector = 0xcd0340d0)]
+    fn token_by_index(&self, index: u128) -> Result<Id, PSP34Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector_of(label: &str) -> [u8; 4] {
+        let mut output = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(label.as_bytes(), &mut output);
+        [output[0], output[1], output[2], output[3]]
+    }
+
+    #[test]
+    fn selectors_match_specification() {
+        let expected = [
+            ("PSP34::collection_id", selectors::COLLECTION_ID),
+            ("PSP34::balance_of", selectors::BALANCE_OF),
+            ("PSP34::owner_of", selectors::OWNER_OF),
+            ("PSP34::allowance", selectors::ALLOWANCE),
+            ("PSP34::approve", selectors::APPROVE),
+            ("PSP34::transfer", selectors::TRANSFER),
+            ("PSP34::total_supply", selectors::TOTAL_SUPPLY),
+            ("PSP34Metadata::get_attribute", selectors::GET_ATTRIBUTE),
+            (
+                "PSP34Enumerable::owners_token_by_index",
+                selectors::OWNERS_TOKEN_BY_INDEX,
+            ),
+            ("PSP34Enumerable::token_by_index", selectors::TOKEN_BY_INDEX),
+        ];
+        for (label, selector) in expected {
+            assert_eq!(selector_of(label), selector, "{label}");
+        }
+    }
+
+    #[test]
+    fn token_ids_map_to_u64_ids() {
+        assert_eq!(Id::from(7u64), Id::U64(7));
+        assert_eq!(Id::U64(7).as_token_id(), Some(7));
+        assert_eq!(Id::U32(7).as_token_id(), None);
+        assert_eq!(Id::Bytes(vec![7]).as_token_id(), None);
+    }
+}
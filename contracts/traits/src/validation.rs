@@ -0,0 +1,115 @@
+// Shared input validation helpers (Issue #1926)
+//
+// Off-chain links that end up permanently on-chain (badge metadata,
+// verification evidence, IPFS documents) are validated here so every
+// contract applies the same rules.
+
+/// Returns `true` if `cid` looks like an IPFS content identifier.
+///
+/// - CIDv0: starts with `Qm` and is exactly 46 characters (base58btc).
+/// - CIDv1: starts with the multibase prefix `b` (base32) and is at least
+///   10 characters long.
+///
+/// Both forms must be purely alphanumeric.
+pub fn is_valid_ipfs_cid(cid: &str) -> bool {
+    if !cid.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        return false;
+    }
+    if cid.starts_with("Qm") {
+        cid.len() == 46
+    } else if cid.starts_with('b') {
+        cid.len() >= 10
+    } else {
+        false
+    }
+}
+
+/// Returns `true` if `url` is a syntactically plausible `https://` URL.
+///
+/// Only a basic check is performed: printable ASCII with no whitespace, and
+/// a dotted host made of alphanumerics, `-` and an optional `:port`.
+pub fn is_valid_https_url(url: &str) -> bool {
+    let rest = match url.strip_prefix("https://") {
+        Some(rest) => rest,
+        None => return false,
+    };
+    if !url.bytes().all(|b| b.is_ascii_graphic()) {
+        return false;
+    }
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host = match authority.split_once(':') {
+        Some((host, port)) => {
+            if port.is_empty() || !port.bytes().all(|b| b.is_ascii_digit()) {
+                return false;
+            }
+            host
+        }
+        None => authority,
+    };
+    !host.is_empty()
+        && host.contains('.')
+        && !host.starts_with(['.', '-'])
+        && !host.ends_with(['.', '-'])
+        && !host.contains("..")
+        && host
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'-')
+}
+
+/// Returns `true` if `link` is either an `https://` URL or an IPFS CID,
+/// optionally written with the `ipfs://` scheme.
+pub fn is_valid_metadata_link(link: &str) -> bool {
+    if let Some(cid) = link.strip_prefix("ipfs://") {
+        return is_valid_ipfs_cid(cid);
+    }
+    is_valid_https_url(link) || is_valid_ipfs_cid(link)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+    const CID_V1: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    #[test]
+    fn test_ipfs_cid_versions() {
+        assert!(is_valid_ipfs_cid(CID_V0));
+        assert!(is_valid_ipfs_cid(CID_V1));
+        assert!(!is_valid_ipfs_cid(""));
+        assert!(!is_valid_ipfs_cid("QmTooShort"));
+        assert!(!is_valid_ipfs_cid("bafy"));
+        assert!(!is_valid_ipfs_cid("bad input string"));
+        assert!(!is_valid_ipfs_cid(
+            "zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7"
+        ));
+    }
+
+    #[test]
+    fn test_https_urls() {
+        assert!(is_valid_https_url("https://example.com"));
+        assert!(is_valid_https_url(
+            "https://docs.example.com:8443/deed.pdf?v=2#p1"
+        ));
+        assert!(!is_valid_https_url("http://example.com"));
+        assert!(!is_valid_https_url("https://"));
+        assert!(!is_valid_https_url("https://localhost/badge"));
+        assert!(!is_valid_https_url("https://exa mple.com"));
+        assert!(!is_valid_https_url("https://.example.com"));
+        assert!(!is_valid_https_url("https://example..com"));
+        assert!(!is_valid_https_url("https://example.com:/x"));
+        assert!(!is_valid_https_url("https://user@example.com"));
+    }
+
+    #[test]
+    fn test_metadata_links() {
+        assert!(is_valid_metadata_link(CID_V0));
+        assert!(is_valid_metadata_link(
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3"
+        ));
+        assert!(is_valid_metadata_link("https://example.com/badge.json"));
+        assert!(!is_valid_metadata_link("ipfs://badge"));
+        assert!(!is_valid_metadata_link("javascript:alert(1)"));
+        assert!(!is_valid_metadata_link("not a link"));
+    }
+}
@@ -0,0 +1,15 @@
+//! PropChain End-to-End Test Support
+//!
+//! Shared fixtures for the cross-contract scenarios in `tests/e2e.rs`. The
+//! scenarios deploy the registry, compliance registry, oracle, bridge, the
+//! reference marketplace and a reference PSP22 token to a local
+//! `substrate-contracts-node` and only build with the `e2e-tests` feature:
+//!
+//! ```sh
+//! CONTRACTS_NODE=/path/to/substrate-contracts-node \
+//!     cargo test -p propchain-e2e-tests --features e2e-tests --test e2e
+//! ```
+
+pub mod test_utils;
+
+pub use test_utils::*;
@@ -0,0 +1,107 @@
+//! Metadata, valuation, account and operator-key fixtures shared by the
+//! end-to-end scenarios.
+
+use ink::primitives::{AccountId, Hash};
+use propchain_traits::{PropertyMetadata, PropertyValuation, ValuationMethod};
+
+/// Result type returned by the `#[ink_e2e::test]` scenarios.
+pub type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// Property metadata that passes the registry's validation.
+pub fn property_metadata(location: &str, valuation: u128) -> PropertyMetadata {
+    PropertyMetadata {
+        location: location.to_string(),
+        size: 1_200,
+        legal_description: format!("Lot 7, Block 3 — {location}"),
+        valuation,
+        documents_url: "https://docs.propchain.io/deeds/lot-7.pdf".to_string(),
+    }
+}
+
+/// The default property used by the scenarios.
+pub fn standard_metadata() -> PropertyMetadata {
+    property_metadata("221B Baker Street, London", 450_000)
+}
+
+/// An oracle valuation for `property_id`, as pushed by the oracle admin.
+pub fn market_valuation(property_id: u64, valuation: u128) -> PropertyValuation {
+    PropertyValuation {
+        property_id,
+        valuation,
+        confidence_score: 90,
+        sources_used: 3,
+        last_updated: 0,
+        valuation_method: ValuationMethod::MarketData,
+    }
+}
+
+/// KYC document hash recorded for `account` in the compliance registry.
+pub fn kyc_hash(account: &AccountId) -> [u8; 32] {
+    propchain_traits::crypto::hash_encoded(&(b"propchain:e2e:kyc", account)).into()
+}
+
+/// Deterministic bridge operator key derived from `seed`. Returns the secret
+/// key and the compressed public key the bridge stores.
+pub fn operator_keypair(seed: u8) -> (secp256k1::SecretKey, [u8; 33]) {
+    let secp = secp256k1::Secp256k1::new();
+    let secret = secp256k1::SecretKey::from_slice(&[seed; 32]).expect("valid secret key");
+    let public = secp256k1::PublicKey::from_secret_key(&secp, &secret).serialize();
+    (secret, public)
+}
+
+/// Signs `hash` with a recoverable ECDSA signature in the 65-byte layout the
+/// bridge expects.
+pub fn sign_hash(secret: &secp256k1::SecretKey, hash: Hash) -> [u8; 65] {
+    let secp = secp256k1::Secp256k1::new();
+    let message = secp256k1::Message::from_digest_slice(hash.as_ref()).expect("32-byte digest");
+    let (recovery_id, compact) = secp
+        .sign_ecdsa_recoverable(&message, secret)
+        .serialize_compact();
+    let mut signature = [0u8; 65];
+    signature[..64].copy_from_slice(&compact);
+    signature[64] = recovery_id.to_i32() as u8;
+    signature
+}
+
+/// Dev accounts of the test node, by role in the scenarios.
+#[cfg(feature = "e2e-tests")]
+pub struct E2EAccounts {
+    /// Deploys and administers every contract.
+    pub alice: AccountId,
+    pub bob: AccountId,
+    pub charlie: AccountId,
+    pub dave: AccountId,
+    pub eve: AccountId,
+    pub ferdie: AccountId,
+}
+
+#[cfg(feature = "e2e-tests")]
+impl Default for E2EAccounts {
+    fn default() -> Self {
+        use ink_e2e::{account_id, AccountKeyring};
+        Self {
+            alice: account_id(AccountKeyring::Alice),
+            bob: account_id(AccountKeyring::Bob),
+            charlie: account_id(AccountKeyring::Charlie),
+            dave: account_id(AccountKeyring::Dave),
+            eve: account_id(AccountKeyring::Eve),
+            ferdie: account_id(AccountKeyring::Ferdie),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operator_signatures_recover_the_operator_key() {
+        let (secret, public) = operator_keypair(7);
+        let hash = propchain_traits::crypto::hash_encoded(&standard_metadata());
+        let recovered = propchain_traits::crypto::verify_ecdsa_signature(
+            &sign_hash(&secret, hash),
+            &hash.into(),
+        );
+        assert_eq!(recovered, Ok(public));
+    }
+}
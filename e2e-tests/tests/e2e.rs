@@ -0,0 +1,1516 @@
+//! Cross-contract scenarios run against a local contracts node.
+//!
+//! Every scenario deploys fresh contracts from Alice, wires them together
+//! through their `set_*` configuration messages and then drives a complete
+//! user flow. Expected failures are checked with dry runs, since a message
+//! returning `Err` reverts the extrinsic.
+
+#![cfg(feature = "e2e-tests")]
+
+use compliance_registry::compliance_registry::{
+    AMLRiskFactors, BiometricMethod, ComplianceRegistry, ComplianceRegistryRef, ConsentStatus,
+    DocumentType, Jurisdiction, RiskLevel, SanctionsList,
+};
+use ink::primitives::AccountId;
+use ink_e2e::{ChainBackend, ContractsBackend};
+use oracle::propchain_oracle::{PropertyValuationOracle, PropertyValuationOracleRef};
+use propchain_bridge::bridge::{Error as BridgeError, PropertyBridge, PropertyBridgeRef};
+use propchain_contracts::propchain_contracts::{
+    BadgeType, Error as RegistryError, PropertyRegistry, PropertyRegistryRef,
+};
+use propchain_e2e_tests::*;
+use propchain_marketplace::propchain_marketplace::{
+    PropertyMarketplace, PropertyMarketplaceRef,
+};
+use propchain_psp22_token::propchain_psp22_token::{Psp22Token, Psp22TokenRef};
+use propchain_traits::marketplace::{Marketplace, TransferAgent};
+use propchain_traits::psp22::PSP22;
+
+/// Client the scenarios run with: a local node reached over RPC.
+type NodeClient = ink_e2e::Client<ink_e2e::PolkadotConfig, ink::env::DefaultEnvironment>;
+
+const BADGE_CID: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+/// Runs `account` through KYC, AML, sanctions screening and consent so the
+/// compliance registry reports it compliant. Alice deployed the registry and
+/// is its default verifier.
+async fn verify_account(client: &mut NodeClient, compliance: AccountId, account: AccountId) {
+    let mut call = ink_e2e::create_call_builder::<ComplianceRegistry>(compliance);
+    let alice = ink_e2e::alice();
+
+    client
+        .call(
+            &alice,
+            &call.submit_verification(
+                account,
+                Jurisdiction::US,
+                kyc_hash(&account),
+                RiskLevel::Low,
+                DocumentType::Passport,
+                BiometricMethod::FaceRecognition,
+                15,
+            ),
+        )
+        .submit()
+        .await
+        .expect("submit_verification failed");
+    let aml = AMLRiskFactors {
+        pep_status: false,
+        high_risk_country: false,
+        suspicious_transaction_pattern: false,
+        large_transaction_volume: false,
+        source_of_funds_verified: true,
+    };
+    client
+        .call(&alice, &call.update_aml_status(account, true, aml))
+        .submit()
+        .await
+        .expect("update_aml_status failed");
+    client
+        .call(
+            &alice,
+            &call.update_sanctions_status(account, true, SanctionsList::OFAC),
+        )
+        .submit()
+        .await
+        .expect("update_sanctions_status failed");
+    client
+        .call(&alice, &call.update_consent(account, ConsentStatus::Given))
+        .submit()
+        .await
+        .expect("update_consent failed");
+}
+
+/// Registers an operator with signing key `seed` on `bridge`, as both a
+/// bridge operator and a validator.
+async fn add_operator(client: &mut NodeClient, bridge: AccountId, operator: AccountId, seed: u8) {
+    let mut call = ink_e2e::create_call_builder::<PropertyBridge>(bridge);
+    let (_, public) = operator_keypair(seed);
+    client
+        .call(
+            &ink_e2e::alice(),
+            &call.add_bridge_operator_with_key(operator, public),
+        )
+        .submit()
+        .await
+        .expect("add_bridge_operator_with_key failed");
+    client
+        .call(&ink_e2e::alice(), &call.add_validator(operator))
+        .submit()
+        .await
+        .expect("add_validator failed");
+}
+
+/// Approves an outbound request on `bridge` with offline signatures from
+/// the operators keyed by `seeds`, relayed by Dave, then executes it as Bob.
+async fn approve_and_execute(
+    client: &mut NodeClient,
+    bridge: AccountId,
+    request_id: u64,
+    seeds: &[u8],
+) {
+    let mut call = ink_e2e::create_call_builder::<PropertyBridge>(bridge);
+    let payload = client
+        .call(
+            &ink_e2e::dave(),
+            &call.get_operator_signing_payload(request_id),
+        )
+        .dry_run()
+        .await
+        .expect("get_operator_signing_payload failed")
+        .return_value()
+        .expect("request exists");
+    for seed in seeds {
+        let (secret, _) = operator_keypair(*seed);
+        client
+            .call(
+                &ink_e2e::dave(),
+                &call.submit_operator_signature(request_id, sign_hash(&secret, payload)),
+            )
+            .submit()
+            .await
+            .expect("submit_operator_signature failed");
+    }
+    client
+        .call(&ink_e2e::bob(), &call.execute_bridge(request_id))
+        .submit()
+        .await
+        .expect("execute_bridge failed");
+}
+
+/// Finalizes an inbound transfer on `bridge` with an operator quorum
+/// signed by `seeds`. Returns the dry-run result when `commit` is false.
+async fn finalize_inbound(
+    client: &mut NodeClient,
+    bridge: AccountId,
+    source: (u64, u64),
+    token_id: u64,
+    recipient: AccountId,
+    commit: bool,
+) -> Result<Option<u64>, BridgeError> {
+    let accounts = E2EAccounts::default();
+    let mut call = ink_e2e::create_call_builder::<PropertyBridge>(bridge);
+    let metadata = standard_metadata();
+    let metadata_hash = propchain_traits::crypto::hash_encoded(&metadata);
+    let hash = client
+        .call(
+            &ink_e2e::dave(),
+            &call.get_inbound_message_hash(
+                source.0,
+                source.1,
+                token_id,
+                recipient,
+                metadata.clone(),
+                metadata_hash,
+            ),
+        )
+        .dry_run()
+        .await
+        .expect("get_inbound_message_hash failed")
+        .return_value();
+    let signatures = [(2u8, accounts.bob), (3, accounts.charlie)]
+        .into_iter()
+        .map(|(seed, operator)| (operator, sign_hash(&operator_keypair(seed).0, hash)))
+        .collect::<Vec<_>>();
+    let message = call.finalize_inbound(
+        source.0,
+        source.1,
+        token_id,
+        recipient,
+        metadata,
+        metadata_hash,
+        signatures,
+    );
+    if commit {
+        client
+            .call(&ink_e2e::dave(), &message)
+            .submit()
+            .await
+            .expect("finalize_inbound failed")
+            .return_value()
+    } else {
+        client
+            .call(&ink_e2e::dave(), &message)
+            .dry_run()
+            .await
+            .expect("finalize_inbound dry run failed")
+            .return_value()
+    }
+}
+
+/// Bob sells a property to Charlie through escrow while the registry checks
+/// every party against the compliance registry. The oracle valuation is
+/// synced into the registry before the sale.
+#[ink_e2e::test]
+async fn full_sale_via_escrow_with_compliance<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = ComplianceRegistryRef::new();
+    let compliance = client
+        .instantiate("compliance_registry", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("compliance registry instantiate failed");
+    let mut constructor = PropertyValuationOracleRef::new(accounts.alice);
+    let oracle = client
+        .instantiate("oracle", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("oracle instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+    let mut oracle_call = oracle.call_builder::<PropertyValuationOracle>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_compliance_registry(Some(compliance.account_id)),
+        )
+        .submit()
+        .await
+        .expect("set_compliance_registry failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_oracle(oracle.account_id),
+        )
+        .submit()
+        .await
+        .expect("set_oracle failed");
+
+    // Unverified sellers cannot register.
+    let rejected = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(rejected.return_value(), Err(RegistryError::NotCompliant));
+
+    verify_account(&mut client, compliance.account_id, accounts.bob).await;
+    let property_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+
+    // Sync the oracle's valuation into the registry.
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call
+                .update_property_valuation(property_id, market_valuation(property_id, 480_000)),
+        )
+        .submit()
+        .await
+        .expect("update_property_valuation failed");
+    client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.update_valuation_from_oracle(property_id),
+        )
+        .submit()
+        .await
+        .expect("update_valuation_from_oracle failed");
+    let property = client
+        .call(&ink_e2e::bob(), &registry_call.get_property(property_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("property exists");
+    assert_eq!(property.metadata.valuation, 480_000);
+
+    let escrow_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.create_escrow(property_id, accounts.charlie, 480_000, None),
+        )
+        .submit()
+        .await
+        .expect("create_escrow failed")
+        .return_value()
+        .expect("escrow rejected");
+
+    // The buyer must be compliant before the escrow can close.
+    let rejected = client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.release_escrow(escrow_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(rejected.return_value(), Err(RegistryError::NotCompliant));
+
+    verify_account(&mut client, compliance.account_id, accounts.charlie).await;
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.release_escrow(escrow_id),
+        )
+        .submit()
+        .await
+        .expect("release_escrow failed");
+
+    let property = client
+        .call(&ink_e2e::bob(), &registry_call.get_property(property_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("property exists");
+    assert_eq!(property.owner, accounts.charlie);
+    let escrow = client
+        .call(&ink_e2e::bob(), &registry_call.get_escrow(escrow_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("escrow exists");
+    assert!(escrow.released);
+
+    Ok(())
+}
+
+/// The oracle pushes admin valuation updates that move by at least 5% into
+/// the registry. Smaller moves are not pushed, and a registry that does not
+/// list the oracle rejects the push without reverting the oracle update.
+#[ink_e2e::test]
+async fn oracle_pushes_large_valuation_moves_to_registry<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = PropertyValuationOracleRef::new(accounts.alice);
+    let oracle = client
+        .instantiate("oracle", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("oracle instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let unlinked = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("second registry instantiate failed");
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+    let mut oracle_call = oracle.call_builder::<PropertyValuationOracle>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_oracle(oracle.account_id),
+        )
+        .submit()
+        .await
+        .expect("set_oracle failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call.set_registry_callback(registry.account_id, 500),
+        )
+        .submit()
+        .await
+        .expect("set_registry_callback failed");
+
+    let property_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+
+    // The first valuation is always pushed; a 1% move is not; a 12.5% move is.
+    for (valuation, expected) in [(480_000, 480_000), (484_800, 480_000), (540_000, 540_000)] {
+        client
+            .call(
+                &ink_e2e::alice(),
+                &oracle_call.update_property_valuation(
+                    property_id,
+                    market_valuation(property_id, valuation),
+                ),
+            )
+            .submit()
+            .await
+            .expect("update_property_valuation failed");
+        let property = client
+            .call(&ink_e2e::bob(), &registry_call.get_property(property_id))
+            .dry_run()
+            .await?
+            .return_value()
+            .expect("property exists");
+        assert_eq!(property.metadata.valuation, expected);
+    }
+
+    // A registry that does not list this oracle refuses the push, but the
+    // oracle keeps the new valuation.
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call.set_registry_callback(unlinked.account_id, 500),
+        )
+        .submit()
+        .await
+        .expect("set_registry_callback failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call
+                .update_property_valuation(property_id, market_valuation(property_id, 600_000)),
+        )
+        .submit()
+        .await
+        .expect("update_property_valuation reverted on a failing callback");
+    let valuation = client
+        .call(
+            &ink_e2e::bob(),
+            &oracle_call.get_property_valuation(property_id),
+        )
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("valuation exists");
+    assert_eq!(valuation.valuation, 600_000);
+    let property = client
+        .call(&ink_e2e::bob(), &registry_call.get_property(property_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("property exists");
+    assert_eq!(property.metadata.valuation, 540_000);
+
+    Ok(())
+}
+
+/// A portfolio alert resolves the watched owner's properties through the
+/// registry: a move on one of them fires at once, a move on someone else's
+/// property is ignored, and a further breach inside the cooldown waits in
+/// the pending list.
+#[ink_e2e::test]
+async fn portfolio_alert_batches_breaches_within_cooldown<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = PropertyValuationOracleRef::new(accounts.alice);
+    let oracle = client
+        .instantiate("oracle", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("oracle instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+    let mut oracle_call = oracle.call_builder::<PropertyValuationOracle>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call.set_registry_contract(registry.account_id),
+        )
+        .submit()
+        .await
+        .expect("set_registry_contract failed");
+
+    let mut property_ids = Vec::new();
+    for owner in [ink_e2e::bob(), ink_e2e::bob(), ink_e2e::charlie()] {
+        let property_id = client
+            .call(
+                &owner,
+                &registry_call.register_property(standard_metadata(), None),
+            )
+            .submit()
+            .await
+            .expect("register_property failed")
+            .return_value()
+            .expect("registration rejected");
+        property_ids.push(property_id);
+    }
+    let (first, second, foreign) = (property_ids[0], property_ids[1], property_ids[2]);
+
+    let alert_id = client
+        .call(
+            &ink_e2e::dave(),
+            &oracle_call.set_portfolio_alert(accounts.bob, 500, accounts.dave),
+        )
+        .submit()
+        .await
+        .expect("set_portfolio_alert failed")
+        .return_value()
+        .expect("subscription rejected");
+
+    // Opening valuations have nothing to compare against; then a 10% move
+    // on bob's first property fires, a 10% move on charlie's does not
+    // concern bob, a 2% move is under the threshold and a further breach
+    // inside the cooldown is held back.
+    for (property_id, valuation) in [
+        (first, 500_000),
+        (second, 500_000),
+        (foreign, 500_000),
+        (first, 550_000),
+        (foreign, 550_000),
+        (second, 510_000),
+        (second, 560_000),
+    ] {
+        client
+            .call(
+                &ink_e2e::alice(),
+                &oracle_call.update_property_valuation(
+                    property_id,
+                    market_valuation(property_id, valuation),
+                ),
+            )
+            .submit()
+            .await
+            .expect("update_property_valuation failed");
+    }
+
+    let alert = client
+        .call(&ink_e2e::dave(), &oracle_call.get_portfolio_alert(alert_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("alert exists");
+    assert!(alert.last_triggered > 0);
+    assert_eq!(alert.pending_property_ids, vec![second]);
+
+    let alerts = client
+        .call(
+            &ink_e2e::dave(),
+            &oracle_call.get_portfolio_alerts(accounts.dave),
+        )
+        .dry_run()
+        .await?
+        .return_value();
+    assert_eq!(alerts, vec![alert]);
+
+    client
+        .call(
+            &ink_e2e::dave(),
+            &oracle_call.deactivate_portfolio_alert(alert_id),
+        )
+        .submit()
+        .await
+        .expect("deactivate_portfolio_alert failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call.update_property_valuation(first, market_valuation(first, 500_000)),
+        )
+        .submit()
+        .await
+        .expect("update_property_valuation failed");
+    let alert = client
+        .call(&ink_e2e::dave(), &oracle_call.get_portfolio_alert(alert_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("alert exists");
+    assert!(!alert.is_active);
+    assert_eq!(alert.pending_property_ids, vec![second]);
+
+    Ok(())
+}
+
+/// A crash of more than 25% within an hour makes the oracle flag a
+/// valuation collapse to the registry, which suspends the property's
+/// `PremiumListing` badge under the admin's collapse policy.
+#[ink_e2e::test]
+async fn valuation_collapse_suspends_premium_listing<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = PropertyValuationOracleRef::new(accounts.alice);
+    let oracle = client
+        .instantiate("oracle", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("oracle instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+    let mut oracle_call = oracle.call_builder::<PropertyValuationOracle>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_oracle(oracle.account_id),
+        )
+        .submit()
+        .await
+        .expect("set_oracle failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_suspend_premium_on_collapse(true),
+        )
+        .submit()
+        .await
+        .expect("set_suspend_premium_on_collapse failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call.set_registry_callback(registry.account_id, 500),
+        )
+        .submit()
+        .await
+        .expect("set_registry_callback failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &oracle_call.set_collapse_threshold(2_500, 3_600_000),
+        )
+        .submit()
+        .await
+        .expect("set_collapse_threshold failed");
+
+    let property_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.issue_badge(
+                property_id,
+                BadgeType::PremiumListing,
+                None,
+                format!("ipfs://{BADGE_CID}"),
+            ),
+        )
+        .submit()
+        .await
+        .expect("issue_badge failed");
+
+    // Each step stays under the oracle's circuit breaker; the badge survives
+    // a 24% fall and is suspended once the fall from the peak passes 25%.
+    for (valuation, premium) in [
+        (1_000_000, true),
+        (850_000, true),
+        (760_000, true),
+        (700_000, false),
+    ] {
+        client
+            .call(
+                &ink_e2e::alice(),
+                &oracle_call.update_property_valuation(
+                    property_id,
+                    market_valuation(property_id, valuation),
+                ),
+            )
+            .submit()
+            .await
+            .expect("update_property_valuation failed");
+        let has_badge = client
+            .call(
+                &ink_e2e::bob(),
+                &registry_call.has_badge(property_id, BadgeType::PremiumListing),
+            )
+            .dry_run()
+            .await?
+            .return_value();
+        assert_eq!(has_badge, premium);
+    }
+
+    let flag = client
+        .call(&ink_e2e::bob(), &registry_call.get_oracle_flag(property_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("collapse flagged");
+    assert_eq!(flag.flag, propchain_traits::OracleFlag::ValuationCollapse);
+    assert_eq!(flag.value, 3_000);
+    assert_eq!(flag.flagged_by, oracle.account_id);
+
+    Ok(())
+}
+
+/// Bob sells to Dave through an escrow that requires a `PremiumListing`
+/// badge. Release stays blocked until a registry verifier issues the badge.
+#[ink_e2e::test]
+async fn premium_sale_requires_badge<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_verifier(accounts.charlie, BadgeType::PremiumListing, true),
+        )
+        .submit()
+        .await
+        .expect("set_verifier failed");
+
+    let property_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+    let escrow_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.create_escrow_with_badges(
+                property_id,
+                accounts.dave,
+                1_500_000,
+                vec![BadgeType::PremiumListing],
+            ),
+        )
+        .submit()
+        .await
+        .expect("create_escrow_with_badges failed")
+        .return_value()
+        .expect("escrow rejected");
+    let rejected = client
+        .call(&ink_e2e::dave(), &registry_call.release_escrow(escrow_id))
+        .dry_run()
+        .await?;
+    assert_eq!(
+        rejected.return_value(),
+        Err(RegistryError::BadgeRequirementNotMet(
+            BadgeType::PremiumListing
+        ))
+    );
+
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.issue_badge(
+                property_id,
+                BadgeType::PremiumListing,
+                None,
+                format!("ipfs://{BADGE_CID}"),
+            ),
+        )
+        .submit()
+        .await
+        .expect("issue_badge failed");
+    let premium = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.has_badge(property_id, BadgeType::PremiumListing),
+        )
+        .dry_run()
+        .await?;
+    assert!(premium.return_value());
+
+    client
+        .call(&ink_e2e::dave(), &registry_call.release_escrow(escrow_id))
+        .submit()
+        .await
+        .expect("release_escrow failed");
+
+    let property = client
+        .call(&ink_e2e::bob(), &registry_call.get_property(property_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("property exists");
+    assert_eq!(property.owner, accounts.dave);
+
+    Ok(())
+}
+
+/// Deploys a registry and a marketplace it trusts as transfer agent, and
+/// registers a property for Bob. Returns (registry, marketplace, property).
+async fn marketplace_setup(client: &mut NodeClient) -> (AccountId, AccountId, u64) {
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+    let mut constructor = PropertyMarketplaceRef::new(registry.account_id);
+    let market = client
+        .instantiate("propchain_marketplace", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("marketplace instantiate failed");
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_transfer_agent(market.account_id, true),
+        )
+        .submit()
+        .await
+        .expect("set_transfer_agent failed");
+    let property_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+    (registry.account_id, market.account_id, property_id)
+}
+
+/// Bob lists a property at a fixed price and Charlie buys it. The sale
+/// moves ownership in the registry through the transfer-agent hook.
+#[ink_e2e::test]
+async fn marketplace_sale_transfers_ownership<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+    let (registry, market, property_id) = marketplace_setup(&mut client).await;
+    let registry_call = ink_e2e::create_call_builder::<PropertyRegistry>(registry);
+    let mut market_call = ink_e2e::create_call_builder::<PropertyMarketplace>(market);
+
+    let listing_id = client
+        .call(&ink_e2e::bob(), &market_call.list(property_id, 1_000, None))
+        .submit()
+        .await
+        .expect("list failed")
+        .return_value()
+        .expect("listing rejected");
+    client
+        .call(&ink_e2e::charlie(), &market_call.buy(listing_id))
+        .value(1_000)
+        .submit()
+        .await
+        .expect("buy failed")
+        .return_value()
+        .expect("purchase rejected");
+
+    let owner = client
+        .call(&ink_e2e::bob(), &registry_call.property_owner(property_id))
+        .dry_run()
+        .await?;
+    assert_eq!(owner.return_value(), Some(accounts.charlie));
+    let listing = client
+        .call(&ink_e2e::bob(), &market_call.get_listing(listing_id))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("listing exists");
+    assert!(!listing.active);
+
+    Ok(())
+}
+
+/// Bob transfers a property while its auction is running. Settling can no
+/// longer hand it to Charlie, so Charlie's bid is returned; Dave, the new
+/// owner, replaces a listing Bob left behind.
+#[ink_e2e::test]
+async fn marketplace_listings_survive_an_owner_change<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+    let (registry, market, property_id) = marketplace_setup(&mut client).await;
+    let mut registry_call = ink_e2e::create_call_builder::<PropertyRegistry>(registry);
+    let mut market_call = ink_e2e::create_call_builder::<PropertyMarketplace>(market);
+    let market_balance = client.free_balance(market).await?;
+
+    // Every extrinsic below seals a block: the bid lands before the
+    // auction ends and the settlement after.
+    let auction = client
+        .call(&ink_e2e::bob(), &market_call.list(property_id, 1_000, Some(2)))
+        .submit()
+        .await
+        .expect("list failed")
+        .return_value()
+        .expect("listing rejected");
+    client
+        .call(&ink_e2e::charlie(), &market_call.bid(auction))
+        .value(1_500)
+        .submit()
+        .await
+        .expect("bid failed")
+        .return_value()
+        .expect("bid rejected");
+    client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.transfer_property(property_id, accounts.dave),
+        )
+        .submit()
+        .await
+        .expect("transfer_property failed")
+        .return_value()
+        .expect("transfer rejected");
+    client
+        .call(&ink_e2e::eve(), &market_call.settle(auction))
+        .submit()
+        .await
+        .expect("settle failed")
+        .return_value()
+        .expect("settlement rejected");
+
+    let owner = client
+        .call(&ink_e2e::bob(), &registry_call.property_owner(property_id))
+        .dry_run()
+        .await?;
+    assert_eq!(owner.return_value(), Some(accounts.dave));
+    assert_eq!(client.free_balance(market).await?, market_balance);
+
+    // Dave takes the property back to Bob, who lists it and gives it to
+    // Dave again without delisting. Dave can still list it.
+    client
+        .call(
+            &ink_e2e::dave(),
+            &registry_call.transfer_property(property_id, accounts.bob),
+        )
+        .submit()
+        .await
+        .expect("transfer_property failed")
+        .return_value()
+        .expect("transfer rejected");
+    let stale = client
+        .call(&ink_e2e::bob(), &market_call.list(property_id, 1_000, None))
+        .submit()
+        .await
+        .expect("list failed")
+        .return_value()
+        .expect("listing rejected");
+    client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.transfer_property(property_id, accounts.dave),
+        )
+        .submit()
+        .await
+        .expect("transfer_property failed")
+        .return_value()
+        .expect("transfer rejected");
+    let relisted = client
+        .call(&ink_e2e::dave(), &market_call.list(property_id, 2_000, None))
+        .submit()
+        .await
+        .expect("list failed")
+        .return_value()
+        .expect("listing rejected");
+
+    let active = client
+        .call(&ink_e2e::dave(), &market_call.get_listing(stale))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("listing exists")
+        .active;
+    assert!(!active);
+    let listing = client
+        .call(&ink_e2e::dave(), &market_call.get_listing(relisted))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("listing exists");
+    assert_eq!((listing.seller, listing.active), (accounts.dave, true));
+
+    Ok(())
+}
+
+/// Charlie buys Bob's property for a PSP22 stable token. The registry pulls
+/// the deposit with `transfer_from` once Charlie approves it, pays Bob on
+/// release, and refunds a second escrow back to Charlie. Tokens sent to the
+/// registry directly can be rescued, but never those held for an escrow.
+#[ink_e2e::test]
+async fn escrow_settles_in_psp22_stable_token<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = Psp22TokenRef::new(1_000_000);
+    let token = client
+        .instantiate("propchain_psp22_token", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut token_call = token.call_builder::<Psp22Token>();
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &token_call.transfer(accounts.charlie, 600_000, Vec::new()),
+        )
+        .submit()
+        .await
+        .expect("token transfer failed");
+
+    let first = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+    let rejected = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.create_escrow_psp22(first, accounts.charlie, token.account_id, 500_000),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(
+        rejected.return_value(),
+        Err(RegistryError::SettlementTokenNotAccepted)
+    );
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_settlement_token(token.account_id, true),
+        )
+        .submit()
+        .await
+        .expect("set_settlement_token failed");
+    let escrow_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.create_escrow_psp22(first, accounts.charlie, token.account_id, 500_000),
+        )
+        .submit()
+        .await
+        .expect("create_escrow_psp22 failed")
+        .return_value()
+        .expect("escrow rejected");
+
+    // Without an allowance the token refuses the pull.
+    let rejected = client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.deposit_escrow_psp22(escrow_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(
+        rejected.return_value(),
+        Err(RegistryError::TokenTransferRejected)
+    );
+
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &token_call.approve(registry.account_id, 500_000),
+        )
+        .submit()
+        .await
+        .expect("approve failed");
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.deposit_escrow_psp22(escrow_id),
+        )
+        .submit()
+        .await
+        .expect("deposit_escrow_psp22 failed")
+        .return_value()
+        .expect("deposit rejected");
+
+    // Alice mistakenly sends tokens straight to the registry. Only those
+    // can be rescued; the escrowed deposit stays put.
+    client
+        .call(
+            &ink_e2e::alice(),
+            &token_call.transfer(registry.account_id, 1_000, Vec::new()),
+        )
+        .submit()
+        .await
+        .expect("token transfer failed");
+    let rejected = client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.rescue_tokens(token.account_id, accounts.alice, 1_001),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(
+        rejected.return_value(),
+        Err(RegistryError::RescueExceedsFreeBalance)
+    );
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.rescue_tokens(token.account_id, accounts.alice, 1_000),
+        )
+        .submit()
+        .await
+        .expect("rescue_tokens failed")
+        .return_value()
+        .expect("rescue rejected");
+
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.release_escrow(escrow_id),
+        )
+        .submit()
+        .await
+        .expect("release_escrow failed")
+        .return_value()
+        .expect("release rejected");
+    let owner = client
+        .call(&ink_e2e::bob(), &registry_call.property_owner(first))
+        .dry_run()
+        .await?;
+    assert_eq!(owner.return_value(), Some(accounts.charlie));
+    let seller_balance = client
+        .call(&ink_e2e::bob(), &token_call.balance_of(accounts.bob))
+        .dry_run()
+        .await?;
+    assert_eq!(seller_balance.return_value(), 500_000);
+
+    // A refunded escrow returns the deposit to the buyer.
+    let second = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(property_metadata("12 Grimmauld Place", 90_000), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+    let escrow_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.create_escrow_psp22(second, accounts.charlie, token.account_id, 100_000),
+        )
+        .submit()
+        .await
+        .expect("create_escrow_psp22 failed")
+        .return_value()
+        .expect("escrow rejected");
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &token_call.approve(registry.account_id, 100_000),
+        )
+        .submit()
+        .await
+        .expect("approve failed");
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.deposit_escrow_psp22(escrow_id),
+        )
+        .submit()
+        .await
+        .expect("deposit_escrow_psp22 failed")
+        .return_value()
+        .expect("deposit rejected");
+    client
+        .call(&ink_e2e::bob(), &registry_call.refund_escrow(escrow_id))
+        .submit()
+        .await
+        .expect("refund_escrow failed")
+        .return_value()
+        .expect("refund rejected");
+
+    let buyer_balance = client
+        .call(
+            &ink_e2e::charlie(),
+            &token_call.balance_of(accounts.charlie),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(buyer_balance.return_value(), 100_000);
+    let held = client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.get_escrowed_token_balance(token.account_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(held.return_value(), 0);
+    let registry_balance = client
+        .call(
+            &ink_e2e::alice(),
+            &token_call.balance_of(registry.account_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(registry_balance.return_value(), 0);
+
+    Ok(())
+}
+
+/// Charlie funds a PSP22 escrow for Bob's property, then Bob transfers the
+/// property to Dave instead. The escrow can no longer be released, so
+/// Charlie reclaims the deposit straight away.
+#[ink_e2e::test]
+async fn psp22_deposit_reclaimed_after_seller_moves_property<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = Psp22TokenRef::new(1_000_000);
+    let token = client
+        .instantiate("propchain_psp22_token", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+    let mut constructor = PropertyRegistryRef::new();
+    let registry = client
+        .instantiate("propchain_contracts", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("registry instantiate failed");
+    let mut token_call = token.call_builder::<Psp22Token>();
+    let mut registry_call = registry.call_builder::<PropertyRegistry>();
+
+    client
+        .call(
+            &ink_e2e::alice(),
+            &token_call.transfer(accounts.charlie, 100_000, Vec::new()),
+        )
+        .submit()
+        .await
+        .expect("token transfer failed");
+    client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.set_settlement_token(token.account_id, true),
+        )
+        .submit()
+        .await
+        .expect("set_settlement_token failed");
+    let property_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.register_property(standard_metadata(), None),
+        )
+        .submit()
+        .await
+        .expect("register_property failed")
+        .return_value()
+        .expect("registration rejected");
+    let escrow_id = client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.create_escrow_psp22(
+                property_id,
+                accounts.charlie,
+                token.account_id,
+                100_000,
+            ),
+        )
+        .submit()
+        .await
+        .expect("create_escrow_psp22 failed")
+        .return_value()
+        .expect("escrow rejected");
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &token_call.approve(registry.account_id, 100_000),
+        )
+        .submit()
+        .await
+        .expect("approve failed");
+    client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.deposit_escrow_psp22(escrow_id),
+        )
+        .submit()
+        .await
+        .expect("deposit_escrow_psp22 failed")
+        .return_value()
+        .expect("deposit rejected");
+
+    let locked = client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.reclaim_escrow_deposit(escrow_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(
+        locked.return_value(),
+        Err(RegistryError::EscrowDepositLocked)
+    );
+
+    client
+        .call(
+            &ink_e2e::bob(),
+            &registry_call.transfer_property(property_id, accounts.dave),
+        )
+        .submit()
+        .await
+        .expect("transfer_property failed")
+        .return_value()
+        .expect("transfer rejected");
+    let release = client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.release_escrow(escrow_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(release.return_value(), Err(RegistryError::Unauthorized));
+
+    let refunded = client
+        .call(
+            &ink_e2e::charlie(),
+            &registry_call.reclaim_escrow_deposit(escrow_id),
+        )
+        .submit()
+        .await
+        .expect("reclaim_escrow_deposit failed")
+        .return_value()
+        .expect("reclaim rejected");
+    assert_eq!(refunded, 100_000);
+
+    let buyer_balance = client
+        .call(
+            &ink_e2e::charlie(),
+            &token_call.balance_of(accounts.charlie),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(buyer_balance.return_value(), 100_000);
+    let held = client
+        .call(
+            &ink_e2e::alice(),
+            &registry_call.get_escrowed_token_balance(token.account_id),
+        )
+        .dry_run()
+        .await?;
+    assert_eq!(held.return_value(), 0);
+
+    Ok(())
+}
+
+/// Eve bridges a token from chain 1 to Ferdie on chain 2, who sends it
+/// back. Each leg is approved with offline operator signatures relayed by
+/// Dave, and each arrival is finalized with a signed operator quorum.
+#[ink_e2e::test]
+async fn bridge_round_trip_with_operator_signatures<Client: ink_e2e::E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    const TOKEN: u64 = 42;
+    let accounts = E2EAccounts::default();
+
+    let mut constructor = PropertyBridgeRef::new(1, vec![1, 2], 2, 5, 100, 500_000);
+    let home = client
+        .instantiate("propchain_bridge", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("home bridge instantiate failed");
+    let mut constructor = PropertyBridgeRef::new(2, vec![1, 2], 2, 5, 100, 500_000);
+    let remote = client
+        .instantiate("propchain_bridge", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("remote bridge instantiate failed");
+    for bridge in [home.account_id, remote.account_id] {
+        add_operator(&mut client, bridge, accounts.bob, 2).await;
+        add_operator(&mut client, bridge, accounts.charlie, 3).await;
+    }
+    let mut home_call = home.call_builder::<PropertyBridge>();
+    let mut remote_call = remote.call_builder::<PropertyBridge>();
+
+    // Outbound: chain 1 → chain 2.
+    let outbound = client
+        .call(
+            &ink_e2e::eve(),
+            &home_call.initiate_bridge_multisig(
+                TOKEN,
+                2,
+                accounts.ferdie,
+                2,
+                None,
+                standard_metadata(),
+            ),
+        )
+        .submit()
+        .await
+        .expect("initiate_bridge_multisig failed")
+        .return_value()
+        .expect("bridge request rejected");
+    approve_and_execute(&mut client, home.account_id, outbound, &[2, 3]).await;
+    let active = client
+        .call(&ink_e2e::eve(), &home_call.get_active_request(TOKEN))
+        .dry_run()
+        .await?;
+    assert_eq!(active.return_value(), None);
+
+    let arrived = finalize_inbound(
+        &mut client,
+        remote.account_id,
+        (1, outbound),
+        TOKEN,
+        accounts.ferdie,
+        true,
+    )
+    .await;
+    assert_eq!(arrived, Ok(None));
+    let origin = client
+        .call(&ink_e2e::ferdie(), &remote_call.get_token_origin(TOKEN))
+        .dry_run()
+        .await?;
+    assert_eq!(origin.return_value(), Some((1, TOKEN)));
+
+    // Return leg: chain 2 → back to its origin, chain 1.
+    let inbound = client
+        .call(
+            &ink_e2e::ferdie(),
+            &remote_call.initiate_bridge_multisig(
+                TOKEN,
+                1,
+                accounts.eve,
+                2,
+                None,
+                standard_metadata(),
+            ),
+        )
+        .submit()
+        .await
+        .expect("return initiate_bridge_multisig failed")
+        .return_value()
+        .expect("return request rejected");
+    approve_and_execute(&mut client, remote.account_id, inbound, &[2, 3]).await;
+
+    let returned = finalize_inbound(
+        &mut client,
+        home.account_id,
+        (2, inbound),
+        TOKEN,
+        accounts.eve,
+        true,
+    )
+    .await;
+    assert_eq!(returned, Ok(None));
+    let processed = client
+        .call(&ink_e2e::eve(), &home_call.is_inbound_processed(2, inbound))
+        .dry_run()
+        .await?;
+    assert!(processed.return_value());
+
+    // The same quorum cannot be replayed.
+    let replay = finalize_inbound(
+        &mut client,
+        home.account_id,
+        (2, inbound),
+        TOKEN,
+        accounts.eve,
+        false,
+    )
+    .await;
+    assert_eq!(replay, Err(BridgeError::InboundAlreadyProcessed));
+
+    Ok(())
+}
@@ -82,7 +82,7 @@ run_rust_tests() {
     cd "$WORKSPACE_ROOT"
     
     # Run E2E tests with cargo
-    cargo test --features e2e-tests --test e2e -- --nocapture
+    cargo test -p propchain-e2e-tests --features e2e-tests --test e2e -- --nocapture
     
     log_success "Rust E2E tests completed"
 }
@@ -69,6 +69,7 @@ mod integration_bridge_oracle {
         let accounts = test::default_accounts::<DefaultEnvironment>();
         test::set_caller::<DefaultEnvironment>(accounts.alice);
         PropertyBridge::new(
+            CHAIN_STELLAR,
             vec![CHAIN_STELLAR, CHAIN_ETH],
             1,
             10,
@@ -312,6 +313,7 @@ mod integration_bridge_oracle {
                 confirmation_blocks: propchain_traits::constants::DEFAULT_CONFIRMATION_BLOCKS,
                 supported_tokens: vec![],
                 chain_daily_limit: 10_000_000_000_000_000_000,
+                gas_limit: None,
             })
             .expect("Admin should update chain info");
 